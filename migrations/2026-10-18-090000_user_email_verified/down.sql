@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+alter table user_information drop column if exists email_verified;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+alter table user_information
+    add column email_verified boolean not null default false;
+
+-- Accounts created before verification existed would otherwise be locked out.
+update user_information
+set email_verified = true;
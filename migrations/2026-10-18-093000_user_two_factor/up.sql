@@ -0,0 +1,14 @@
+-- Your SQL goes here
+create table user_two_factor
+(
+    user_id   uuid primary key references user_information (id) not null,
+    secret    varchar                                           not null,
+    confirmed boolean                                           not null default false
+);
+
+create table user_recovery_code
+(
+    id        uuid primary key                      not null,
+    user_id   uuid references user_information (id) not null,
+    code_hash varchar                               not null
+);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+drop table if exists personal_access_token;
+drop type if exists access_token_scope;
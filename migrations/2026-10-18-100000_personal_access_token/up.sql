@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TYPE access_token_scope AS ENUM ('read_only', 'tasks', 'teams');
+
+create table personal_access_token
+(
+    id                  uuid primary key                      not null,
+    user_id             uuid references user_information (id) not null,
+    name                varchar(50)                           not null check ( length(name) > 0 ),
+    token_hash          varchar unique                        not null,
+    scope               access_token_scope                    not null,
+    created_timestamp   bigint                                not null,
+    expires_timestamp   bigint                                null,
+    last_used_timestamp bigint                                null,
+    constraint unique_user_token_name_combination unique (user_id, name)
+);
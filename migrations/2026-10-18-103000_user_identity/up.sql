@@ -0,0 +1,9 @@
+-- Your SQL goes here
+create table user_identity
+(
+    id      uuid primary key                      not null,
+    user_id uuid references user_information (id) not null,
+    issuer  varchar                               not null,
+    subject varchar                               not null,
+    constraint unique_issuer_subject_combination unique (issuer, subject)
+);
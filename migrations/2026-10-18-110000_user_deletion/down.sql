@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+delete
+from user_information
+where id = '00000000-0000-0000-0000-000000000000';
+
+alter table user_information
+    drop column deletion_requested_timestamp;
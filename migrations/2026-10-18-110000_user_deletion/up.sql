@@ -0,0 +1,7 @@
+-- Your SQL goes here
+alter table user_information
+    add column deletion_requested_timestamp bigint null;
+
+-- Placeholder owner for tasks and teams of deleted accounts.
+insert into user_information (id, email, profile_image, user_name, password, email_verified)
+values ('00000000-0000-0000-0000-000000000000', 'deleted-user@taskem.invalid', null, 'deleted-user', '', false);
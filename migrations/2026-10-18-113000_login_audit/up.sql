@@ -0,0 +1,15 @@
+-- Your SQL goes here
+create table login_audit
+(
+    id                uuid primary key                      not null,
+    user_id           uuid references user_information (id) null,
+    success           boolean                               not null,
+    reason            varchar(50)                           not null,
+    ip                varchar(45)                           null,
+    user_agent        varchar(255)                          null,
+    two_factor_used   boolean                               not null default false,
+    suspicious        boolean                               not null default false,
+    created_timestamp bigint                                not null
+);
+
+create index login_audit_user_id_idx on login_audit (user_id, id desc);
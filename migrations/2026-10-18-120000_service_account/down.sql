@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+drop index user_information_service_account_team_id_idx;
+
+alter table user_information
+    drop column service_account_team_id;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+alter table user_information
+    add column service_account_team_id uuid null references team_information (id);
+
+create index user_information_service_account_team_id_idx on user_information (service_account_team_id);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+create table webauthn_credential
+(
+    id                  uuid primary key,
+    user_id             uuid        not null references user_information (id),
+    credential_id       varchar     not null unique,
+    name                varchar(50) not null,
+    passkey             text        not null,
+    sign_count          bigint      not null,
+    created_timestamp   bigint      not null,
+    last_used_timestamp bigint      null
+);
+
+create index webauthn_credential_user_id_idx on webauthn_credential (user_id);
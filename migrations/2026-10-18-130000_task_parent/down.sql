@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+drop index task_information_parent_id_idx;
+
+alter table task_information
+    drop column parent_id;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+alter table task_information
+    add column parent_id uuid null references task_information (id);
+
+create index task_information_parent_id_idx on task_information (parent_id);
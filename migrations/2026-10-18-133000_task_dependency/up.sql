@@ -0,0 +1,11 @@
+-- Your SQL goes here
+create table task_dependency
+(
+    id         uuid primary key,
+    task_id    uuid not null references task_information (id),
+    blocker_id uuid not null references task_information (id),
+    unique (task_id, blocker_id),
+    check (task_id <> blocker_id)
+);
+
+create index task_dependency_blocker_id_idx on task_dependency (blocker_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+drop table task_label;
+
+drop table team_label;
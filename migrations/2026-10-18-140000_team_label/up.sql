@@ -0,0 +1,19 @@
+-- Your SQL goes here
+create table team_label
+(
+    id      uuid primary key,
+    team_id uuid        not null references team_information (id),
+    name    varchar(50) not null,
+    color   varchar(7)  not null,
+    unique (team_id, name)
+);
+
+create table task_label
+(
+    id       uuid primary key,
+    task_id  uuid not null references task_information (id),
+    label_id uuid not null references team_label (id),
+    unique (task_id, label_id)
+);
+
+create index task_label_label_id_idx on task_label (label_id);
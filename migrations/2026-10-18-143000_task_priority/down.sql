@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+drop index task_information_team_id_priority_idx;
+
+alter table task_information
+    drop column priority;
+
+drop type task_priority;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+create type task_priority as enum ('none', 'low', 'medium', 'high', 'urgent');
+
+alter table task_information
+    add column priority task_priority not null default 'none';
+
+create index task_information_team_id_priority_idx on task_information (team_id, priority);
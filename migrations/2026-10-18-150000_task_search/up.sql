@@ -0,0 +1,8 @@
+-- Your SQL goes here
+alter table task_information
+    add column search_vector tsvector not null generated always as (
+        setweight(to_tsvector('english', name), 'A') ||
+        setweight(to_tsvector('english', description), 'B')
+        ) stored;
+
+create index task_information_search_vector_idx on task_information using gin (search_vector);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+drop index task_information_column_id_position_idx;
+
+alter table task_information
+    drop column column_id,
+    drop column position;
+
+drop table board_column;
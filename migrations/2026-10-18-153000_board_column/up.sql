@@ -0,0 +1,16 @@
+-- Your SQL goes here
+create table board_column
+(
+    id       uuid primary key,
+    team_id  uuid        not null references team_information (id),
+    name     varchar(50) not null,
+    status   task_status not null,
+    position integer     not null,
+    unique (team_id, name)
+);
+
+alter table task_information
+    add column column_id uuid null references board_column (id),
+    add column position  integer not null default 0;
+
+create index task_information_column_id_position_idx on task_information (column_id, position);
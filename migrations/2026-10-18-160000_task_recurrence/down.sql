@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+drop index task_information_recurrence_pending_idx;
+
+alter table task_information
+    drop column recurrence_rule,
+    drop column recurrence_index,
+    drop column recurrence_pending;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+alter table task_information
+    add column recurrence_rule    varchar(255) null,
+    add column recurrence_index   integer      not null default 0,
+    add column recurrence_pending boolean      not null default false;
+
+create index task_information_recurrence_pending_idx on task_information (end_timestamp) where recurrence_pending;
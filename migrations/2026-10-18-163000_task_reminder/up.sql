@@ -0,0 +1,10 @@
+-- Your SQL goes here
+create table task_reminder
+(
+    id             uuid primary key,
+    task_id        uuid   not null references task_information (id),
+    offset_seconds bigint not null,
+    end_timestamp  bigint not null,
+    sent_timestamp bigint not null,
+    unique (task_id, offset_seconds, end_timestamp)
+);
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+create table task_checklist_item
+(
+    id          uuid primary key,
+    task_id     uuid         not null references task_information (id),
+    text        varchar(200) not null,
+    done        boolean      not null default false,
+    assignee_id uuid         null references user_information (id),
+    position    integer      not null
+);
+
+create index task_checklist_item_task_id_position_idx on task_checklist_item (task_id, position);
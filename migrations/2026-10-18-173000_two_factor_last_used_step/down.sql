@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+alter table user_two_factor
+    drop column last_used_step;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+alter table user_two_factor
+    add column last_used_step bigint null;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+create table access_token_audit
+(
+    id                uuid primary key                      not null,
+    access_token_id   uuid                                  not null,
+    user_id           uuid references user_information (id) not null,
+    method            varchar(255)                          not null,
+    created_timestamp bigint                                not null
+);
+
+create index access_token_audit_user_id_idx on access_token_audit (user_id, id desc);
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+create table task_comment
+(
+    id                uuid primary key,
+    task_id           uuid     not null references task_information (id),
+    author_id         uuid     not null references user_information (id),
+    body              text     not null check ( length(body) > 0 ),
+    created_timestamp bigint   not null,
+    search_vector     tsvector not null generated always as (
+        setweight(to_tsvector('english', body), 'C')
+        ) stored
+);
+
+create index task_comment_task_id_idx on task_comment (task_id, id);
+create index task_comment_search_vector_idx on task_comment using gin (search_vector);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+alter table task_information
+    drop column recurrence_anchor;
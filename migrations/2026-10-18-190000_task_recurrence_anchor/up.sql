@@ -0,0 +1,16 @@
+-- Your SQL goes here
+alter table task_information
+    add column recurrence_anchor bigint null;
+
+-- Series already under way get the end of their first occurrence back from the
+-- current one.
+update task_information
+set recurrence_anchor = extract(epoch from
+        to_timestamp(end_timestamp) - recurrence_index
+            * coalesce(substring(recurrence_rule from 'INTERVAL=(\d+)')::integer, 1)
+            * case substring(recurrence_rule from 'FREQ=(\w+)')
+                  when 'DAILY' then interval '1 day'
+                  when 'WEEKLY' then interval '1 week'
+                  else interval '1 month'
+              end)::bigint
+where recurrence_rule is not null;
@@ -6,13 +6,27 @@ use prost_types::Timestamp;
 use uuid::Uuid;
 
 use crate::auth::{LoginRequest, SignupRequest};
+use crate::domain::models::task::board_column::BoardColumn;
+use crate::domain::models::task::checklist_item::ChecklistItem;
+use crate::domain::models::task::task_comment::TaskComment;
 use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::domain::models::task::task_search_result::TaskSearchResult;
 use crate::domain::models::task::task_status::TaskStatus;
 use crate::domain::models::team::team_information::TeamInformation;
+use crate::domain::models::team::team_label::TeamLabel;
+use crate::domain::models::user::access_token::AccessToken;
+use crate::domain::models::user::access_token_audit::AccessTokenAudit;
+use crate::domain::models::user::login_audit::LoginAudit;
 use crate::domain::models::user::login_information::LoginInformation;
 use crate::domain::models::user::user_information::UserInformation;
-use crate::task::{CreateTaskRequest, TaskResponse};
-use crate::team::{CreateTeamRequest, UserInfo};
+use crate::domain::models::user::webauthn_credential::WebauthnCredential;
+use crate::profile::{AccessTokenResponse, LoginAuditResponse, PasskeyResponse};
+use crate::task::{
+    BoardColumnResponse, ChecklistItemResponse, CommentResponse, CreateTaskRequest,
+    TaskLabelResponse, TaskResponse, TaskSearchHit,
+};
+use crate::team::{CreateTeamRequest, LabelResponse, ServiceAccountActivityResponse, UserInfo};
 
 impl From<SignupRequest> for UserInformation {
     fn from(value: SignupRequest) -> Self {
@@ -22,6 +36,9 @@ impl From<SignupRequest> for UserInformation {
             user_name: value.user_name,
             profile_image: None,
             password: value.password,
+            email_verified: false,
+            deletion_requested_timestamp: None,
+            service_account_team_id: None,
         }
     }
 }
@@ -64,12 +81,27 @@ impl From<CreateTaskRequest> for TaskInformation {
             status: TaskStatus::Paused,
             creator: Uuid::default(),
             assigned_users: vec![],
+            parent_id: None,
+            priority: TaskPriority::None,
+            column_id: None,
+            position: 0,
+            recurrence_rule: None,
+            recurrence_index: 0,
+            recurrence_pending: false,
+            recurrence_anchor: None,
+            children_total: 0,
+            children_finished: 0,
+            checklist_total: 0,
+            checklist_done: 0,
+            labels: vec![],
         }
     }
 }
 
 impl From<TaskInformation> for TaskResponse {
     fn from(value: TaskInformation) -> Self {
+        let overdue = value.is_overdue(Utc::now().timestamp());
+
         TaskResponse {
             id: value.id.to_string(),
             name: value.name,
@@ -89,15 +121,41 @@ impl From<TaskInformation> for TaskResponse {
                     id: assigned.id.to_string(),
                     user_name: "".to_string(),
                     role: "".to_string(),
+                    service_account: assigned.service_account_team_id.is_some(),
                 })
                 .collect(),
             status: value.status.into(),
+            priority: value.priority.into(),
+            column_id: value
+                .column_id
+                .map(|column_id| column_id.to_string())
+                .unwrap_or_default(),
+            position: value.position,
+            overdue,
+            recurrence_rule: value.recurrence_rule.unwrap_or_default(),
+            recurrence_index: value.recurrence_index,
+            creator: value.creator.to_string(),
+            parent_id: value
+                .parent_id
+                .map(|parent_id| parent_id.to_string())
+                .unwrap_or_default(),
+            children_total: value.children_total,
+            children_finished: value.children_finished,
+            checklist_total: value.checklist_total,
+            checklist_done: value.checklist_done,
+            labels: value
+                .labels
+                .into_iter()
+                .map(TaskLabelResponse::from)
+                .collect(),
         }
     }
 }
 
 impl From<&TaskInformation> for TaskResponse {
     fn from(value: &TaskInformation) -> Self {
+        let overdue = value.is_overdue(Utc::now().timestamp());
+
         TaskResponse {
             id: value.id.to_string(),
             name: value.name.clone(),
@@ -117,9 +175,176 @@ impl From<&TaskInformation> for TaskResponse {
                     id: assigned.id.to_string(),
                     user_name: assigned.user_name.clone(),
                     role: "".to_string(),
+                    service_account: assigned.service_account_team_id.is_some(),
                 })
                 .collect(),
             status: value.status.clone().into(),
+            priority: value.priority.into(),
+            column_id: value
+                .column_id
+                .map(|column_id| column_id.to_string())
+                .unwrap_or_default(),
+            position: value.position,
+            overdue,
+            recurrence_rule: value.recurrence_rule.clone().unwrap_or_default(),
+            recurrence_index: value.recurrence_index,
+            creator: value.creator.to_string(),
+            parent_id: value
+                .parent_id
+                .map(|parent_id| parent_id.to_string())
+                .unwrap_or_default(),
+            children_total: value.children_total,
+            children_finished: value.children_finished,
+            checklist_total: value.checklist_total,
+            checklist_done: value.checklist_done,
+            labels: value
+                .labels
+                .iter()
+                .cloned()
+                .map(TaskLabelResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<TaskSearchResult> for TaskSearchHit {
+    fn from(value: TaskSearchResult) -> Self {
+        TaskSearchHit {
+            task: Some(TaskResponse::from(value.task)),
+            rank: value.rank,
+            snippet: value.snippet,
+        }
+    }
+}
+
+impl From<BoardColumn> for BoardColumnResponse {
+    fn from(value: BoardColumn) -> Self {
+        BoardColumnResponse {
+            id: value.id.to_string(),
+            team_id: value.team_id.to_string(),
+            name: value.name,
+            status: value.status.into(),
+            position: value.position,
+        }
+    }
+}
+
+impl From<TaskComment> for CommentResponse {
+    fn from(value: TaskComment) -> Self {
+        CommentResponse {
+            id: value.id.to_string(),
+            task_id: value.task_id.to_string(),
+            author_id: value.author_id.to_string(),
+            body: value.body,
+            created_timestamp: Some(Timestamp {
+                seconds: value.created_timestamp,
+                nanos: 0,
+            }),
+        }
+    }
+}
+
+impl From<ChecklistItem> for ChecklistItemResponse {
+    fn from(value: ChecklistItem) -> Self {
+        ChecklistItemResponse {
+            id: value.id.to_string(),
+            task_id: value.task_id.to_string(),
+            text: value.text,
+            done: value.done,
+            assignee_id: value
+                .assignee_id
+                .map(|assignee_id| assignee_id.to_string())
+                .unwrap_or_default(),
+            position: value.position,
+        }
+    }
+}
+
+impl From<AccessToken> for AccessTokenResponse {
+    fn from(value: AccessToken) -> Self {
+        AccessTokenResponse {
+            id: value.id.to_string(),
+            name: value.name,
+            scope: value.scope.into(),
+            created_timestamp: Some(Timestamp {
+                seconds: value.created_timestamp,
+                nanos: 0,
+            }),
+            expires_timestamp: value
+                .expires_timestamp
+                .map(|seconds| Timestamp { seconds, nanos: 0 }),
+            last_used_timestamp: value
+                .last_used_timestamp
+                .map(|seconds| Timestamp { seconds, nanos: 0 }),
+        }
+    }
+}
+
+impl From<LoginAudit> for LoginAuditResponse {
+    fn from(value: LoginAudit) -> Self {
+        LoginAuditResponse {
+            id: value.id.to_string(),
+            success: value.success,
+            reason: value.reason,
+            ip: value.ip.unwrap_or_default(),
+            user_agent: value.user_agent.unwrap_or_default(),
+            two_factor_used: value.two_factor_used,
+            suspicious: value.suspicious,
+            created_timestamp: Some(Timestamp {
+                seconds: value.created_timestamp,
+                nanos: 0,
+            }),
+        }
+    }
+}
+
+impl From<AccessTokenAudit> for ServiceAccountActivityResponse {
+    fn from(value: AccessTokenAudit) -> Self {
+        ServiceAccountActivityResponse {
+            id: value.id.to_string(),
+            key_id: value.access_token_id.to_string(),
+            method: value.method,
+            created_timestamp: Some(Timestamp {
+                seconds: value.created_timestamp,
+                nanos: 0,
+            }),
+        }
+    }
+}
+
+impl From<WebauthnCredential> for PasskeyResponse {
+    fn from(value: WebauthnCredential) -> Self {
+        PasskeyResponse {
+            id: value.id.to_string(),
+            name: value.name,
+            created_timestamp: Some(Timestamp {
+                seconds: value.created_timestamp,
+                nanos: 0,
+            }),
+            last_used_timestamp: value
+                .last_used_timestamp
+                .map(|seconds| Timestamp { seconds, nanos: 0 }),
+        }
+    }
+}
+
+impl From<TeamLabel> for LabelResponse {
+    fn from(value: TeamLabel) -> Self {
+        LabelResponse {
+            id: value.id.to_string(),
+            team_id: value.team_id.to_string(),
+            name: value.name,
+            color: value.color,
+        }
+    }
+}
+
+impl From<TeamLabel> for TaskLabelResponse {
+    fn from(value: TeamLabel) -> Self {
+        TaskLabelResponse {
+            id: value.id.to_string(),
+            name: value.name,
+            color: value.color,
         }
     }
 }
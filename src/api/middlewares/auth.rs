@@ -1,20 +1,37 @@
-use crate::domain::constants::MIDDLEWARE_AUTH_SESSION_KEY;
+use crate::core::jwt::JwtKeys;
+use crate::core::revoked_tokens::RevokedTokens;
+use crate::core::token::hash_token;
+use crate::domain::constants::{
+    ACCESS_TOKEN_PREFIX, MIDDLEWARE_AUTH_SESSION_KEY, MIDDLEWARE_AUTH_USER_KEY,
+};
+use crate::domain::models::user::access_token_audit::AccessTokenAudit;
+use crate::domain::models::user::service_account::service_account_allows;
+use crate::domain::repositories::access_token::AccessTokenRepository;
+use crate::domain::repositories::access_token_audit::AccessTokenAuditRepository;
 use crate::domain::repositories::session::RedisSessionRepository;
+use crate::domain::repositories::user::UserRepository;
+use chrono::Utc;
 use derive_new::new;
 use hyper::StatusCode;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use tonic::body::BoxBody;
-use tonic::codegen::http::{Request, Response};
+use tonic::codegen::http::{HeaderValue, Request, Response};
 use tonic::transport::Body;
 use tower::Layer;
 use tower_service::Service;
 use tracing::{debug, info};
+use uuid::Uuid;
 
 #[derive(Clone, new)]
 pub struct AuthMiddlewareLayer {
     redis_repository: Arc<dyn RedisSessionRepository>,
+    access_token_repository: Arc<dyn AccessTokenRepository>,
+    jwt_keys: Option<Arc<JwtKeys>>,
+    revoked_tokens: Arc<RevokedTokens>,
+    user_repository: Arc<dyn UserRepository>,
+    access_token_audit_repository: Arc<dyn AccessTokenAuditRepository>,
 }
 
 impl<S> Layer<S> for AuthMiddlewareLayer {
@@ -24,6 +41,11 @@ impl<S> Layer<S> for AuthMiddlewareLayer {
         AuthMiddleware {
             inner,
             redis_repository: self.redis_repository.clone(),
+            access_token_repository: self.access_token_repository.clone(),
+            jwt_keys: self.jwt_keys.clone(),
+            revoked_tokens: self.revoked_tokens.clone(),
+            user_repository: self.user_repository.clone(),
+            access_token_audit_repository: self.access_token_audit_repository.clone(),
         }
     }
 }
@@ -32,6 +54,75 @@ impl<S> Layer<S> for AuthMiddlewareLayer {
 pub struct AuthMiddleware<S> {
     inner: S,
     redis_repository: Arc<dyn RedisSessionRepository>,
+    access_token_repository: Arc<dyn AccessTokenRepository>,
+    jwt_keys: Option<Arc<JwtKeys>>,
+    revoked_tokens: Arc<RevokedTokens>,
+    user_repository: Arc<dyn UserRepository>,
+    access_token_audit_repository: Arc<dyn AccessTokenAuditRepository>,
+}
+
+impl<S> AuthMiddleware<S> {
+    /// Resolves the user behind an `authorization` header value, which is either a
+    /// personal access token or, depending on the token mode, a signed access token
+    /// or a session id. Personal access tokens are checked against `path` when the
+    /// request is a gRPC call, and every use of one is written to the audit log.
+    fn authenticate(&self, value: &str, path: &str, is_grpc: bool) -> Option<Uuid> {
+        if value.starts_with(ACCESS_TOKEN_PREFIX) {
+            let access_token = self
+                .access_token_repository
+                .find_active(&hash_token(value))
+                .ok()?;
+
+            if is_grpc && !access_token.scope.allows(path) {
+                debug!("Access token scope doesn't allow {}", path);
+                return None;
+            }
+
+            let user = self.user_repository.get(&access_token.user_id).ok()?;
+
+            // Like sessions, tokens stop working once the account is scheduled for deletion.
+            if user.deletion_requested_timestamp.is_some() {
+                debug!("Account is scheduled for deletion");
+                return None;
+            }
+
+            if is_grpc && user.service_account_team_id.is_some() && !service_account_allows(path) {
+                debug!("Service accounts can't call {}", path);
+                return None;
+            }
+
+            // Fails closed, a request that can't be attributed to its token isn't served.
+            self.access_token_audit_repository
+                .create(&AccessTokenAudit {
+                    id: Uuid::now_v7(),
+                    access_token_id: access_token.id,
+                    user_id: access_token.user_id,
+                    method: path.chars().take(255).collect(),
+                    created_timestamp: Utc::now().timestamp(),
+                })
+                .ok()?;
+
+            info!("Access token accepted");
+            return Some(access_token.user_id);
+        }
+
+        if let Some(jwt_keys) = &self.jwt_keys {
+            let claims = jwt_keys.verify(value)?;
+
+            // Checked against the local copy, the deny-list isn't read per request.
+            if self.revoked_tokens.is_revoked(&claims.jti) {
+                debug!("Access token revoked");
+                return None;
+            }
+
+            info!("Access token verified");
+            return Some(claims.sub);
+        }
+
+        let user_id = self.redis_repository.session_expand(value).ok()?;
+        info!("Session expanded");
+        Some(user_id)
+    }
 }
 
 type BoxFuture<'a, T> = Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
@@ -49,10 +140,12 @@ where
         self.inner.poll_ready(cx).map_err(Into::into)
     }
 
-    fn call(&mut self, req: Request<Body>) -> Self::Future {
+    fn call(&mut self, mut req: Request<Body>) -> Self::Future {
         let clone = self.inner.clone();
         let mut inner = std::mem::replace(&mut self.inner, clone);
 
+        req.headers_mut().remove(MIDDLEWARE_AUTH_USER_KEY);
+
         debug!("Check auth url for request: \n{:?}", req.uri());
 
         if req.uri().path().contains("Auth") {
@@ -70,10 +163,13 @@ where
             let value = header_value
                 .to_str()
                 .expect("Can't convert header value to str");
-            let session = format!("session_id:{}", value);
 
-            if self.redis_repository.session_expand(&session).is_ok() {
-                info!("Session expanded");
+            if let Some(user_id) = self.authenticate(value, req.uri().path(), true) {
+                req.headers_mut().insert(
+                    MIDDLEWARE_AUTH_USER_KEY,
+                    HeaderValue::from_str(&user_id.to_string())
+                        .expect("Can't convert user id to header value"),
+                );
                 return Box::pin(async move {
                     let response = inner.call(req).await?;
                     Ok(response)
@@ -106,7 +202,9 @@ where
         self.inner.poll_ready(cx)
     }
 
-    fn call(&mut self, request: axum::extract::Request) -> Self::Future {
+    fn call(&mut self, mut request: axum::extract::Request) -> Self::Future {
+        request.headers_mut().remove(MIDDLEWARE_AUTH_USER_KEY);
+
         let headers = request.headers();
 
         debug!("Find authorization header");
@@ -115,10 +213,13 @@ where
             let value = header_value
                 .to_str()
                 .expect("Can't convert header value to str");
-            let session = format!("session_id:{}", value);
 
-            if self.redis_repository.session_expand(&session).is_ok() {
-                info!("Session expanded");
+            if let Some(user_id) = self.authenticate(value, request.uri().path(), false) {
+                request.headers_mut().insert(
+                    MIDDLEWARE_AUTH_USER_KEY,
+                    axum::http::HeaderValue::from_str(&user_id.to_string())
+                        .expect("Can't convert user id to header value"),
+                );
                 let future = self.inner.call(request);
                 return Box::pin(async move {
                     let response: axum::response::Response = future.await?;
@@ -1,4 +1,6 @@
 mod from;
 pub mod middlewares;
+pub mod password_validator;
 mod service_macros;
 pub mod services;
+pub mod workers;
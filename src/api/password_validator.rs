@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, FieldViolation, StatusExt};
+use tracing::error;
+
+use crate::domain::models::user::password_policy::{PasswordPolicy, PasswordViolation};
+use crate::domain::services::breached_password::BreachedPasswordChecker;
+
+/// Applies the password policy and the breached password list, rejected passwords are
+/// reported as `invalid_argument` with a `BadRequest` detail listing every violation.
+#[derive(new)]
+pub struct PasswordValidator {
+    policy: PasswordPolicy,
+    breached_password_checker: Option<Arc<dyn BreachedPasswordChecker>>,
+}
+
+impl PasswordValidator {
+    pub fn validate(
+        &self,
+        field: &str,
+        password: &str,
+        email: &str,
+        user_name: &str,
+    ) -> Result<(), Status> {
+        let mut violations = self.policy.check(password, email, user_name);
+
+        if let Some(checker) = &self.breached_password_checker {
+            match checker.is_breached(password) {
+                Ok(true) => violations.push(PasswordViolation::Breached),
+                Ok(false) => {}
+                Err(e) => error!("Breached password check failed: {:?}", e),
+            }
+        }
+
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        let field_violations = violations
+            .iter()
+            .map(|violation| {
+                FieldViolation::new(field, format!("{}: {}", violation.code(), violation))
+            })
+            .collect::<Vec<_>>();
+
+        Err(Status::with_error_details(
+            Code::InvalidArgument,
+            "Password does not satisfy the password policy",
+            ErrorDetails::with_bad_request(field_violations),
+        ))
+    }
+}
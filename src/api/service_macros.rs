@@ -5,13 +5,13 @@ macro_rules! extract_user_id_from_metadata {
 
         let metadata = $request.metadata().clone();
 
-        if !metadata.contains_key(MIDDLEWARE_AUTH_SESSION_KEY) {
+        if !metadata.contains_key(MIDDLEWARE_AUTH_USER_KEY) {
             return Err(tonic::Status::unauthenticated(
                 "Unauthenticated".to_string(),
             ));
         }
 
-        let metadata_value = metadata.get(MIDDLEWARE_AUTH_SESSION_KEY).unwrap();
+        let metadata_value = metadata.get(MIDDLEWARE_AUTH_USER_KEY).unwrap();
 
         let user_id = match uuid::Uuid::from_str(
             metadata_value
@@ -1,25 +1,440 @@
+use std::str::FromStr;
 use std::sync::Arc;
 
 use autometrics::autometrics;
+use chrono::Utc;
 use derive_new::new;
 use tonic::{Request, Response, Status};
 use tracing::log::error;
+use uuid::Uuid;
+use webauthn_rs::prelude::{Passkey, PublicKeyCredential};
+use webauthn_rs::Webauthn;
 
+use crate::api::password_validator::PasswordValidator;
 use crate::auth::auth_server::Auth;
 use crate::auth::{
-    LoginRequest, LoginResponse, LogoutRequest, LogoutResponse, SignUpResponse, SignupRequest,
+    CancelAccountDeletionRequest, CancelAccountDeletionResponse, CompleteMagicLinkLoginRequest,
+    CompleteOidcLoginRequest, CompletePasskeyLoginRequest, CompleteTwoFactorLoginRequest,
+    ConfirmEmailChangeRequest, ConfirmEmailChangeResponse, ForgotPasswordRequest,
+    ForgotPasswordResponse, LoginRequest, LoginResponse, LogoutRequest, LogoutResponse,
+    RefreshTokenRequest, RequestMagicLinkRequest, RequestMagicLinkResponse,
+    ResendVerificationRequest, ResendVerificationResponse, ResetPasswordRequest,
+    ResetPasswordResponse, SignUpResponse, SignupRequest, StartOidcLoginRequest,
+    StartOidcLoginResponse, StartPasskeyLoginRequest, StartPasskeyLoginResponse,
+    VerifyEmailRequest, VerifyEmailResponse,
 };
+use crate::core::jwt::JwtKeys;
+use crate::core::metrics::record_blocked_login;
+use crate::core::pkce;
 use crate::core::regex::CachedRegexValidator;
+use crate::core::revoked_tokens::RevokedTokens;
+use crate::core::token::generate_token;
+use crate::core::totp;
+use crate::core::webauthn::{encode_credential_id, is_sign_count_valid};
+use crate::domain::constants::{
+    EMAIL_CHANGE_TOKEN, EMAIL_VERIFICATION_EXPIRE_SECONDS, EMAIL_VERIFICATION_TOKEN,
+    MAGIC_LINK_EXPIRE_SECONDS, MAGIC_LINK_RATE_LIMIT, MAGIC_LINK_RATE_LIMIT_WINDOW_SECONDS,
+    MAGIC_LINK_TOKEN, OIDC_LOGIN_STATE_EXPIRE_SECONDS, OIDC_LOGIN_STATE_TOKEN,
+    PASSKEY_AUTHENTICATION_TOKEN, PASSKEY_CHALLENGE_EXPIRE_SECONDS, PASSWORD_RESET_EXPIRE_SECONDS,
+    PASSWORD_RESET_TOKEN, SESSION_EXPIRE_SECONDS, TWO_FACTOR_CHALLENGE_EXPIRE_SECONDS,
+    TWO_FACTOR_CHALLENGE_TOKEN, USER_NAME_MAX_LENGTH,
+};
+use crate::domain::error::Error;
+use crate::domain::models::mail::mail_message::MailMessage;
+use crate::domain::models::user::email_verification_policy::EmailVerificationPolicy;
+use crate::domain::models::user::login_audit::LoginAudit;
 use crate::domain::models::user::login_information::LoginInformation;
+use crate::domain::models::user::login_limits::LoginLimits;
+use crate::domain::models::user::oidc_claims::OidcClaims;
+use crate::domain::models::user::oidc_login_state::OidcLoginState;
+use crate::domain::models::user::passkey_ceremony_state::PasskeyAuthenticationState;
+use crate::domain::models::user::two_factor_information::TwoFactorInformation;
+use crate::domain::models::user::user_identity::UserIdentity;
 use crate::domain::models::user::user_information::UserInformation;
+use crate::domain::repositories::login_attempt::RedisLoginAttemptRepository;
+use crate::domain::repositories::login_audit::LoginAuditRepository;
+use crate::domain::repositories::refresh_token::RedisRefreshTokenRepository;
+use crate::domain::repositories::revoked_token::RedisRevokedTokenRepository;
 use crate::domain::repositories::session::RedisSessionRepository;
+use crate::domain::repositories::token::RedisTokenRepository;
+use crate::domain::repositories::two_factor::TwoFactorRepository;
 use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user_identity::UserIdentityRepository;
+use crate::domain::repositories::webauthn_credential::WebauthnCredentialRepository;
+use crate::domain::services::mail::MailSender;
+use crate::domain::services::oidc::OidcProvider;
 
 #[derive(new)]
 pub struct AuthServiceImpl {
     pub(self) regex_cache: Arc<CachedRegexValidator>,
     pub(self) user_repository: Arc<dyn UserRepository>,
     pub(self) redis_repository: Arc<dyn RedisSessionRepository>,
+    pub(self) token_repository: Arc<dyn RedisTokenRepository>,
+    pub(self) mail_sender: Arc<dyn MailSender>,
+    pub(self) two_factor_repository: Arc<dyn TwoFactorRepository>,
+    pub(self) login_attempt_repository: Arc<dyn RedisLoginAttemptRepository>,
+    pub(self) email_verification_policy: EmailVerificationPolicy,
+    pub(self) login_limits: LoginLimits,
+    pub(self) user_identity_repository: Arc<dyn UserIdentityRepository>,
+    pub(self) oidc_provider: Option<Arc<dyn OidcProvider>>,
+    pub(self) refresh_token_repository: Arc<dyn RedisRefreshTokenRepository>,
+    /// Set when signed access tokens are used instead of sessions.
+    pub(self) jwt_keys: Option<Arc<JwtKeys>>,
+    pub(self) password_validator: Arc<PasswordValidator>,
+    pub(self) login_audit_repository: Arc<dyn LoginAuditRepository>,
+    pub(self) magic_link_enabled: bool,
+    pub(self) webauthn_credential_repository: Arc<dyn WebauthnCredentialRepository>,
+    pub(self) webauthn: Option<Arc<Webauthn>>,
+    pub(self) revoked_token_repository: Arc<dyn RedisRevokedTokenRepository>,
+    pub(self) revoked_tokens: Arc<RevokedTokens>,
+}
+
+/// Where a login attempt came from, recorded in the login audit.
+struct LoginClient {
+    ip: Option<String>,
+    user_agent: Option<String>,
+}
+
+impl LoginClient {
+    fn from_request<T>(request: &Request<T>) -> Self {
+        LoginClient {
+            ip: request.remote_addr().map(|addr| addr.ip().to_string()),
+            user_agent: request
+                .metadata()
+                .get("user-agent")
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.chars().take(255).collect()),
+        }
+    }
+}
+
+impl AuthServiceImpl {
+    fn check_login_limits(&self, ip: &str, account: &str) -> Result<(), Status> {
+        let limits = &self.login_limits;
+        let login_attempt_repository = self.login_attempt_repository.clone();
+
+        let ip_allowed = login_attempt_repository
+            .check_rate_limit(
+                &format!("login_rate:ip:{}", ip),
+                limits.ip_limit,
+                limits.window_seconds,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if !ip_allowed {
+            record_blocked_login("ip");
+            return Err(Status::resource_exhausted("Too many login attempts"));
+        }
+
+        let account_allowed = login_attempt_repository
+            .check_rate_limit(
+                &format!("login_rate:account:{}", account),
+                limits.account_limit,
+                limits.window_seconds,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if !account_allowed {
+            record_blocked_login("account");
+            return Err(Status::resource_exhausted("Too many login attempts"));
+        }
+
+        let lockout = login_attempt_repository
+            .lockout_remaining(account)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if let Some(remaining) = lockout {
+            record_blocked_login("lockout");
+            return Err(Status::resource_exhausted(format!(
+                "Account is locked, try again in {} seconds",
+                remaining
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn register_login_failure(&self, account: &str) -> Result<(), Status> {
+        let failures = self
+            .login_attempt_repository
+            .register_failure(account, self.login_limits.failures_expire_seconds())
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if let Some(lockout_seconds) = self.login_limits.lockout_seconds(failures) {
+            self.login_attempt_repository
+                .lock(account, lockout_seconds)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+        }
+
+        Ok(())
+    }
+
+    fn audit_login(
+        &self,
+        client: &LoginClient,
+        user_id: Option<Uuid>,
+        reason: &str,
+        success: bool,
+        two_factor_used: bool,
+    ) {
+        let result = self.login_audit_repository.create(&LoginAudit {
+            id: Uuid::now_v7(),
+            user_id,
+            success,
+            reason: reason.to_string(),
+            ip: client.ip.clone(),
+            user_agent: client.user_agent.clone(),
+            two_factor_used,
+            suspicious: false,
+            created_timestamp: Utc::now().timestamp(),
+        });
+
+        if let Err(e) = result {
+            error!("Failed to record login audit: {:?}", e);
+        }
+    }
+
+    /// Accounts waiting for deletion can't log in until the deletion is canceled.
+    fn check_not_scheduled_for_deletion(user: &UserInformation) -> Result<(), Status> {
+        if user.deletion_requested_timestamp.is_some() {
+            return Err(Status::failed_precondition(
+                "Account is scheduled for deletion",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Issues a two-factor challenge when the user has two-factor enabled, the
+    /// session is only created once the challenge is completed.
+    fn two_factor_challenge(
+        &self,
+        user: &UserInformation,
+    ) -> Result<Option<LoginResponse>, Status> {
+        Self::check_not_scheduled_for_deletion(user)?;
+
+        let two_factor_enabled = self
+            .two_factor_repository
+            .get(&user.id)
+            .map(|two_factor| two_factor.confirmed)
+            .unwrap_or(false);
+
+        if !two_factor_enabled {
+            return Ok(None);
+        }
+
+        let challenge_token = self
+            .token_repository
+            .create(
+                TWO_FACTOR_CHALLENGE_TOKEN,
+                &user.id.to_string(),
+                TWO_FACTOR_CHALLENGE_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Some(LoginResponse {
+            user_name: user.user_name.clone(),
+            message: "Two-factor code required".to_string(),
+            session_id: String::new(),
+            two_factor_required: true,
+            challenge_token,
+            refresh_token: String::new(),
+            expires_in: 0,
+        }))
+    }
+
+    /// Accepts a TOTP code once, a code that was already used can't be replayed.
+    fn use_totp_code(&self, two_factor: &TwoFactorInformation, code: &str) -> Result<bool, Status> {
+        let Some(step) = totp::verify_code(&two_factor.secret, code, two_factor.last_used_step)
+        else {
+            return Ok(false);
+        };
+
+        self.two_factor_repository
+            .use_step(&two_factor.user_id, step)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))
+    }
+
+    /// Ends a login in signed token mode. The access token in `session_id` is revoked
+    /// until it expires and the refresh token family is revoked, either may be empty.
+    fn revoke_jwt_login(
+        &self,
+        jwt_keys: &JwtKeys,
+        logout_request: &LogoutRequest,
+    ) -> Result<(), Error> {
+        if let Some(claims) = jwt_keys.verify(&logout_request.session_id) {
+            self.revoked_token_repository
+                .revoke(&claims.jti, claims.exp)?;
+            self.revoked_tokens.insert(claims.jti);
+        }
+
+        if !logout_request.refresh_token.is_empty() {
+            self.refresh_token_repository
+                .revoke(&logout_request.refresh_token)?;
+        }
+
+        Ok(())
+    }
+
+    fn create_session(&self, user: UserInformation) -> Result<LoginResponse, Status> {
+        Self::check_not_scheduled_for_deletion(&user)?;
+
+        if let Some(jwt_keys) = &self.jwt_keys {
+            let refresh_token = self
+                .refresh_token_repository
+                .create(&user.id)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+            return Ok(LoginResponse {
+                user_name: user.user_name,
+                message: "User successfully logged".to_string(),
+                session_id: jwt_keys.issue(&user.id),
+                two_factor_required: false,
+                challenge_token: String::new(),
+                refresh_token,
+                expires_in: jwt_keys.access_token_expire_seconds(),
+            });
+        }
+
+        let session = self
+            .redis_repository
+            .create(&user.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(LoginResponse {
+            user_name: user.user_name,
+            message: "User successfully logged".to_string(),
+            session_id: session,
+            two_factor_required: false,
+            challenge_token: String::new(),
+            refresh_token: String::new(),
+            expires_in: SESSION_EXPIRE_SECONDS,
+        })
+    }
+
+    fn send_verification(&self, user: &UserInformation) -> Result<(), Status> {
+        let token = self
+            .token_repository
+            .create(
+                EMAIL_VERIFICATION_TOKEN,
+                &user.id.to_string(),
+                EMAIL_VERIFICATION_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.mail_sender
+            .send(&MailMessage {
+                to: user.email.clone(),
+                subject: String::from("Confirm your Taskem email"),
+                body: format!("Use this token to confirm your email: {}", token),
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))
+    }
+
+    fn webauthn(&self) -> Result<Arc<Webauthn>, Status> {
+        self.webauthn
+            .clone()
+            .ok_or_else(|| Status::failed_precondition("Passkey login is not configured"))
+    }
+
+    fn oidc_provider(&self) -> Result<Arc<dyn OidcProvider>, Status> {
+        self.oidc_provider
+            .clone()
+            .ok_or_else(|| Status::failed_precondition("OpenID Connect login is not configured"))
+    }
+
+    /// Resolves the local user of an external identity. Unknown identities are linked to
+    /// the account with the same verified email, otherwise a new account is created.
+    /// Unverified accounts are never linked: anyone could have signed up with the email
+    /// and would keep access to the account through its password.
+    fn find_or_create_oidc_user(&self, claims: OidcClaims) -> Result<UserInformation, Status> {
+        if let Ok(identity) = self
+            .user_identity_repository
+            .get(&claims.issuer, &claims.subject)
+        {
+            return self
+                .user_repository
+                .get(&identity.user_id)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)));
+        }
+
+        let email = match (&claims.email, claims.email_verified) {
+            (Some(email), true) => email.to_lowercase(),
+            _ => {
+                return Err(Status::permission_denied(
+                    "Identity provider did not return a verified email",
+                ))
+            }
+        };
+
+        let user = match self.user_repository.get_by_email(&email) {
+            Ok(user) if !user.email_verified => {
+                return Err(Status::failed_precondition(
+                    "Verify the existing account with this email first",
+                ))
+            }
+            Ok(user) => user,
+            Err(_) => {
+                let base_name = claims
+                    .preferred_username
+                    .as_deref()
+                    .unwrap_or_else(|| email.split('@').next().unwrap_or_default());
+
+                self.user_repository
+                    .create(&UserInformation {
+                        id: Uuid::now_v7(),
+                        email,
+                        user_name: self.free_user_name(base_name),
+                        profile_image: None,
+                        password: generate_token(),
+                        email_verified: true,
+                        deletion_requested_timestamp: None,
+                        service_account_team_id: None,
+                    })
+                    .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?
+            }
+        };
+
+        self.user_identity_repository
+            .create(&UserIdentity {
+                id: Uuid::now_v7(),
+                user_id: user.id,
+                issuer: claims.issuer,
+                subject: claims.subject,
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(user)
+    }
+
+    fn free_user_name(&self, base_name: &str) -> String {
+        let mut base_name: String = base_name
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-' || *c == '.')
+            .collect();
+
+        if base_name.is_empty() {
+            base_name = String::from("user");
+        }
+
+        // The name is shortened, not the suffix, so it still fits the column.
+        let mut user_name: String = base_name.chars().take(USER_NAME_MAX_LENGTH).collect();
+        let mut suffix = 1;
+
+        while self.user_repository.get_by_name(&user_name).is_ok() {
+            suffix += 1;
+
+            let suffix = suffix.to_string();
+            let prefix: String = base_name
+                .chars()
+                .take(USER_NAME_MAX_LENGTH - suffix.len())
+                .collect();
+
+            user_name = format!("{}{}", prefix, suffix);
+        }
+
+        user_name
+    }
 }
 
 #[autometrics]
@@ -39,15 +454,26 @@ impl Auth for AuthServiceImpl {
             return Err(Status::invalid_argument("Invalid email"));
         }
 
+        self.password_validator.validate(
+            "password",
+            &sign_up_request.password,
+            &sign_up_request.email,
+            &sign_up_request.user_name,
+        )?;
+
         let user_repository = self.user_repository.clone();
 
-        user_repository
+        let created_user = user_repository
             .create(&UserInformation::from(sign_up_request.clone()))
             .map_err(|e| {
                 error!("{:?}", e);
                 Status::internal("User creation failed")
             })?;
 
+        if let Err(e) = self.send_verification(&created_user) {
+            error!("Failed to send verification email: {:?}", e);
+        }
+
         Ok(Response::new(SignUpResponse {
             message: String::from("User successfully created"),
         }))
@@ -57,25 +483,60 @@ impl Auth for AuthServiceImpl {
         &self,
         request: Request<LoginRequest>,
     ) -> Result<Response<LoginResponse>, Status> {
+        let client = LoginClient::from_request(&request);
+        let ip = client.ip.clone().unwrap_or_else(|| String::from("unknown"));
+
         let login_request = request.into_inner();
 
         if self.regex_cache.check_email(&login_request.email).is_err() {
             return Err(Status::invalid_argument("Invalid email"));
         }
 
+        let account = login_request.email.to_lowercase();
+
+        let account_user_id = || {
+            self.user_repository
+                .get_by_email(&account)
+                .ok()
+                .map(|user| user.id)
+        };
+
+        if let Err(status) = self.check_login_limits(&ip, &account) {
+            self.audit_login(&client, account_user_id(), "throttled", false, false);
+            return Err(status);
+        }
+
         let user_repository = self.user_repository.clone();
 
         match user_repository.login(&LoginInformation::from(login_request)) {
             Ok(value) => {
-                let session = self.redis_repository.create(&value.id).unwrap();
-                return Ok(Response::new(LoginResponse {
-                    user_name: value.user_name,
-                    message: "User successfully logged".to_string(),
-                    session_id: session,
-                }));
+                self.login_attempt_repository
+                    .reset_failures(&account)
+                    .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+                if !value.email_verified && self.email_verification_policy.blocks_login() {
+                    self.audit_login(&client, Some(value.id), "email_not_verified", false, false);
+                    return Err(Status::failed_precondition("Email is not verified"));
+                }
+
+                if let Some(challenge) = self.two_factor_challenge(&value)? {
+                    return Ok(Response::new(challenge));
+                }
+
+                self.audit_login(&client, Some(value.id), "password", true, false);
+
+                Ok(Response::new(self.create_session(value)?))
             }
             Err(e) => {
                 error!("{:?}", e);
+                self.audit_login(
+                    &client,
+                    account_user_id(),
+                    "invalid_credentials",
+                    false,
+                    false,
+                );
+                self.register_login_failure(&account)?;
                 Err(Status::not_found("User not found"))
             }
         }
@@ -86,14 +547,921 @@ impl Auth for AuthServiceImpl {
         request: Request<LogoutRequest>,
     ) -> Result<Response<LogoutResponse>, Status> {
         let logout_request = request.into_inner();
-        match self
-            .redis_repository
-            .remove_session(&logout_request.session_id)
-        {
+
+        let result = if let Some(jwt_keys) = &self.jwt_keys {
+            self.revoke_jwt_login(jwt_keys, &logout_request)
+        } else {
+            self.redis_repository
+                .remove_session(&logout_request.session_id)
+        };
+
+        match result {
             Ok(_) => Ok(Response::new(LogoutResponse {
                 message: "User session removed".to_string(),
             })),
             Err(e) => Err(Status::internal(format!("Internal Server Error: {}", e))),
         }
     }
+
+    async fn forgot_password(
+        &self,
+        request: Request<ForgotPasswordRequest>,
+    ) -> Result<Response<ForgotPasswordResponse>, Status> {
+        let forgot_request = request.into_inner();
+
+        if self.regex_cache.check_email(&forgot_request.email).is_err() {
+            return Err(Status::invalid_argument("Invalid email"));
+        }
+
+        let response = ForgotPasswordResponse {
+            message: String::from("If the account exists, a reset link has been sent"),
+        };
+
+        // Respond the same way for unknown emails, so accounts can't be enumerated.
+        let user = match self.user_repository.get_by_email(&forgot_request.email) {
+            Ok(value) if value.service_account_team_id.is_none() => value,
+            _ => return Ok(Response::new(response)),
+        };
+
+        let token = self
+            .token_repository
+            .create(
+                PASSWORD_RESET_TOKEN,
+                &user.id.to_string(),
+                PASSWORD_RESET_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.mail_sender
+            .send(&MailMessage {
+                to: user.email,
+                subject: String::from("Taskem password reset"),
+                body: format!(
+                    "Use this token to reset your password: {}\nIt expires in {} minutes.",
+                    token,
+                    PASSWORD_RESET_EXPIRE_SECONDS / 60
+                ),
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn reset_password(
+        &self,
+        request: Request<ResetPasswordRequest>,
+    ) -> Result<Response<ResetPasswordResponse>, Status> {
+        let reset_request = request.into_inner();
+
+        if reset_request.new_password.is_empty() {
+            return Err(Status::invalid_argument("Empty password"));
+        }
+
+        // The token is only spent once the new password passed validation, so a rejected
+        // password can be corrected with the same reset link.
+        let user_id = self
+            .token_repository
+            .peek(PASSWORD_RESET_TOKEN, &reset_request.token)
+            .ok()
+            .and_then(|value| Uuid::from_str(&value).ok())
+            .ok_or_else(|| Status::invalid_argument("Invalid or expired token"))?;
+
+        let user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.password_validator.validate(
+            "new_password",
+            &reset_request.new_password,
+            &user.email,
+            &user.user_name,
+        )?;
+
+        let consumed_user_id = self
+            .token_repository
+            .consume(PASSWORD_RESET_TOKEN, &reset_request.token)
+            .ok()
+            .and_then(|value| Uuid::from_str(&value).ok());
+
+        if consumed_user_id != Some(user_id) {
+            return Err(Status::invalid_argument("Invalid or expired token"));
+        }
+
+        self.user_repository
+            .set_password(&user_id, &reset_request.new_password)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.redis_repository
+            .remove_user_sessions(&user_id, None)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.refresh_token_repository
+            .revoke_user(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(ResetPasswordResponse {
+            message: String::from("Password successfully reset"),
+        }))
+    }
+
+    async fn verify_email(
+        &self,
+        request: Request<VerifyEmailRequest>,
+    ) -> Result<Response<VerifyEmailResponse>, Status> {
+        let verify_request = request.into_inner();
+
+        let user_id = self
+            .token_repository
+            .consume(EMAIL_VERIFICATION_TOKEN, &verify_request.token)
+            .ok()
+            .and_then(|value| Uuid::from_str(&value).ok())
+            .ok_or_else(|| Status::invalid_argument("Invalid or expired token"))?;
+
+        self.user_repository
+            .set_email_verified(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(VerifyEmailResponse {
+            message: String::from("Email successfully verified"),
+        }))
+    }
+
+    async fn resend_verification(
+        &self,
+        request: Request<ResendVerificationRequest>,
+    ) -> Result<Response<ResendVerificationResponse>, Status> {
+        let resend_request = request.into_inner();
+
+        if self.regex_cache.check_email(&resend_request.email).is_err() {
+            return Err(Status::invalid_argument("Invalid email"));
+        }
+
+        let response = ResendVerificationResponse {
+            message: String::from("If the account needs verification, an email has been sent"),
+        };
+
+        match self.user_repository.get_by_email(&resend_request.email) {
+            Ok(user) if !user.email_verified => {
+                self.send_verification(&user)?;
+                Ok(Response::new(response))
+            }
+            _ => Ok(Response::new(response)),
+        }
+    }
+
+    async fn complete_two_factor_login(
+        &self,
+        request: Request<CompleteTwoFactorLoginRequest>,
+    ) -> Result<Response<LoginResponse>, Status> {
+        let client = LoginClient::from_request(&request);
+        let complete_request = request.into_inner();
+
+        let user_id = self
+            .token_repository
+            .consume(
+                TWO_FACTOR_CHALLENGE_TOKEN,
+                &complete_request.challenge_token,
+            )
+            .ok()
+            .and_then(|value| Uuid::from_str(&value).ok())
+            .ok_or_else(|| Status::invalid_argument("Invalid or expired challenge token"))?;
+
+        let two_factor = self
+            .two_factor_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let code_valid = self.use_totp_code(&two_factor, &complete_request.code)?
+            || self
+                .two_factor_repository
+                .use_recovery_code(&user_id, &complete_request.code)
+                .is_ok();
+
+        if !code_valid {
+            self.audit_login(
+                &client,
+                Some(user_id),
+                "invalid_two_factor_code",
+                false,
+                true,
+            );
+            return Err(Status::unauthenticated("Invalid two-factor code"));
+        }
+
+        let user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.audit_login(&client, Some(user_id), "password", true, true);
+
+        Ok(Response::new(self.create_session(user)?))
+    }
+
+    async fn start_oidc_login(
+        &self,
+        _request: Request<StartOidcLoginRequest>,
+    ) -> Result<Response<StartOidcLoginResponse>, Status> {
+        let oidc_provider = self.oidc_provider()?;
+
+        let login_state = OidcLoginState {
+            code_verifier: pkce::generate_code_verifier(),
+            nonce: generate_token(),
+        };
+
+        let serialized_state = serde_json::to_string(&login_state)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let state = self
+            .token_repository
+            .create(
+                OIDC_LOGIN_STATE_TOKEN,
+                &serialized_state,
+                OIDC_LOGIN_STATE_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let authorization_url = oidc_provider
+            .authorization_url(
+                &state,
+                &login_state.nonce,
+                &pkce::code_challenge(&login_state.code_verifier),
+            )
+            .await
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(StartOidcLoginResponse {
+            authorization_url,
+            state,
+        }))
+    }
+
+    async fn complete_oidc_login(
+        &self,
+        request: Request<CompleteOidcLoginRequest>,
+    ) -> Result<Response<LoginResponse>, Status> {
+        let oidc_provider = self.oidc_provider()?;
+        let client = LoginClient::from_request(&request);
+        let complete_request = request.into_inner();
+
+        let login_state = self
+            .token_repository
+            .consume(OIDC_LOGIN_STATE_TOKEN, &complete_request.state)
+            .ok()
+            .and_then(|value| serde_json::from_str::<OidcLoginState>(&value).ok())
+            .ok_or_else(|| Status::invalid_argument("Invalid or expired state"))?;
+
+        let claims = oidc_provider
+            .exchange_code(
+                &complete_request.code,
+                &login_state.code_verifier,
+                &login_state.nonce,
+            )
+            .await
+            .map_err(|e| {
+                error!("{:?}", e);
+                self.audit_login(&client, None, "oidc_failed", false, false);
+                Status::unauthenticated("OpenID Connect login failed")
+            })?;
+
+        let user = self.find_or_create_oidc_user(claims)?;
+
+        if let Some(challenge) = self.two_factor_challenge(&user)? {
+            return Ok(Response::new(challenge));
+        }
+
+        self.audit_login(&client, Some(user.id), "oidc", true, false);
+
+        Ok(Response::new(self.create_session(user)?))
+    }
+
+    async fn refresh_token(
+        &self,
+        request: Request<RefreshTokenRequest>,
+    ) -> Result<Response<LoginResponse>, Status> {
+        let jwt_keys = self
+            .jwt_keys
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("Refresh tokens are not enabled"))?;
+
+        let refresh_request = request.into_inner();
+
+        let (user_id, refresh_token) = self
+            .refresh_token_repository
+            .rotate(&refresh_request.refresh_token)
+            .map_err(|_| Status::unauthenticated("Invalid or expired refresh token"))?;
+
+        let user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if let Err(status) = Self::check_not_scheduled_for_deletion(&user) {
+            self.refresh_token_repository
+                .revoke(&refresh_token)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+            return Err(status);
+        }
+
+        Ok(Response::new(LoginResponse {
+            user_name: user.user_name,
+            message: "Token successfully refreshed".to_string(),
+            session_id: jwt_keys.issue(&user.id),
+            two_factor_required: false,
+            challenge_token: String::new(),
+            refresh_token,
+            expires_in: jwt_keys.access_token_expire_seconds(),
+        }))
+    }
+
+    async fn confirm_email_change(
+        &self,
+        request: Request<ConfirmEmailChangeRequest>,
+    ) -> Result<Response<ConfirmEmailChangeResponse>, Status> {
+        let confirm_request = request.into_inner();
+
+        let (user_id, new_email) = self
+            .token_repository
+            .consume(EMAIL_CHANGE_TOKEN, &confirm_request.token)
+            .ok()
+            .and_then(|value| {
+                let (user_id, new_email) = value.split_once(':')?;
+                Some((Uuid::from_str(user_id).ok()?, new_email.to_string()))
+            })
+            .ok_or_else(|| Status::invalid_argument("Invalid or expired token"))?;
+
+        self.user_repository
+            .set_email(&user_id, &new_email)
+            .map_err(|e| match e {
+                Error::AlreadyExistsError => Status::already_exists("Email is already in use"),
+                e => Status::internal(format!("Internal Server Error: {}", e)),
+            })?;
+
+        Ok(Response::new(ConfirmEmailChangeResponse {
+            message: String::from("Email successfully changed"),
+        }))
+    }
+
+    async fn request_magic_link(
+        &self,
+        request: Request<RequestMagicLinkRequest>,
+    ) -> Result<Response<RequestMagicLinkResponse>, Status> {
+        if !self.magic_link_enabled {
+            return Err(Status::failed_precondition("Magic link login is disabled"));
+        }
+
+        let magic_link_request = request.into_inner();
+
+        if self
+            .regex_cache
+            .check_email(&magic_link_request.email)
+            .is_err()
+        {
+            return Err(Status::invalid_argument("Invalid email"));
+        }
+
+        let account = magic_link_request.email.to_lowercase();
+
+        let allowed = self
+            .login_attempt_repository
+            .check_rate_limit(
+                &format!("magic_link_rate:email:{}", account),
+                MAGIC_LINK_RATE_LIMIT,
+                MAGIC_LINK_RATE_LIMIT_WINDOW_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if !allowed {
+            record_blocked_login("magic_link");
+            return Err(Status::resource_exhausted("Too many login links requested"));
+        }
+
+        let response = RequestMagicLinkResponse {
+            message: String::from("If the account exists, a login link has been sent"),
+        };
+
+        // Unknown emails get the same answer, so accounts can't be enumerated.
+        let user = match self.user_repository.get_by_email(&account) {
+            Ok(value) if value.service_account_team_id.is_none() => value,
+            _ => return Ok(Response::new(response)),
+        };
+
+        let token = self
+            .token_repository
+            .create(
+                MAGIC_LINK_TOKEN,
+                &user.id.to_string(),
+                MAGIC_LINK_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.mail_sender
+            .send(&MailMessage {
+                to: user.email,
+                subject: String::from("Your Taskem login link"),
+                body: format!(
+                    "Use this token to log in: {}\nIt can be used once within {} minutes.",
+                    token,
+                    MAGIC_LINK_EXPIRE_SECONDS / 60
+                ),
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn complete_magic_link_login(
+        &self,
+        request: Request<CompleteMagicLinkLoginRequest>,
+    ) -> Result<Response<LoginResponse>, Status> {
+        if !self.magic_link_enabled {
+            return Err(Status::failed_precondition("Magic link login is disabled"));
+        }
+
+        let client = LoginClient::from_request(&request);
+        let complete_request = request.into_inner();
+
+        let user_id = match self
+            .token_repository
+            .consume(MAGIC_LINK_TOKEN, &complete_request.token)
+            .ok()
+            .and_then(|value| Uuid::from_str(&value).ok())
+        {
+            Some(user_id) => user_id,
+            None => {
+                self.audit_login(&client, None, "invalid_magic_link", false, false);
+                return Err(Status::invalid_argument("Invalid or expired token"));
+            }
+        };
+
+        let mut user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        // Opening the link proves the user owns the email.
+        if !user.email_verified {
+            user = self
+                .user_repository
+                .set_email_verified(&user_id)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+        }
+
+        if let Some(challenge) = self.two_factor_challenge(&user)? {
+            return Ok(Response::new(challenge));
+        }
+
+        self.audit_login(&client, Some(user_id), "magic_link", true, false);
+
+        Ok(Response::new(self.create_session(user)?))
+    }
+
+    async fn start_passkey_login(
+        &self,
+        request: Request<StartPasskeyLoginRequest>,
+    ) -> Result<Response<StartPasskeyLoginResponse>, Status> {
+        let webauthn = self.webauthn()?;
+        let client = LoginClient::from_request(&request);
+        let ip = client.ip.clone().unwrap_or_else(|| String::from("unknown"));
+
+        let start_request = request.into_inner();
+
+        if self.regex_cache.check_email(&start_request.email).is_err() {
+            return Err(Status::invalid_argument("Invalid email"));
+        }
+
+        let account = start_request.email.to_lowercase();
+
+        let user = self
+            .user_repository
+            .get_by_email(&account)
+            .ok()
+            .filter(|user| user.service_account_team_id.is_none());
+
+        if let Err(status) = self.check_login_limits(&ip, &account) {
+            self.audit_login(
+                &client,
+                user.as_ref().map(|user| user.id),
+                "throttled",
+                false,
+                false,
+            );
+            return Err(status);
+        }
+
+        let no_passkey =
+            || Status::failed_precondition("No passkey is registered for this account");
+
+        let user = user.ok_or_else(no_passkey)?;
+
+        let passkeys = self
+            .webauthn_credential_repository
+            .get_all_for_user(&user.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?
+            .iter()
+            .filter_map(|credential| serde_json::from_str::<Passkey>(&credential.passkey).ok())
+            .collect::<Vec<_>>();
+
+        if passkeys.is_empty() {
+            return Err(no_passkey());
+        }
+
+        let (options, authentication) = webauthn
+            .start_passkey_authentication(&passkeys)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let state = serde_json::to_string(&PasskeyAuthenticationState {
+            user_id: user.id,
+            authentication,
+        })
+        .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let authentication_token = self
+            .token_repository
+            .create(
+                PASSKEY_AUTHENTICATION_TOKEN,
+                &state,
+                PASSKEY_CHALLENGE_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(StartPasskeyLoginResponse {
+            authentication_token,
+            options: serde_json::to_string(&options)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?,
+        }))
+    }
+
+    async fn complete_passkey_login(
+        &self,
+        request: Request<CompletePasskeyLoginRequest>,
+    ) -> Result<Response<LoginResponse>, Status> {
+        let webauthn = self.webauthn()?;
+        let client = LoginClient::from_request(&request);
+
+        let complete_request = request.into_inner();
+
+        let state = self
+            .token_repository
+            .consume(
+                PASSKEY_AUTHENTICATION_TOKEN,
+                &complete_request.authentication_token,
+            )
+            .ok()
+            .and_then(|value| serde_json::from_str::<PasskeyAuthenticationState>(&value).ok())
+            .ok_or_else(|| Status::invalid_argument("Invalid or expired token"))?;
+
+        let credential = serde_json::from_str::<PublicKeyCredential>(&complete_request.credential)
+            .map_err(|_| Status::invalid_argument("Invalid credential"))?;
+
+        let authentication_result =
+            match webauthn.finish_passkey_authentication(&credential, &state.authentication) {
+                Ok(value) => value,
+                Err(e) => {
+                    error!("{:?}", e);
+                    self.audit_login(
+                        &client,
+                        Some(state.user_id),
+                        "invalid_passkey",
+                        false,
+                        false,
+                    );
+                    return Err(Status::unauthenticated("Passkey verification failed"));
+                }
+            };
+
+        // The authentication options require user verification, check it as well since
+        // the passkey stands in for both factors.
+        if !authentication_result.user_verified() {
+            self.audit_login(
+                &client,
+                Some(state.user_id),
+                "passkey_not_verified",
+                false,
+                false,
+            );
+            return Err(Status::unauthenticated("Passkey verification failed"));
+        }
+
+        let credential_id = encode_credential_id(authentication_result.cred_id());
+
+        let stored = self
+            .webauthn_credential_repository
+            .get_all_for_user(&state.user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?
+            .into_iter()
+            .find(|stored| stored.credential_id == credential_id)
+            .ok_or_else(|| Status::unauthenticated("Passkey verification failed"))?;
+
+        let sign_count = i64::from(authentication_result.counter());
+        if !is_sign_count_valid(stored.sign_count, sign_count) {
+            self.audit_login(
+                &client,
+                Some(state.user_id),
+                "passkey_counter_mismatch",
+                false,
+                false,
+            );
+            return Err(Status::permission_denied(
+                "Passkey sign counter check failed",
+            ));
+        }
+
+        let mut passkey = serde_json::from_str::<Passkey>(&stored.passkey)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+        passkey.update_credential(&authentication_result);
+
+        let passkey = serde_json::to_string(&passkey)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let updated = self
+            .webauthn_credential_repository
+            .update_usage(&stored.id, &passkey, sign_count, Utc::now().timestamp())
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        // Another login stored the same or a higher counter in the meantime.
+        if !updated {
+            self.audit_login(
+                &client,
+                Some(state.user_id),
+                "passkey_counter_mismatch",
+                false,
+                false,
+            );
+            return Err(Status::permission_denied(
+                "Passkey sign counter check failed",
+            ));
+        }
+
+        let user = self
+            .user_repository
+            .get(&state.user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if !user.email_verified && self.email_verification_policy.blocks_login() {
+            self.audit_login(&client, Some(user.id), "email_not_verified", false, false);
+            return Err(Status::failed_precondition("Email is not verified"));
+        }
+
+        // The authenticator verified the user, so the passkey already counts as two
+        // factors and skips the code challenge.
+        self.audit_login(&client, Some(user.id), "passkey", true, false);
+
+        Ok(Response::new(self.create_session(user)?))
+    }
+
+    async fn cancel_account_deletion(
+        &self,
+        request: Request<CancelAccountDeletionRequest>,
+    ) -> Result<Response<CancelAccountDeletionResponse>, Status> {
+        let client = LoginClient::from_request(&request);
+        let ip = client.ip.clone().unwrap_or_else(|| String::from("unknown"));
+
+        let cancel_request = request.into_inner();
+        let account = cancel_request.email.to_lowercase();
+
+        // Takes the password like a login, so it shares the login limits.
+        self.check_login_limits(&ip, &account)?;
+
+        let user = match self.user_repository.login(&LoginInformation {
+            email: cancel_request.email,
+            password: cancel_request.password,
+        }) {
+            Ok(user) => user,
+            Err(_) => {
+                self.register_login_failure(&account)?;
+                return Err(Status::not_found("User not found"));
+            }
+        };
+
+        self.login_attempt_repository
+            .reset_failures(&account)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if user.deletion_requested_timestamp.is_none() {
+            return Err(Status::failed_precondition(
+                "Account is not scheduled for deletion",
+            ));
+        }
+
+        self.user_repository
+            .set_deletion_requested(&user.id, None)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(CancelAccountDeletionResponse {
+            message: String::from("Account deletion canceled, you can log in again"),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use webauthn_authenticator_rs::softpasskey::SoftPasskey;
+    use webauthn_authenticator_rs::WebauthnAuthenticator;
+    use webauthn_rs::prelude::{RequestChallengeResponse, Url};
+    use webauthn_rs::WebauthnBuilder;
+
+    use super::*;
+    use crate::core::webauthn::passkey_sign_count;
+    use crate::domain::models::user::password_policy::PasswordPolicy;
+    use crate::domain::models::user::webauthn_credential::WebauthnCredential;
+    use crate::domain::repositories::login_attempt::MockRedisLoginAttemptRepository;
+    use crate::domain::repositories::login_audit::MockLoginAuditRepository;
+    use crate::domain::repositories::refresh_token::MockRedisRefreshTokenRepository;
+    use crate::domain::repositories::revoked_token::MockRedisRevokedTokenRepository;
+    use crate::domain::repositories::session::MockRedisSessionRepository;
+    use crate::domain::repositories::token::MockRedisTokenRepository;
+    use crate::domain::repositories::two_factor::MockTwoFactorRepository;
+    use crate::domain::repositories::user::MockUserRepository;
+    use crate::domain::repositories::user_identity::MockUserIdentityRepository;
+    use crate::domain::repositories::webauthn_credential::MockWebauthnCredentialRepository;
+    use crate::domain::services::mail::MockMailSender;
+
+    const EMAIL: &str = "alice@example.com";
+    const AUTHENTICATION_TOKEN: &str = "authentication-token";
+    const SESSION_ID: &str = "session-id";
+
+    struct PasskeyLogin {
+        service: AuthServiceImpl,
+        authenticator: WebauthnAuthenticator<SoftPasskey>,
+        origin: Url,
+    }
+
+    /// Registers a soft passkey and builds the service around it. The credential
+    /// repository answers the counter update with `counter_updated`, like the database
+    /// would when the counter was or wasn't stored yet.
+    fn passkey_login(counter_updated: bool) -> PasskeyLogin {
+        let origin = Url::parse("https://localhost:8080").unwrap();
+        let webauthn = WebauthnBuilder::new("localhost", &origin)
+            .unwrap()
+            .rp_name("Taskem")
+            .build()
+            .unwrap();
+        let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+
+        let user = UserInformation {
+            id: Uuid::now_v7(),
+            email: EMAIL.to_string(),
+            user_name: String::from("alice"),
+            profile_image: None,
+            password: String::new(),
+            email_verified: true,
+            deletion_requested_timestamp: None,
+            service_account_team_id: None,
+        };
+
+        let (challenge, registration) = webauthn
+            .start_passkey_registration(user.id, "alice", "alice", None)
+            .unwrap();
+        let registered = authenticator
+            .do_registration(origin.clone(), challenge)
+            .unwrap();
+        let passkey = webauthn
+            .finish_passkey_registration(&registered, &registration)
+            .unwrap();
+
+        let stored = WebauthnCredential {
+            id: Uuid::now_v7(),
+            user_id: user.id,
+            credential_id: encode_credential_id(passkey.cred_id()),
+            name: String::from("Laptop"),
+            passkey: serde_json::to_string(&passkey).unwrap(),
+            sign_count: passkey_sign_count(&passkey),
+            created_timestamp: 0,
+            last_used_timestamp: None,
+        };
+
+        let mut user_repository = MockUserRepository::new();
+        let by_email = user.clone();
+        user_repository
+            .expect_get_by_email()
+            .returning(move |_| Ok(by_email.clone()));
+        user_repository
+            .expect_get()
+            .returning(move |_| Ok(user.clone()));
+
+        let mut login_attempt_repository = MockRedisLoginAttemptRepository::new();
+        login_attempt_repository
+            .expect_check_rate_limit()
+            .returning(|_, _, _| Ok(true));
+        login_attempt_repository
+            .expect_lockout_remaining()
+            .returning(|_| Ok(None));
+
+        let state = Arc::new(Mutex::new(None::<String>));
+        let mut token_repository = MockRedisTokenRepository::new();
+        let created_state = state.clone();
+        token_repository
+            .expect_create()
+            .withf(|purpose, _, _| purpose == PASSKEY_AUTHENTICATION_TOKEN)
+            .returning(move |_, value, _| {
+                *created_state.lock().unwrap() = Some(value.to_string());
+                Ok(AUTHENTICATION_TOKEN.to_string())
+            });
+        token_repository
+            .expect_consume()
+            .withf(|purpose, token| {
+                purpose == PASSKEY_AUTHENTICATION_TOKEN && token == AUTHENTICATION_TOKEN
+            })
+            .returning(move |_, _| state.lock().unwrap().take().ok_or(Error::RepositoryError));
+
+        let mut webauthn_credential_repository = MockWebauthnCredentialRepository::new();
+        let credentials = vec![stored.clone()];
+        webauthn_credential_repository
+            .expect_get_all_for_user()
+            .returning(move |_| Ok(credentials.clone()));
+        webauthn_credential_repository
+            .expect_update_usage()
+            .withf(move |id, _, sign_count, _| {
+                *id == stored.id && is_sign_count_valid(stored.sign_count, *sign_count)
+            })
+            .times(1)
+            .returning(move |_, _, _, _| Ok(counter_updated));
+
+        let mut login_audit_repository = MockLoginAuditRepository::new();
+        login_audit_repository
+            .expect_create()
+            .returning(|login_audit| Ok(login_audit.clone()));
+
+        let mut session_repository = MockRedisSessionRepository::new();
+        session_repository
+            .expect_create()
+            .returning(|_| Ok(SESSION_ID.to_string()));
+
+        let mut regex_cache = CachedRegexValidator::default();
+        regex_cache.compile_all();
+
+        let service = AuthServiceImpl::new(
+            Arc::new(regex_cache),
+            Arc::new(user_repository),
+            Arc::new(session_repository),
+            Arc::new(token_repository),
+            Arc::new(MockMailSender::new()),
+            Arc::new(MockTwoFactorRepository::new()),
+            Arc::new(login_attempt_repository),
+            EmailVerificationPolicy::Disabled,
+            LoginLimits::default(),
+            Arc::new(MockUserIdentityRepository::new()),
+            None,
+            Arc::new(MockRedisRefreshTokenRepository::new()),
+            None,
+            Arc::new(PasswordValidator::new(PasswordPolicy::default(), None)),
+            Arc::new(login_audit_repository),
+            false,
+            Arc::new(webauthn_credential_repository),
+            Some(Arc::new(webauthn)),
+            Arc::new(MockRedisRevokedTokenRepository::new()),
+            Arc::new(RevokedTokens::default()),
+        );
+
+        PasskeyLogin {
+            service,
+            authenticator,
+            origin,
+        }
+    }
+
+    async fn login(mut passkey_login: PasskeyLogin) -> Result<LoginResponse, Status> {
+        let started = passkey_login
+            .service
+            .start_passkey_login(Request::new(StartPasskeyLoginRequest {
+                email: EMAIL.to_string(),
+            }))
+            .await?
+            .into_inner();
+
+        let options = serde_json::from_str::<RequestChallengeResponse>(&started.options).unwrap();
+        let credential = passkey_login
+            .authenticator
+            .do_authentication(passkey_login.origin, options)
+            .unwrap();
+
+        passkey_login
+            .service
+            .complete_passkey_login(Request::new(CompletePasskeyLoginRequest {
+                authentication_token: started.authentication_token,
+                credential: serde_json::to_string(&credential).unwrap(),
+            }))
+            .await
+            .map(Response::into_inner)
+    }
+
+    #[tokio::test]
+    async fn test_passkey_login_creates_session() {
+        let response = login(passkey_login(true)).await.unwrap();
+
+        assert_eq!(response.session_id, SESSION_ID);
+        assert_eq!(response.user_name, "alice");
+        assert!(!response.two_factor_required);
+    }
+
+    #[tokio::test]
+    async fn test_passkey_login_rejects_counter_stored_concurrently() {
+        let status = login(passkey_login(false)).await.unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
 }
@@ -1,25 +1,115 @@
-use crate::domain::constants::MIDDLEWARE_AUTH_SESSION_KEY;
+use crate::api::password_validator::PasswordValidator;
+use crate::core::archive::zip_archive;
+use crate::core::regex::CachedRegexValidator;
+use crate::core::token::{generate_token, hash_token};
+use crate::core::totp;
+use crate::core::user_files::{copy_user_files, delete_user_files};
+use crate::core::webauthn::{encode_credential_id, passkey_sign_count};
+use crate::domain::constants::{
+    ACCESS_TOKEN_PREFIX, EMAIL_CHANGE_EXPIRE_SECONDS, EMAIL_CHANGE_TOKEN, EXPORT_CHUNK_BYTES,
+    EXPORT_MAX_BYTES, MIDDLEWARE_AUTH_SESSION_KEY, MIDDLEWARE_AUTH_USER_KEY, ONE_DAY,
+    PASSKEY_CHALLENGE_EXPIRE_SECONDS, PASSKEY_REGISTRATION_TOKEN, RECOVERY_CODES_COUNT,
+    USER_NAME_MAX_LENGTH,
+};
+use crate::domain::error::Error;
+use crate::domain::models::mail::mail_message::MailMessage;
+use crate::domain::models::task::task_filter::TaskFilter;
+use crate::domain::models::user::access_token::AccessToken;
+use crate::domain::models::user::access_token_scope::AccessTokenScope;
+use crate::domain::models::user::passkey_ceremony_state::PasskeyRegistrationState;
+use crate::domain::models::user::personal_data_export::{
+    ExportedComment, ExportedMembership, ExportedProfile, ExportedTask, PersonalDataExport,
+};
+use crate::domain::models::user::two_factor_information::TwoFactorInformation;
+use crate::domain::models::user::webauthn_credential::WebauthnCredential;
+use crate::domain::repositories::access_token::AccessTokenRepository;
+use crate::domain::repositories::comment::CommentRepository;
 use crate::domain::repositories::file::FileRepository;
+use crate::domain::repositories::login_audit::LoginAuditRepository;
+use crate::domain::repositories::refresh_token::RedisRefreshTokenRepository;
+use crate::domain::repositories::role::RoleRepository;
+use crate::domain::repositories::session::RedisSessionRepository;
+use crate::domain::repositories::task::TaskRepository;
+use crate::domain::repositories::team::TeamRepository;
+use crate::domain::repositories::token::RedisTokenRepository;
+use crate::domain::repositories::two_factor::TwoFactorRepository;
 use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::webauthn_credential::WebauthnCredentialRepository;
+use crate::domain::services::mail::MailSender;
 use crate::extract_user_id_from_metadata;
 use crate::profile::profile_server::Profile;
 use crate::profile::AddOrUpdateAvatarRequest;
+use crate::profile::ChangePasswordRequest;
 use crate::profile::GetProfileResponse;
+use crate::profile::{
+    AccessTokenResponse, CreateAccessTokenRequest, CreateAccessTokenResponse,
+    GetAccessTokensResponse, RevokeAccessTokenRequest,
+};
+use crate::profile::{ChangeEmailRequest, ChangeUserNameRequest};
+use crate::profile::{
+    ConfirmTwoFactorRequest, ConfirmTwoFactorResponse, DisableTwoFactorRequest,
+    EnableTwoFactorResponse,
+};
+use crate::profile::{
+    ExportPersonalDataChunk, RequestAccountDeletionRequest, RequestAccountDeletionResponse,
+};
+use crate::profile::{
+    FinishPasskeyRegistrationRequest, GetPasskeysResponse, PasskeyResponse, RemovePasskeyRequest,
+    StartPasskeyRegistrationResponse,
+};
+use crate::profile::{
+    FlagLoginAuditRequest, GetLoginHistoryRequest, GetLoginHistoryResponse, LoginAuditResponse,
+};
 use autometrics::autometrics;
+use chrono::Utc;
 use derive_new::new;
+use prost_types::Timestamp;
+use std::pin::Pin;
+use std::str::FromStr;
 use std::sync::Arc;
+use tokio_stream::Stream;
 use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+use webauthn_rs::prelude::{Passkey, RegisterPublicKeyCredential};
+use webauthn_rs::Webauthn;
 
 #[derive(new)]
 pub struct ProfileServiceImpl {
     pub(self) file_repository: Arc<dyn FileRepository>,
     pub(self) user_repository: Arc<dyn UserRepository>,
+    pub(self) redis_repository: Arc<dyn RedisSessionRepository>,
+    pub(self) two_factor_repository: Arc<dyn TwoFactorRepository>,
+    pub(self) access_token_repository: Arc<dyn AccessTokenRepository>,
+    pub(self) refresh_token_repository: Arc<dyn RedisRefreshTokenRepository>,
+    pub(self) task_repository: Arc<dyn TaskRepository>,
+    pub(self) team_repository: Arc<dyn TeamRepository>,
+    pub(self) role_repository: Arc<dyn RoleRepository>,
+    pub(self) account_deletion_grace_days: i64,
+    pub(self) regex_cache: Arc<CachedRegexValidator>,
+    pub(self) token_repository: Arc<dyn RedisTokenRepository>,
+    pub(self) mail_sender: Arc<dyn MailSender>,
+    pub(self) password_validator: Arc<PasswordValidator>,
+    pub(self) login_audit_repository: Arc<dyn LoginAuditRepository>,
+    pub(self) webauthn_credential_repository: Arc<dyn WebauthnCredentialRepository>,
+    pub(self) webauthn: Option<Arc<Webauthn>>,
     pub(self) file_service_url: String,
+    pub(self) comment_repository: Arc<dyn CommentRepository>,
+}
+
+impl ProfileServiceImpl {
+    fn webauthn(&self) -> Result<Arc<Webauthn>, Status> {
+        self.webauthn
+            .clone()
+            .ok_or_else(|| Status::failed_precondition("Passkeys are not configured"))
+    }
 }
 
 #[async_trait]
 #[autometrics]
 impl Profile for ProfileServiceImpl {
+    type ExportPersonalDataStream =
+        Pin<Box<dyn Stream<Item = Result<ExportPersonalDataChunk, Status>> + Send>>;
+
     async fn add_or_update_avatar(
         &self,
         request: Request<AddOrUpdateAvatarRequest>,
@@ -73,4 +163,697 @@ impl Profile for ProfileServiceImpl {
     ) -> Result<Response<GetProfileResponse>, Status> {
         todo!()
     }
+
+    async fn change_password(
+        &self,
+        request: Request<ChangePasswordRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_repository = self.user_repository.clone();
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let session_id = request
+            .metadata()
+            .get(MIDDLEWARE_AUTH_SESSION_KEY)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+
+        let change_request = request.into_inner();
+
+        if change_request.new_password.is_empty() {
+            return Err(Status::invalid_argument("Empty password"));
+        }
+
+        user_repository
+            .verify_password(&user_id, &change_request.old_password)
+            .map_err(|_| Status::permission_denied("Invalid password"))?;
+
+        let user = user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.password_validator.validate(
+            "new_password",
+            &change_request.new_password,
+            &user.email,
+            &user.user_name,
+        )?;
+
+        user_repository
+            .set_password(&user_id, &change_request.new_password)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.redis_repository
+            .remove_user_sessions(&user_id, session_id.as_deref())
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.refresh_token_repository
+            .revoke_user(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn enable_two_factor(
+        &self,
+        request: Request<()>,
+    ) -> Result<Response<EnableTwoFactorResponse>, Status> {
+        let two_factor_repository = self.two_factor_repository.clone();
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        if let Ok(two_factor) = two_factor_repository.get(&user_id) {
+            if two_factor.confirmed {
+                return Err(Status::already_exists("Two-factor is already enabled"));
+            }
+        }
+
+        let user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let secret = totp::generate_secret();
+        let provisioning_uri = totp::provisioning_uri(&secret, &user.email)
+            .ok_or_else(|| Status::internal("Failed to build provisioning uri"))?;
+
+        two_factor_repository
+            .create(&TwoFactorInformation {
+                user_id,
+                secret: secret.clone(),
+                confirmed: false,
+                last_used_step: None,
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(EnableTwoFactorResponse {
+            secret,
+            provisioning_uri,
+        }))
+    }
+
+    async fn confirm_two_factor(
+        &self,
+        request: Request<ConfirmTwoFactorRequest>,
+    ) -> Result<Response<ConfirmTwoFactorResponse>, Status> {
+        let two_factor_repository = self.two_factor_repository.clone();
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let confirm_request = request.into_inner();
+
+        let two_factor = two_factor_repository
+            .get(&user_id)
+            .map_err(|_| Status::failed_precondition("Two-factor enrollment not started"))?;
+
+        if two_factor.confirmed {
+            return Err(Status::already_exists("Two-factor is already enabled"));
+        }
+
+        let step = totp::verify_code(
+            &two_factor.secret,
+            &confirm_request.code,
+            two_factor.last_used_step,
+        )
+        .ok_or_else(|| Status::invalid_argument("Invalid two-factor code"))?;
+
+        let accepted = two_factor_repository
+            .use_step(&user_id, step)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        // Another request used the same code first.
+        if !accepted {
+            return Err(Status::invalid_argument("Invalid two-factor code"));
+        }
+
+        let recovery_codes = totp::generate_recovery_codes(RECOVERY_CODES_COUNT);
+
+        two_factor_repository
+            .confirm(&user_id, &recovery_codes)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(ConfirmTwoFactorResponse { recovery_codes }))
+    }
+
+    async fn disable_two_factor(
+        &self,
+        request: Request<DisableTwoFactorRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let disable_request = request.into_inner();
+
+        self.user_repository
+            .verify_password(&user_id, &disable_request.password)
+            .map_err(|_| Status::permission_denied("Invalid password"))?;
+
+        self.two_factor_repository
+            .remove(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn create_access_token(
+        &self,
+        request: Request<CreateAccessTokenRequest>,
+    ) -> Result<Response<CreateAccessTokenResponse>, Status> {
+        let access_token_repository = self.access_token_repository.clone();
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let create_request = request.into_inner();
+
+        if create_request.name.is_empty() || create_request.name.chars().count() > 50 {
+            return Err(Status::invalid_argument("Invalid token name"));
+        }
+
+        let scope = AccessTokenScope::from_str(&create_request.scope)
+            .map_err(|_| Status::invalid_argument("Invalid token scope"))?;
+
+        let now = Utc::now().timestamp();
+        let expires_timestamp = create_request
+            .expires_timestamp
+            .map(|timestamp| timestamp.seconds);
+
+        if expires_timestamp.is_some_and(|expires| expires <= now) {
+            return Err(Status::invalid_argument("Expiration must be in the future"));
+        }
+
+        let token = format!("{}{}", ACCESS_TOKEN_PREFIX, generate_token());
+
+        let created = access_token_repository
+            .create(&AccessToken {
+                id: Uuid::now_v7(),
+                user_id,
+                name: create_request.name,
+                token_hash: hash_token(&token),
+                scope,
+                created_timestamp: now,
+                expires_timestamp,
+                last_used_timestamp: None,
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(CreateAccessTokenResponse {
+            id: created.id.to_string(),
+            token,
+        }))
+    }
+
+    async fn get_access_tokens(
+        &self,
+        request: Request<()>,
+    ) -> Result<Response<GetAccessTokensResponse>, Status> {
+        let access_token_repository = self.access_token_repository.clone();
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let tokens = access_token_repository
+            .get_all_for_user(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(GetAccessTokensResponse {
+            tokens: tokens.into_iter().map(AccessTokenResponse::from).collect(),
+        }))
+    }
+
+    async fn revoke_access_token(
+        &self,
+        request: Request<RevokeAccessTokenRequest>,
+    ) -> Result<Response<()>, Status> {
+        let access_token_repository = self.access_token_repository.clone();
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let revoke_request = request.into_inner();
+
+        let token_id = Uuid::from_str(&revoke_request.id)
+            .map_err(|_| Status::invalid_argument("Invalid token id"))?;
+
+        access_token_repository
+            .remove(&token_id, &user_id)
+            .map_err(|_| Status::not_found("Token not found"))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn export_personal_data(
+        &self,
+        request: Request<()>,
+    ) -> Result<Response<Self::ExportPersonalDataStream>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let teams = self
+            .team_repository
+            .get_user_teams(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let memberships = teams
+            .iter()
+            .map(|team| {
+                let role = self
+                    .role_repository
+                    .get_by_team_and_user_id(&team.id, &user_id)
+                    .ok();
+                ExportedMembership::new(team, role.as_ref())
+            })
+            .collect();
+
+        let tasks_created = self
+            .task_repository
+            .get_all_created_by(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let tasks_assigned = self
+            .task_repository
+            .query(
+                &TaskFilter {
+                    assignee_id: Some(user_id),
+                    ..Default::default()
+                },
+                None,
+                1000,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let comments = self
+            .comment_repository
+            .get_all_by_author(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let export = PersonalDataExport {
+            profile: ExportedProfile::from(&user),
+            memberships,
+            tasks_created: tasks_created.iter().map(ExportedTask::from).collect(),
+            tasks_assigned: tasks_assigned.iter().map(ExportedTask::from).collect(),
+            comments: comments.iter().map(ExportedComment::from).collect(),
+        };
+
+        let data = serde_json::to_vec_pretty(&export)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        // The archive is built in memory, so the files it takes are capped.
+        let mut total_bytes = data.len();
+        let mut entries = vec![(String::from("data.json"), data)];
+
+        let file_keys = self
+            .file_repository
+            .list("users", &format!("{}/", user.user_name))
+            .await
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        for key in file_keys {
+            let content = self
+                .file_repository
+                .download("users", &key)
+                .await
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+            total_bytes += content.len();
+
+            if total_bytes > EXPORT_MAX_BYTES {
+                return Err(Status::resource_exhausted(
+                    "Personal data export is too large",
+                ));
+            }
+
+            entries.push((format!("files/{}", key), content));
+        }
+
+        let archive = zip_archive(&entries)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let file_name = format!("taskem-{}.zip", user.user_name);
+
+        // Streamed in chunks, a whole archive easily exceeds the gRPC message limit.
+        let chunks = archive
+            .chunks(EXPORT_CHUNK_BYTES)
+            .enumerate()
+            .map(|(index, chunk)| {
+                Ok(ExportPersonalDataChunk {
+                    file_name: if index == 0 {
+                        file_name.clone()
+                    } else {
+                        String::new()
+                    },
+                    data: chunk.to_vec(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Response::new(Box::pin(tokio_stream::iter(chunks))))
+    }
+
+    async fn request_account_deletion(
+        &self,
+        request: Request<RequestAccountDeletionRequest>,
+    ) -> Result<Response<RequestAccountDeletionResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let deletion_request = request.into_inner();
+
+        self.user_repository
+            .verify_password(&user_id, &deletion_request.password)
+            .map_err(|_| Status::permission_denied("Invalid password"))?;
+
+        let now = Utc::now().timestamp();
+
+        self.user_repository
+            .set_deletion_requested(&user_id, Some(now))
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        // Logins are refused from now on, the deletion is canceled through the auth
+        // service with the password.
+        self.redis_repository
+            .remove_user_sessions(&user_id, None)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.refresh_token_repository
+            .revoke_user(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(RequestAccountDeletionResponse {
+            deletion_timestamp: Some(Timestamp {
+                seconds: now + self.account_deletion_grace_days * ONE_DAY,
+                nanos: 0,
+            }),
+        }))
+    }
+
+    async fn change_email(
+        &self,
+        request: Request<ChangeEmailRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let change_request = request.into_inner();
+        let new_email = change_request.new_email.to_lowercase();
+
+        if self.regex_cache.check_email(&new_email).is_err() {
+            return Err(Status::invalid_argument("Invalid email"));
+        }
+
+        self.user_repository
+            .verify_password(&user_id, &change_request.password)
+            .map_err(|_| Status::permission_denied("Invalid password"))?;
+
+        if self.user_repository.get_by_email(&new_email).is_ok() {
+            return Err(Status::already_exists("Email is already in use"));
+        }
+
+        let token = self
+            .token_repository
+            .create(
+                EMAIL_CHANGE_TOKEN,
+                &format!("{}:{}", user_id, new_email),
+                EMAIL_CHANGE_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        self.mail_sender
+            .send(&MailMessage {
+                to: new_email,
+                subject: String::from("Confirm your new Taskem email"),
+                body: format!("Use this token to confirm your new email: {}", token),
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn change_user_name(
+        &self,
+        request: Request<ChangeUserNameRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let new_user_name = request.into_inner().new_user_name;
+
+        let length = new_user_name.chars().count();
+
+        if length < 4 || length > USER_NAME_MAX_LENGTH || new_user_name.contains('/') {
+            return Err(Status::invalid_argument("Invalid user name"));
+        }
+
+        let user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if user.user_name == new_user_name {
+            return Ok(Response::new(()));
+        }
+
+        if self.user_repository.get_by_name(&new_user_name).is_ok() {
+            return Err(Status::already_exists("User name is already taken"));
+        }
+
+        let old_prefix = format!("{}/{}/", self.file_service_url, user.user_name);
+        let profile_image = user.profile_image.clone().map(|profile_image| {
+            match profile_image.strip_prefix(&old_prefix) {
+                Some(file_name) => {
+                    format!("{}/{}/{}", self.file_service_url, new_user_name, file_name)
+                }
+                None => profile_image,
+            }
+        });
+
+        // Claim the name before touching storage: only the winner of a concurrent rename
+        // owns the new prefix, so it is the only one allowed to write or clean up there.
+        self.user_repository
+            .set_user_name(&user_id, &new_user_name, profile_image.as_deref())
+            .map_err(|e| match e {
+                Error::AlreadyExistsError => Status::already_exists("User name is already taken"),
+                e => Status::internal(format!("Internal Server Error: {}", e)),
+            })?;
+
+        match copy_user_files(
+            self.file_repository.as_ref(),
+            &user.user_name,
+            &new_user_name,
+        )
+        .await
+        {
+            Ok((old_keys, _)) => {
+                delete_user_files(self.file_repository.as_ref(), &old_keys).await;
+                Ok(Response::new(()))
+            }
+            Err(e) => {
+                if let Err(rollback_error) = self.user_repository.set_user_name(
+                    &user_id,
+                    &user.user_name,
+                    user.profile_image.as_deref(),
+                ) {
+                    tracing::error!(
+                        "Failed to restore user name of {}: {:?}",
+                        user_id,
+                        rollback_error
+                    );
+                }
+
+                Err(Status::internal(format!("Internal Server Error: {}", e)))
+            }
+        }
+    }
+
+    async fn get_login_history(
+        &self,
+        request: Request<GetLoginHistoryRequest>,
+    ) -> Result<Response<GetLoginHistoryResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let history_request = request.into_inner();
+
+        let page_size = match history_request.page_size {
+            0 => 50,
+            size => size.clamp(1, 100),
+        } as i64;
+
+        let before_id = match history_request.page_token.as_str() {
+            "" => None,
+            token => Some(
+                Uuid::from_str(token)
+                    .map_err(|_| Status::invalid_argument("Invalid page token"))?,
+            ),
+        };
+
+        let entries = self
+            .login_audit_repository
+            .get_page_for_user(&user_id, before_id.as_ref(), page_size)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let next_page_token = match entries.last() {
+            Some(last) if entries.len() as i64 == page_size => last.id.to_string(),
+            _ => String::new(),
+        };
+
+        Ok(Response::new(GetLoginHistoryResponse {
+            entries: entries.into_iter().map(LoginAuditResponse::from).collect(),
+            next_page_token,
+        }))
+    }
+
+    async fn flag_login_audit(
+        &self,
+        request: Request<FlagLoginAuditRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let flag_request = request.into_inner();
+
+        let login_audit_id = Uuid::from_str(&flag_request.id)
+            .map_err(|_| Status::invalid_argument("Invalid login audit id"))?;
+
+        self.login_audit_repository
+            .set_suspicious(&login_audit_id, &user_id, flag_request.suspicious)
+            .map_err(|_| Status::not_found("Login audit entry not found"))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn start_passkey_registration(
+        &self,
+        request: Request<()>,
+    ) -> Result<Response<StartPasskeyRegistrationResponse>, Status> {
+        let webauthn = self.webauthn()?;
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let user = self
+            .user_repository
+            .get(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if user.service_account_team_id.is_some() {
+            return Err(Status::failed_precondition(
+                "Service accounts can't register passkeys",
+            ));
+        }
+
+        // Already registered authenticators are excluded so they aren't enrolled twice.
+        let registered = self
+            .webauthn_credential_repository
+            .get_all_for_user(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?
+            .iter()
+            .filter_map(|credential| serde_json::from_str::<Passkey>(&credential.passkey).ok())
+            .map(|passkey| passkey.cred_id().clone())
+            .collect::<Vec<_>>();
+
+        let (options, registration) = webauthn
+            .start_passkey_registration(user.id, &user.user_name, &user.user_name, Some(registered))
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let state = serde_json::to_string(&PasskeyRegistrationState {
+            user_id,
+            registration,
+        })
+        .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let registration_token = self
+            .token_repository
+            .create(
+                PASSKEY_REGISTRATION_TOKEN,
+                &state,
+                PASSKEY_CHALLENGE_EXPIRE_SECONDS,
+            )
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(StartPasskeyRegistrationResponse {
+            registration_token,
+            options: serde_json::to_string(&options)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?,
+        }))
+    }
+
+    async fn finish_passkey_registration(
+        &self,
+        request: Request<FinishPasskeyRegistrationRequest>,
+    ) -> Result<Response<PasskeyResponse>, Status> {
+        let webauthn = self.webauthn()?;
+
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let finish_request = request.into_inner();
+
+        if finish_request.name.is_empty() || finish_request.name.chars().count() > 50 {
+            return Err(Status::invalid_argument("Invalid passkey name"));
+        }
+
+        let state = self
+            .token_repository
+            .consume(
+                PASSKEY_REGISTRATION_TOKEN,
+                &finish_request.registration_token,
+            )
+            .ok()
+            .and_then(|value| serde_json::from_str::<PasskeyRegistrationState>(&value).ok())
+            .filter(|state| state.user_id == user_id)
+            .ok_or_else(|| Status::invalid_argument("Invalid or expired token"))?;
+
+        let credential =
+            serde_json::from_str::<RegisterPublicKeyCredential>(&finish_request.credential)
+                .map_err(|_| Status::invalid_argument("Invalid credential"))?;
+
+        let passkey = webauthn
+            .finish_passkey_registration(&credential, &state.registration)
+            .map_err(|_| Status::invalid_argument("Passkey registration failed"))?;
+
+        let created = self
+            .webauthn_credential_repository
+            .create(&WebauthnCredential {
+                id: Uuid::now_v7(),
+                user_id,
+                credential_id: encode_credential_id(passkey.cred_id()),
+                name: finish_request.name,
+                passkey: serde_json::to_string(&passkey)
+                    .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?,
+                sign_count: passkey_sign_count(&passkey),
+                created_timestamp: Utc::now().timestamp(),
+                last_used_timestamp: None,
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(PasskeyResponse::from(created)))
+    }
+
+    async fn get_passkeys(
+        &self,
+        request: Request<()>,
+    ) -> Result<Response<GetPasskeysResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let passkeys = self
+            .webauthn_credential_repository
+            .get_all_for_user(&user_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(GetPasskeysResponse {
+            passkeys: passkeys.into_iter().map(PasskeyResponse::from).collect(),
+        }))
+    }
+
+    async fn remove_passkey(
+        &self,
+        request: Request<RemovePasskeyRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let remove_request = request.into_inner();
+
+        let passkey_id = Uuid::from_str(&remove_request.id)
+            .map_err(|_| Status::invalid_argument("Invalid passkey id"))?;
+
+        self.webauthn_credential_repository
+            .remove(&passkey_id, &user_id)
+            .map_err(|_| Status::not_found("Passkey not found"))?;
+
+        Ok(Response::new(()))
+    }
 }
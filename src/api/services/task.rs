@@ -1,27 +1,374 @@
+use std::ops::Add;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use autometrics::autometrics;
+use chrono::{TimeDelta, Utc};
 use derive_new::new;
 use tonic::{async_trait, Request, Response, Status};
 use tracing::error;
 use uuid::Uuid;
 
-use crate::domain::constants::MIDDLEWARE_AUTH_SESSION_KEY;
+use crate::core::board::{column_index, reopens_under_closed_parent};
+use crate::core::ordering::insert_at;
+use crate::core::recurrence::RecurrenceRule;
+use crate::domain::constants::{
+    MIDDLEWARE_AUTH_USER_KEY, TASK_BOARD_PAGE_SIZE, TASK_CHECKLIST_MAX_ITEMS,
+    TASK_COMMENT_MAX_LENGTH, TASK_PAGE_SIZE, TASK_PAGE_SIZE_MAX, TASK_SEARCH_LIMIT,
+    TASK_SEARCH_LIMIT_MAX,
+};
+use crate::domain::error::Error;
+use crate::domain::models::task::board_column::BoardColumn;
+use crate::domain::models::task::checklist_item::ChecklistItem;
 use crate::domain::models::task::task_assign::TaskAssign;
+use crate::domain::models::task::task_comment::TaskComment;
+use crate::domain::models::task::task_cursor::TaskCursor;
+use crate::domain::models::task::task_dependency::TaskDependency;
+use crate::domain::models::task::task_filter::{TaskFilter, TaskSort, TaskSortField};
 use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_label::TaskLabel;
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::domain::models::task::task_status::TaskStatus;
+use crate::domain::repositories::board::BoardRepository;
+use crate::domain::repositories::checklist::ChecklistRepository;
+use crate::domain::repositories::comment::CommentRepository;
+use crate::domain::repositories::label::LabelRepository;
 use crate::domain::repositories::role::RoleRepository;
 use crate::domain::repositories::task::TaskRepository;
+use crate::extract_user_id_from_metadata;
 use crate::task::task_server::Task;
 use crate::task::{
-    AssignTaskRequest, CompleteTaskRequest, CreateTaskRequest, GetAllResponse, GetTaskRequest,
-    GetTeamTasksRequest, TaskResponse,
+    AddBlockerRequest, AddChecklistItemRequest, AddCommentRequest, AssignTaskRequest,
+    BoardColumnResponse, BoardColumnTasks, ChecklistItemResponse, ChecklistResponse,
+    CommentResponse, CommentsResponse, CompleteTaskRequest, CreateBoardColumnRequest,
+    CreateSubtaskRequest, CreateTaskRequest, DeleteBoardColumnRequest, DeleteChecklistItemRequest,
+    DeleteCommentRequest, GetAllResponse, GetBlockersRequest, GetBoardRequest, GetBoardResponse,
+    GetChecklistRequest, GetChildrenRequest, GetCommentsRequest, GetTaskRequest,
+    MoveChecklistItemRequest, MoveTaskRequest, MoveToColumnRequest, QueryTasksRequest,
+    QueryTasksResponse, RemoveBlockerRequest, SearchTasksRequest, SearchTasksResponse,
+    SetRecurrenceRequest, SetTaskPriorityRequest, TaskLabelRequest, TaskResponse, TaskSearchHit,
+    ToggleChecklistItemRequest, UpdateBoardColumnRequest,
 };
 
 #[derive(new)]
 pub struct TaskServiceImpl {
     pub(self) task_repository: Arc<dyn TaskRepository>,
     pub(self) role_repository: Arc<dyn RoleRepository>,
+    pub(self) label_repository: Arc<dyn LabelRepository>,
+    pub(self) board_repository: Arc<dyn BoardRepository>,
+    pub(self) checklist_repository: Arc<dyn ChecklistRepository>,
+    pub(self) comment_repository: Arc<dyn CommentRepository>,
+}
+
+impl TaskServiceImpl {
+    fn check_can_add_task(&self, team_id: &Uuid, user_id: &Uuid) -> Result<(), Status> {
+        let role = self
+            .role_repository
+            .get_by_team_and_user_id(team_id, user_id)
+            .map_err(|_| Status::permission_denied("Can't create task"))?;
+
+        if !role.can_add_task {
+            return Err(Status::permission_denied("Can't create task"));
+        }
+
+        Ok(())
+    }
+
+    fn get_task(&self, task_id: &str) -> Result<TaskInformation, Status> {
+        let task_id =
+            Uuid::from_str(task_id).map_err(|_| Status::invalid_argument("Invalid task id"))?;
+
+        self.task_repository
+            .get(&task_id)
+            .map_err(|_| Status::not_found("Task not found"))
+    }
+
+    /// An empty priority means the task has none.
+    fn parse_priority(priority: &str) -> Result<TaskPriority, Status> {
+        if priority.is_empty() {
+            return Ok(TaskPriority::None);
+        }
+
+        TaskPriority::from_str(priority).map_err(|_| Status::invalid_argument("Invalid priority"))
+    }
+
+    fn parse_id(id: &str, message: &str) -> Result<Option<Uuid>, Status> {
+        if id.is_empty() {
+            return Ok(None);
+        }
+
+        Uuid::from_str(id)
+            .map(Some)
+            .map_err(|_| Status::invalid_argument(message.to_string()))
+    }
+
+    /// Builds the filter of a query, scoped to the teams of `user_id`.
+    fn parse_filter(query: &QueryTasksRequest, user_id: &Uuid) -> Result<TaskFilter, Status> {
+        let statuses = query
+            .statuses
+            .iter()
+            .map(|status| TaskStatus::from_str(status))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| Status::invalid_argument("Invalid status"))?;
+
+        let label_ids = query
+            .label_ids
+            .iter()
+            .map(|label_id| Uuid::from_str(label_id))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| Status::invalid_argument("Invalid label id"))?;
+
+        let priorities = query
+            .priorities
+            .iter()
+            .map(|priority| Self::parse_priority(priority))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if query.sort.len() > 5 {
+            return Err(Status::invalid_argument("Too many sort keys"));
+        }
+
+        let sort = query
+            .sort
+            .iter()
+            .map(|key| {
+                let field = match key.field.as_str() {
+                    "end_timestamp" => TaskSortField::EndTimestamp,
+                    "created_timestamp" => TaskSortField::CreatedTimestamp,
+                    "priority" => TaskSortField::Priority,
+                    "name" => TaskSortField::Name,
+                    "status" => TaskSortField::Status,
+                    "position" => TaskSortField::Position,
+                    _ => return Err(Status::invalid_argument("Invalid sort field")),
+                };
+
+                Ok(TaskSort {
+                    field,
+                    descending: key.descending,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let text = query.text.trim();
+
+        Ok(TaskFilter {
+            member_id: Some(*user_id),
+            team_id: Self::parse_id(&query.team_id, "Invalid team id")?,
+            statuses,
+            assignee_id: Self::parse_id(&query.assignee_id, "Invalid assignee id")?,
+            creator_id: Self::parse_id(&query.creator_id, "Invalid creator id")?,
+            due_before: query.due_before.as_ref().map(|due| due.seconds),
+            due_after: query.due_after.as_ref().map(|due| due.seconds),
+            text: (!text.is_empty()).then(|| text.to_string()),
+            label_ids,
+            priorities,
+            sort,
+        })
+    }
+
+    /// Loads a task and a label of its team, checking the caller may label tasks there.
+    fn get_task_and_label(
+        &self,
+        label_request: &TaskLabelRequest,
+        user_id: &Uuid,
+    ) -> Result<(TaskInformation, Uuid), Status> {
+        let task = self.get_task(&label_request.task_id)?;
+
+        let label_id = Uuid::from_str(&label_request.label_id)
+            .map_err(|_| Status::invalid_argument("Invalid label id"))?;
+
+        let label = self
+            .label_repository
+            .get(&label_id)
+            .map_err(|_| Status::not_found("Label not found"))?;
+
+        if label.team_id != task.team_id {
+            return Err(Status::invalid_argument("Label belongs to another team"));
+        }
+
+        self.check_can_add_task(&task.team_id, user_id)?;
+
+        Ok((task, label.id))
+    }
+
+    /// A task is blocked while any of its blockers isn't finished.
+    fn is_blocked(&self, task_id: &Uuid) -> Result<bool, Status> {
+        let blockers = self
+            .task_repository
+            .get_blockers(task_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        // A canceled blocker will never finish, so it no longer holds the task back.
+        Ok(blockers.iter().any(|blocker| blocker.status.is_open()))
+    }
+
+    /// A task can only be finished once its subtasks and blockers are.
+    fn check_can_finish(&self, task: &TaskInformation) -> Result<(), Status> {
+        if task.children_finished < task.children_total {
+            return Err(Status::failed_precondition("Task has unfinished subtasks"));
+        }
+
+        if self.is_blocked(&task.id)? {
+            return Err(Status::failed_precondition("Task has unfinished blockers"));
+        }
+
+        Ok(())
+    }
+
+    /// Changing the board is limited to members who can manage roles.
+    fn check_can_manage_board(&self, team_id: &Uuid, user_id: &Uuid) -> Result<(), Status> {
+        let role = self
+            .role_repository
+            .get_by_team_and_user_id(team_id, user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        if !role.can_create_roles {
+            return Err(Status::permission_denied("Can't change the board"));
+        }
+
+        Ok(())
+    }
+
+    /// Loads the columns of a team, creating the default board on first use.
+    fn get_board_columns(&self, team_id: &Uuid) -> Result<Vec<BoardColumn>, Status> {
+        let columns = self
+            .board_repository
+            .get_columns(team_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        if !columns.is_empty() {
+            return Ok(columns);
+        }
+
+        let defaults = [
+            ("To do", TaskStatus::Paused),
+            ("In progress", TaskStatus::InProgress),
+            ("Done", TaskStatus::Finished),
+            ("Canceled", TaskStatus::Canceled),
+        ];
+
+        for (position, (name, status)) in defaults.into_iter().enumerate() {
+            let created = self.board_repository.create_column(&BoardColumn {
+                id: Uuid::now_v7(),
+                team_id: *team_id,
+                name: name.to_string(),
+                status,
+                position: position as i32,
+            });
+
+            // Another request created the default board first.
+            match created {
+                Ok(_) | Err(Error::AlreadyExistsError) => {}
+                Err(e) => return Err(Status::internal(format!("Internal Server Error: {}", e))),
+            }
+        }
+
+        self.board_repository
+            .get_columns(team_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))
+    }
+
+    /// Every status needs a column, otherwise its tasks would drop off the board.
+    fn check_statuses_covered(columns: &[BoardColumn]) -> Result<(), Status> {
+        let statuses = [
+            TaskStatus::Paused,
+            TaskStatus::InProgress,
+            TaskStatus::Finished,
+            TaskStatus::Canceled,
+        ];
+
+        for status in statuses {
+            if !columns.iter().any(|column| column.status == status) {
+                return Err(Status::failed_precondition(format!(
+                    "The board needs a column for {} tasks",
+                    String::from(status)
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Normalizes a recurrence rule, an empty rule means the task doesn't recur.
+    fn parse_recurrence(rule: &str) -> Result<Option<String>, Status> {
+        if rule.trim().is_empty() {
+            return Ok(None);
+        }
+
+        RecurrenceRule::parse(rule)
+            .map(|rule| Some(rule.to_string()))
+            .ok_or_else(|| Status::invalid_argument("Invalid recurrence rule"))
+    }
+
+    fn parse_status(status: &str) -> Result<TaskStatus, Status> {
+        TaskStatus::from_str(status).map_err(|_| Status::invalid_argument("Invalid status"))
+    }
+
+    fn check_column_name(name: &str) -> Result<(), Status> {
+        if name.is_empty() || name.chars().count() > 50 {
+            return Err(Status::invalid_argument("Invalid column name"));
+        }
+
+        Ok(())
+    }
+
+    fn map_column_error(e: Error) -> Status {
+        match e {
+            Error::AlreadyExistsError => Status::already_exists("Column name is already taken"),
+            e => Status::internal(format!("Internal Server Error: {}", e)),
+        }
+    }
+
+    /// Loads a checklist item and the task it belongs to.
+    fn get_checklist_item(
+        &self,
+        item_id: &str,
+    ) -> Result<(ChecklistItem, TaskInformation), Status> {
+        let item_id =
+            Uuid::from_str(item_id).map_err(|_| Status::invalid_argument("Invalid item id"))?;
+
+        let item = self
+            .checklist_repository
+            .get_item(&item_id)
+            .map_err(|_| Status::not_found("Checklist item not found"))?;
+
+        let task = self
+            .task_repository
+            .get(&item.task_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok((item, task))
+    }
+
+    fn get_checklist_response(&self, task_id: &Uuid) -> Result<ChecklistResponse, Status> {
+        let items = self
+            .checklist_repository
+            .get_items(task_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(ChecklistResponse {
+            items: items.into_iter().map(ChecklistItemResponse::from).collect(),
+        })
+    }
+
+    fn check_checklist_text(text: &str) -> Result<(), Status> {
+        if text.is_empty() || text.chars().count() > 200 {
+            return Err(Status::invalid_argument("Invalid checklist item text"));
+        }
+
+        Ok(())
+    }
+
+    /// Subtasks can only be added to open tasks, otherwise a finished parent would
+    /// get open children.
+    fn check_open_parent(parent: &TaskInformation) -> Result<(), Status> {
+        match parent.status {
+            TaskStatus::Finished | TaskStatus::Canceled => {
+                Err(Status::failed_precondition("Parent task is already closed"))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -33,11 +380,11 @@ impl Task for TaskServiceImpl {
 
         let metadata = request.metadata().clone();
 
-        if !metadata.contains_key(MIDDLEWARE_AUTH_SESSION_KEY) {
+        if !metadata.contains_key(MIDDLEWARE_AUTH_USER_KEY) {
             return Err(Status::unauthenticated("Unauthenticated".to_string()));
         }
 
-        let metadata_value = metadata.get(MIDDLEWARE_AUTH_SESSION_KEY).unwrap();
+        let metadata_value = metadata.get(MIDDLEWARE_AUTH_USER_KEY).unwrap();
 
         let user_id = match Uuid::from_str(
             metadata_value
@@ -70,8 +417,17 @@ impl Task for TaskServiceImpl {
             }
         }
 
+        let priority = Self::parse_priority(&create_request.priority)?;
+        let recurrence_rule = Self::parse_recurrence(&create_request.recurrence_rule)?;
+
         let mut task_information = TaskInformation::from(create_request);
         task_information.creator = user_id;
+        task_information.priority = priority;
+        task_information.recurrence_pending = recurrence_rule.is_some();
+        task_information.recurrence_anchor = recurrence_rule
+            .as_ref()
+            .map(|_| task_information.end_timestamp);
+        task_information.recurrence_rule = recurrence_rule;
 
         match task_repository.create(&task_information) {
             Ok(_) => Ok(Response::new(())),
@@ -79,32 +435,19 @@ impl Task for TaskServiceImpl {
         }
     }
 
-    async fn get_all(&self, _: Request<()>) -> Result<Response<GetAllResponse>, Status> {
-        let task_repository = self.task_repository.clone();
-
-        match task_repository.get_all() {
-            Ok(value) => Ok(Response::new(GetAllResponse {
-                tasks: value.iter().map(TaskResponse::from).collect(),
-            })),
-            Err(e) => Err(Status::internal(format!("Internal Server Error: {}", e))),
-        }
-    }
+    async fn get_all(&self, request: Request<()>) -> Result<Response<GetAllResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
 
-    async fn get_all_for_team(
-        &self,
-        request: Request<GetTeamTasksRequest>,
-    ) -> Result<Response<GetAllResponse>, Status> {
-        let get_team_tasks_request = request.into_inner();
-        let task_repository = self.task_repository.clone();
-
-        let team_id = match Uuid::from_str(&get_team_tasks_request.team_id) {
-            Ok(value) => value,
-            Err(_) => {
-                return Err(Status::invalid_argument("Invalid task id"));
-            }
+        let filter = TaskFilter {
+            member_id: Some(user_id),
+            sort: vec![TaskSort {
+                field: TaskSortField::Name,
+                descending: false,
+            }],
+            ..Default::default()
         };
 
-        match task_repository.get_all_for_team(&team_id) {
+        match self.task_repository.query(&filter, None, 1000) {
             Ok(value) => Ok(Response::new(GetAllResponse {
                 tasks: value.iter().map(TaskResponse::from).collect(),
             })),
@@ -112,37 +455,55 @@ impl Task for TaskServiceImpl {
         }
     }
 
-    async fn get_all_for_user(
+    async fn query(
         &self,
-        request: Request<()>,
-    ) -> Result<Response<GetAllResponse>, Status> {
-        let task_repository = self.task_repository.clone();
+        request: Request<QueryTasksRequest>,
+    ) -> Result<Response<QueryTasksResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
 
-        let metadata = request.metadata().clone();
+        let query_request = request.into_inner();
 
-        if !metadata.contains_key(MIDDLEWARE_AUTH_SESSION_KEY) {
-            return Err(Status::unauthenticated("Unauthenticated".to_string()));
+        let filter = Self::parse_filter(&query_request, &user_id)?;
+
+        if let Some(team_id) = &filter.team_id {
+            self.role_repository
+                .get_by_team_and_user_id(team_id, &user_id)
+                .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
         }
 
-        let metadata_value = metadata.get(MIDDLEWARE_AUTH_SESSION_KEY).unwrap();
+        let after = match query_request.cursor.as_str() {
+            "" => None,
+            cursor => Some(
+                TaskCursor::decode(cursor)
+                    .ok_or_else(|| Status::invalid_argument("Invalid cursor"))?,
+            ),
+        };
 
-        let user_id = match Uuid::from_str(
-            metadata_value
-                .to_str()
-                .expect("Failed to convert metadata value to str"),
-        ) {
-            Ok(value) => value,
-            Err(_) => {
-                return Err(Status::invalid_argument("Invalid user id"));
+        let page_size = match query_request.page_size as i64 {
+            0 => TASK_PAGE_SIZE,
+            page_size if page_size < 0 => {
+                return Err(Status::invalid_argument("Invalid page size"));
             }
+            page_size => page_size.min(TASK_PAGE_SIZE_MAX),
         };
 
-        match task_repository.get_all_for_user(&user_id) {
-            Ok(value) => Ok(Response::new(GetAllResponse {
-                tasks: value.iter().map(TaskResponse::from).collect(),
-            })),
-            Err(e) => Err(Status::internal(format!("Internal Server Error: {}", e))),
-        }
+        // One extra task tells whether there is a next page.
+        let mut tasks = self
+            .task_repository
+            .query(&filter, after.as_ref(), page_size + 1)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let next_cursor = if tasks.len() as i64 > page_size {
+            tasks.truncate(page_size as usize);
+            tasks.last().map(|task| TaskCursor::from(task).encode())
+        } else {
+            None
+        };
+
+        Ok(Response::new(QueryTasksResponse {
+            tasks: tasks.iter().map(TaskResponse::from).collect(),
+            next_cursor: next_cursor.unwrap_or_default(),
+        }))
     }
 
     async fn get(
@@ -179,11 +540,11 @@ impl Task for TaskServiceImpl {
 
         let metadata = request.metadata().clone();
 
-        if !metadata.contains_key(MIDDLEWARE_AUTH_SESSION_KEY) {
+        if !metadata.contains_key(MIDDLEWARE_AUTH_USER_KEY) {
             return Err(Status::unauthenticated("Unauthenticated".to_string()));
         }
 
-        let metadata_value = metadata.get(MIDDLEWARE_AUTH_SESSION_KEY).unwrap();
+        let metadata_value = metadata.get(MIDDLEWARE_AUTH_USER_KEY).unwrap();
         let assign_request = request.into_inner();
 
         let creator_user_id = match Uuid::from_str(
@@ -250,7 +611,10 @@ impl Task for TaskServiceImpl {
             user_id,
         };
 
-        match task_repository.assign(&task_assign) {
+        // Blocked tasks can be assigned ahead of time but stay paused.
+        let start = !self.is_blocked(&task_id)?;
+
+        match task_repository.assign(&task_assign, start) {
             Ok(value) => {
                 let assigned_task = match task_repository.get(&value.task_id) {
                     Ok(value) => value,
@@ -274,11 +638,812 @@ impl Task for TaskServiceImpl {
         let task_repository = self.task_repository.clone();
         let complete_request = request.into_inner();
 
-        let task_id = Uuid::from_str(complete_request.task_id.as_str())
-            .map_err(|_| Status::invalid_argument("Invalid task id"))?;
+        let task = self.get_task(&complete_request.task_id)?;
+
+        self.check_can_finish(&task)?;
 
         task_repository
-            .complete(&task_id)
+            .complete(&task.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn create_subtask(
+        &self,
+        request: Request<CreateSubtaskRequest>,
+    ) -> Result<Response<TaskResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let create_request = request.into_inner();
+
+        let parent = self.get_task(&create_request.parent_id)?;
+
+        self.check_can_add_task(&parent.team_id, &user_id)?;
+        Self::check_open_parent(&parent)?;
+
+        let priority = Self::parse_priority(&create_request.priority)?;
+
+        let now = Utc::now();
+
+        let subtask = TaskInformation {
+            id: Uuid::now_v7(),
+            name: create_request.name,
+            description: create_request.description,
+            created_timestamp: now.timestamp(),
+            end_timestamp: match create_request.end_timestamp {
+                None => now.add(TimeDelta::try_days(1).unwrap()).timestamp(),
+                Some(value) => value.seconds,
+            },
+            team_id: parent.team_id,
+            status: TaskStatus::Paused,
+            creator: user_id,
+            assigned_users: vec![],
+            parent_id: Some(parent.id),
+            priority,
+            column_id: None,
+            position: 0,
+            recurrence_rule: None,
+            recurrence_index: 0,
+            recurrence_pending: false,
+            recurrence_anchor: None,
+            children_total: 0,
+            children_finished: 0,
+            checklist_total: 0,
+            checklist_done: 0,
+            labels: vec![],
+        };
+
+        let created = self
+            .task_repository
+            .create(&subtask)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(TaskResponse::from(created)))
+    }
+
+    async fn move_task(
+        &self,
+        request: Request<MoveTaskRequest>,
+    ) -> Result<Response<TaskResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let move_request = request.into_inner();
+
+        let task = self.get_task(&move_request.task_id)?;
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        // An empty parent id turns the task into a top level task.
+        let new_parent = if move_request.parent_id.is_empty() {
+            None
+        } else {
+            Some(self.get_task(&move_request.parent_id)?)
+        };
+
+        if let Some(parent) = &new_parent {
+            if parent.team_id != task.team_id {
+                return Err(Status::invalid_argument(
+                    "Parent task belongs to another team",
+                ));
+            }
+
+            Self::check_open_parent(parent)?;
+
+            if task.recurrence_rule.is_some() {
+                return Err(Status::failed_precondition(
+                    "A recurring task can't become a subtask",
+                ));
+            }
+        }
+
+        let moved = self
+            .task_repository
+            .set_parent(
+                &task.id,
+                new_parent.as_ref().map(|parent| &parent.id),
+                &task.team_id,
+            )
+            .map_err(|e| match e {
+                Error::DependencyCycleError => {
+                    Status::invalid_argument("A task can't be moved below itself")
+                }
+                e => Status::internal(format!("Internal Server Error: {}", e)),
+            })?;
+
+        Ok(Response::new(TaskResponse::from(moved)))
+    }
+
+    async fn get_children(
+        &self,
+        request: Request<GetChildrenRequest>,
+    ) -> Result<Response<GetAllResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let parent = self.get_task(&request.into_inner().task_id)?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&parent.team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        let children = self
+            .task_repository
+            .get_children(&parent.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(GetAllResponse {
+            tasks: children.iter().map(TaskResponse::from).collect(),
+        }))
+    }
+
+    async fn add_blocker(
+        &self,
+        request: Request<AddBlockerRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let add_request = request.into_inner();
+
+        let task = self.get_task(&add_request.task_id)?;
+        let blocker = self.get_task(&add_request.blocker_id)?;
+
+        if task.team_id != blocker.team_id {
+            return Err(Status::invalid_argument(
+                "Tasks of different teams can't block each other",
+            ));
+        }
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        let dependency = TaskDependency {
+            id: Uuid::now_v7(),
+            task_id: task.id,
+            blocker_id: blocker.id,
+        };
+
+        self.task_repository
+            .add_dependency(&dependency, &task.team_id)
+            .map_err(|e| match e {
+                Error::DependencyCycleError => {
+                    Status::failed_precondition("The blocker already waits on this task")
+                }
+                e => Status::internal(format!("Internal Server Error: {}", e)),
+            })?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn remove_blocker(
+        &self,
+        request: Request<RemoveBlockerRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let remove_request = request.into_inner();
+
+        let task = self.get_task(&remove_request.task_id)?;
+
+        let blocker_id = Uuid::from_str(&remove_request.blocker_id)
+            .map_err(|_| Status::invalid_argument("Invalid blocker id"))?;
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        self.task_repository
+            .remove_dependency(&task.id, &blocker_id)
+            .map_err(|_| Status::not_found("Dependency not found"))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn get_blockers(
+        &self,
+        request: Request<GetBlockersRequest>,
+    ) -> Result<Response<GetAllResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let task = self.get_task(&request.into_inner().task_id)?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&task.team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        let blockers = self
+            .task_repository
+            .get_blockers(&task.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(GetAllResponse {
+            tasks: blockers.iter().map(TaskResponse::from).collect(),
+        }))
+    }
+
+    async fn add_task_label(
+        &self,
+        request: Request<TaskLabelRequest>,
+    ) -> Result<Response<TaskResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let (task, label_id) = self.get_task_and_label(&request.into_inner(), &user_id)?;
+
+        self.label_repository
+            .attach(&TaskLabel {
+                id: Uuid::now_v7(),
+                task_id: task.id,
+                label_id,
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let labeled = self
+            .task_repository
+            .get(&task.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(TaskResponse::from(labeled)))
+    }
+
+    async fn remove_task_label(
+        &self,
+        request: Request<TaskLabelRequest>,
+    ) -> Result<Response<TaskResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let (task, label_id) = self.get_task_and_label(&request.into_inner(), &user_id)?;
+
+        self.label_repository
+            .detach(&task.id, &label_id)
+            .map_err(|_| Status::not_found("Label isn't attached to the task"))?;
+
+        let unlabeled = self
+            .task_repository
+            .get(&task.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(TaskResponse::from(unlabeled)))
+    }
+
+    async fn set_priority(
+        &self,
+        request: Request<SetTaskPriorityRequest>,
+    ) -> Result<Response<TaskResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let priority_request = request.into_inner();
+
+        let task = self.get_task(&priority_request.id)?;
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        let priority = Self::parse_priority(&priority_request.priority)?;
+
+        let updated = self
+            .task_repository
+            .set_priority(&task.id, priority)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(TaskResponse::from(updated)))
+    }
+
+    async fn search(
+        &self,
+        request: Request<SearchTasksRequest>,
+    ) -> Result<Response<SearchTasksResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let search_request = request.into_inner();
+
+        let text = search_request.text.trim();
+
+        if text.is_empty() || text.chars().count() > 200 {
+            return Err(Status::invalid_argument("Invalid search text"));
+        }
+
+        let team_id = Self::parse_id(&search_request.team_id, "Invalid team id")?;
+
+        if let Some(team_id) = &team_id {
+            self.role_repository
+                .get_by_team_and_user_id(team_id, &user_id)
+                .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+        }
+
+        let limit = match search_request.limit as i64 {
+            0 => TASK_SEARCH_LIMIT,
+            limit if limit < 0 => return Err(Status::invalid_argument("Invalid limit")),
+            limit => limit.min(TASK_SEARCH_LIMIT_MAX),
+        };
+
+        let results = self
+            .task_repository
+            .search(&user_id, team_id.as_ref(), text, limit)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(SearchTasksResponse {
+            hits: results.into_iter().map(TaskSearchHit::from).collect(),
+        }))
+    }
+
+    async fn get_board(
+        &self,
+        request: Request<GetBoardRequest>,
+    ) -> Result<Response<GetBoardResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let board_request = request.into_inner();
+
+        let team_id = Uuid::from_str(&board_request.team_id)
+            .map_err(|_| Status::invalid_argument("Invalid team id"))?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        let columns = self.get_board_columns(&team_id)?;
+
+        let filter = TaskFilter {
+            team_id: Some(team_id),
+            sort: vec![
+                TaskSort {
+                    field: TaskSortField::Position,
+                    descending: false,
+                },
+                TaskSort {
+                    field: TaskSortField::EndTimestamp,
+                    descending: false,
+                },
+            ],
+            ..Default::default()
+        };
+
+        // Loads the board page by page, so no task falls off a large board.
+        let mut tasks: Vec<TaskInformation> = vec![];
+        let mut after: Option<TaskCursor> = None;
+
+        loop {
+            let page = self
+                .task_repository
+                .query(&filter, after.as_ref(), TASK_BOARD_PAGE_SIZE)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+            let is_last = (page.len() as i64) < TASK_BOARD_PAGE_SIZE;
+            after = page.last().map(TaskCursor::from);
+            tasks.extend(page);
+
+            if is_last {
+                break;
+            }
+        }
+
+        let mut board: Vec<BoardColumnTasks> = columns
+            .iter()
+            .map(|column| BoardColumnTasks {
+                column: Some(BoardColumnResponse::from(column.clone())),
+                tasks: vec![],
+            })
+            .collect();
+
+        for task in tasks {
+            if let Some(index) = column_index(&columns, task.column_id, &task.status) {
+                board[index].tasks.push(TaskResponse::from(task));
+            }
+        }
+
+        Ok(Response::new(GetBoardResponse { columns: board }))
+    }
+
+    async fn create_board_column(
+        &self,
+        request: Request<CreateBoardColumnRequest>,
+    ) -> Result<Response<BoardColumnResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let create_request = request.into_inner();
+
+        let team_id = Uuid::from_str(&create_request.team_id)
+            .map_err(|_| Status::invalid_argument("Invalid team id"))?;
+
+        self.check_can_manage_board(&team_id, &user_id)?;
+        Self::check_column_name(&create_request.name)?;
+
+        let status = Self::parse_status(&create_request.status)?;
+
+        let columns = self.get_board_columns(&team_id)?;
+
+        let created = self
+            .board_repository
+            .create_column(&BoardColumn {
+                id: Uuid::now_v7(),
+                team_id,
+                name: create_request.name,
+                status,
+                position: columns.last().map_or(0, |column| column.position + 1),
+            })
+            .map_err(Self::map_column_error)?;
+
+        Ok(Response::new(BoardColumnResponse::from(created)))
+    }
+
+    async fn update_board_column(
+        &self,
+        request: Request<UpdateBoardColumnRequest>,
+    ) -> Result<Response<BoardColumnResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let update_request = request.into_inner();
+
+        let column_id = Uuid::from_str(&update_request.id)
+            .map_err(|_| Status::invalid_argument("Invalid column id"))?;
+
+        let column = self
+            .board_repository
+            .get_column(&column_id)
+            .map_err(|_| Status::not_found("Column not found"))?;
+
+        self.check_can_manage_board(&column.team_id, &user_id)?;
+        Self::check_column_name(&update_request.name)?;
+
+        let status = Self::parse_status(&update_request.status)?;
+
+        // Tasks take the status of their column, so the status of a column can only
+        // change while it's empty.
+        if status != column.status {
+            let tasks = self
+                .board_repository
+                .count_tasks(&column.id)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+            if tasks > 0 {
+                return Err(Status::failed_precondition(
+                    "Move the tasks out of the column before changing its status",
+                ));
+            }
+        }
+
+        let mut columns = self.get_board_columns(&column.team_id)?;
+        columns.retain(|other| other.id != column.id);
+
+        let index = insert_at(
+            &mut columns,
+            update_request.position,
+            BoardColumn {
+                name: update_request.name,
+                status,
+                ..column
+            },
+        );
+
+        Self::check_statuses_covered(&columns)?;
+
+        for (position, column) in columns.iter_mut().enumerate() {
+            column.position = position as i32;
+        }
+
+        self.board_repository
+            .update_columns(&columns)
+            .map_err(Self::map_column_error)?;
+
+        Ok(Response::new(BoardColumnResponse::from(
+            columns.swap_remove(index),
+        )))
+    }
+
+    async fn delete_board_column(
+        &self,
+        request: Request<DeleteBoardColumnRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let delete_request = request.into_inner();
+
+        let column_id = Uuid::from_str(&delete_request.id)
+            .map_err(|_| Status::invalid_argument("Invalid column id"))?;
+
+        let column = self
+            .board_repository
+            .get_column(&column_id)
+            .map_err(|_| Status::not_found("Column not found"))?;
+
+        self.check_can_manage_board(&column.team_id, &user_id)?;
+
+        let mut columns = self.get_board_columns(&column.team_id)?;
+        columns.retain(|other| other.id != column.id);
+
+        Self::check_statuses_covered(&columns)?;
+
+        self.board_repository
+            .delete_column(&column.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn move_to_column(
+        &self,
+        request: Request<MoveToColumnRequest>,
+    ) -> Result<Response<TaskResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let move_request = request.into_inner();
+
+        let task = self.get_task(&move_request.task_id)?;
+
+        let column_id = Uuid::from_str(&move_request.column_id)
+            .map_err(|_| Status::invalid_argument("Invalid column id"))?;
+
+        let column = self
+            .board_repository
+            .get_column(&column_id)
+            .map_err(|_| Status::not_found("Column not found"))?;
+
+        if column.team_id != task.team_id {
+            return Err(Status::invalid_argument("Column belongs to another team"));
+        }
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        if column.status != task.status {
+            match column.status {
+                TaskStatus::Finished => self.check_can_finish(&task)?,
+                TaskStatus::InProgress if self.is_blocked(&task.id)? => {
+                    return Err(Status::failed_precondition("Task has unfinished blockers"));
+                }
+                _ => {}
+            }
+
+            if let Some(parent_id) = task.parent_id {
+                let parent = self
+                    .task_repository
+                    .get(&parent_id)
+                    .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+                if reopens_under_closed_parent(&column.status, &parent.status) {
+                    return Err(Status::failed_precondition("Parent task is already closed"));
+                }
+            }
+        }
+
+        let moved = self
+            .task_repository
+            .move_to_column(&task.id, &column, move_request.position)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(TaskResponse::from(moved)))
+    }
+
+    async fn set_recurrence(
+        &self,
+        request: Request<SetRecurrenceRequest>,
+    ) -> Result<Response<TaskResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let recurrence_request = request.into_inner();
+
+        let task = self.get_task(&recurrence_request.id)?;
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        let rule = Self::parse_recurrence(&recurrence_request.rule)?;
+
+        // An occurrence would be created below the same parent again, even once it's
+        // closed.
+        if rule.is_some() && task.parent_id.is_some() {
+            return Err(Status::failed_precondition("Subtasks can't recur"));
+        }
+
+        // Closed and overdue tasks may already have created their next occurrence.
+        if rule.is_some()
+            && (matches!(task.status, TaskStatus::Finished | TaskStatus::Canceled)
+                || task.end_timestamp < Utc::now().timestamp())
+        {
+            return Err(Status::failed_precondition(
+                "Only open tasks that aren't overdue can recur",
+            ));
+        }
+
+        let updated = self
+            .task_repository
+            .set_recurrence(&task.id, rule.as_deref())
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(TaskResponse::from(updated)))
+    }
+
+    async fn get_checklist(
+        &self,
+        request: Request<GetChecklistRequest>,
+    ) -> Result<Response<ChecklistResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let task = self.get_task(&request.into_inner().task_id)?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&task.team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        Ok(Response::new(self.get_checklist_response(&task.id)?))
+    }
+
+    async fn add_checklist_item(
+        &self,
+        request: Request<AddChecklistItemRequest>,
+    ) -> Result<Response<ChecklistResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let item_request = request.into_inner();
+
+        let task = self.get_task(&item_request.task_id)?;
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        let text = item_request.text.trim();
+        Self::check_checklist_text(text)?;
+
+        let assignee_id = Self::parse_id(&item_request.assignee_id, "Invalid assignee id")?;
+
+        if let Some(assignee_id) = assignee_id {
+            self.role_repository
+                .get_by_team_and_user_id(&task.team_id, &assignee_id)
+                .map_err(|_| Status::invalid_argument("Assignee isn't a member of this team"))?;
+        }
+
+        if task.checklist_total as usize >= TASK_CHECKLIST_MAX_ITEMS {
+            return Err(Status::failed_precondition(format!(
+                "A checklist can't have more than {} items",
+                TASK_CHECKLIST_MAX_ITEMS
+            )));
+        }
+
+        self.checklist_repository
+            .add_item(&ChecklistItem {
+                id: Uuid::now_v7(),
+                task_id: task.id,
+                text: text.to_string(),
+                done: false,
+                assignee_id,
+                position: 0,
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(self.get_checklist_response(&task.id)?))
+    }
+
+    async fn toggle_checklist_item(
+        &self,
+        request: Request<ToggleChecklistItemRequest>,
+    ) -> Result<Response<ChecklistResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let toggle_request = request.into_inner();
+
+        let (item, task) = self.get_checklist_item(&toggle_request.id)?;
+
+        let role = self
+            .role_repository
+            .get_by_team_and_user_id(&task.team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        if !item.can_toggle(&user_id, role.can_add_task) {
+            return Err(Status::permission_denied("Can't toggle this checklist item"));
+        }
+
+        self.checklist_repository
+            .set_done(&item.id, toggle_request.done)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(self.get_checklist_response(&task.id)?))
+    }
+
+    async fn move_checklist_item(
+        &self,
+        request: Request<MoveChecklistItemRequest>,
+    ) -> Result<Response<ChecklistResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let move_request = request.into_inner();
+
+        let (item, task) = self.get_checklist_item(&move_request.id)?;
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        self.checklist_repository
+            .move_item(&item, move_request.position)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(self.get_checklist_response(&task.id)?))
+    }
+
+    async fn delete_checklist_item(
+        &self,
+        request: Request<DeleteChecklistItemRequest>,
+    ) -> Result<Response<ChecklistResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let (item, task) = self.get_checklist_item(&request.into_inner().id)?;
+
+        self.check_can_add_task(&task.team_id, &user_id)?;
+
+        self.checklist_repository
+            .delete_item(&item)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(self.get_checklist_response(&task.id)?))
+    }
+
+    async fn get_comments(
+        &self,
+        request: Request<GetCommentsRequest>,
+    ) -> Result<Response<CommentsResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let task = self.get_task(&request.into_inner().task_id)?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&task.team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        let comments = self
+            .comment_repository
+            .get_for_task(&task.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(CommentsResponse {
+            comments: comments.into_iter().map(CommentResponse::from).collect(),
+        }))
+    }
+
+    async fn add_comment(
+        &self,
+        request: Request<AddCommentRequest>,
+    ) -> Result<Response<CommentResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let comment_request = request.into_inner();
+
+        let task = self.get_task(&comment_request.task_id)?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&task.team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        let body = comment_request.body.trim();
+
+        if body.is_empty() || body.chars().count() > TASK_COMMENT_MAX_LENGTH {
+            return Err(Status::invalid_argument("Invalid comment"));
+        }
+
+        let comment = self
+            .comment_repository
+            .create(&TaskComment {
+                id: Uuid::now_v7(),
+                task_id: task.id,
+                author_id: user_id,
+                body: body.to_string(),
+                created_timestamp: Utc::now().timestamp(),
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(CommentResponse::from(comment)))
+    }
+
+    async fn delete_comment(
+        &self,
+        request: Request<DeleteCommentRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let comment_id = Uuid::from_str(&request.into_inner().id)
+            .map_err(|_| Status::invalid_argument("Invalid comment id"))?;
+
+        let comment = self
+            .comment_repository
+            .get(&comment_id)
+            .map_err(|_| Status::not_found("Comment not found"))?;
+
+        // Only the author can take a comment back.
+        if comment.author_id != user_id {
+            return Err(Status::permission_denied("Can't delete this comment"));
+        }
+
+        self.comment_repository
+            .delete(&comment.id)
             .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
 
         Ok(Response::new(()))
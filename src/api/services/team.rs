@@ -2,28 +2,55 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use autometrics::autometrics;
+use chrono::Utc;
 use derive_new::new;
 use tonic::{async_trait, Request, Response, Status};
 use uuid::Uuid;
 
-use crate::domain::constants::MIDDLEWARE_AUTH_SESSION_KEY;
+use crate::core::regex::CachedRegexValidator;
+use crate::core::token::{generate_token, hash_token};
+use crate::domain::constants::{
+    ACCESS_TOKEN_PREFIX, MIDDLEWARE_AUTH_USER_KEY, USER_NAME_MAX_LENGTH,
+};
+use crate::domain::error::Error;
 use crate::domain::models::team::team_information::TeamInformation;
+use crate::domain::models::team::team_label::TeamLabel;
 use crate::domain::models::team::team_member::TeamMember;
 use crate::domain::models::team::team_role::TeamRole;
+use crate::domain::models::user::access_token::AccessToken;
+use crate::domain::models::user::access_token_scope::AccessTokenScope;
+use crate::domain::models::user::email_verification_policy::EmailVerificationPolicy;
+use crate::domain::models::user::user_information::UserInformation;
+use crate::domain::repositories::access_token::AccessTokenRepository;
+use crate::domain::repositories::access_token_audit::AccessTokenAuditRepository;
+use crate::domain::repositories::label::LabelRepository;
 use crate::domain::repositories::role::RoleRepository;
 use crate::domain::repositories::team::TeamRepository;
+use crate::domain::repositories::user::UserRepository;
 use crate::extract_user_id_from_metadata;
 use crate::team::team_server::Team;
 use crate::team::{
-    ChangeTeamRole, CreateTeamRequest, CreateTeamResponse, GetAllTeamsResponse, GetTeamRequest,
-    GetTeamRolesRequest, GetTeamRolesResponse, JoinTeamRequest, JoinTeamResponse, LeaveTeamRequest,
-    LeaveTeamResponse, Role, RolePermission, TeamResponse, UserInfo,
+    ChangeTeamRole, CreateLabelRequest, CreateServiceAccountKeyRequest,
+    CreateServiceAccountKeyResponse, CreateServiceAccountRequest, CreateTeamRequest,
+    CreateTeamResponse, DeleteLabelRequest, GetAllTeamsResponse, GetLabelsRequest,
+    GetLabelsResponse, GetServiceAccountActivityRequest, GetServiceAccountActivityResponse,
+    GetServiceAccountsRequest, GetServiceAccountsResponse, GetTeamRequest, GetTeamRolesRequest,
+    GetTeamRolesResponse, JoinTeamRequest, JoinTeamResponse, LabelResponse, LeaveTeamRequest,
+    LeaveTeamResponse, RevokeServiceAccountKeyRequest, Role, RolePermission,
+    ServiceAccountActivityResponse, ServiceAccountResponse, TeamResponse, UpdateLabelRequest,
+    UserInfo,
 };
 
 #[derive(new)]
 pub struct TeamServiceImpl {
     pub(self) team_repository: Arc<dyn TeamRepository>,
     pub(self) role_repository: Arc<dyn RoleRepository>,
+    pub(self) user_repository: Arc<dyn UserRepository>,
+    pub(self) email_verification_policy: EmailVerificationPolicy,
+    pub(self) access_token_repository: Arc<dyn AccessTokenRepository>,
+    pub(self) label_repository: Arc<dyn LabelRepository>,
+    pub(self) regex_cache: Arc<CachedRegexValidator>,
+    pub(self) access_token_audit_repository: Arc<dyn AccessTokenAuditRepository>,
 }
 
 impl TeamServiceImpl {
@@ -49,6 +76,7 @@ impl TeamServiceImpl {
                             id: member.id.to_string(),
                             user_name: member.user_name.clone(),
                             role: role.name,
+                            service_account: member.service_account_team_id.is_some(),
                         }
                     })
                     .collect(),
@@ -56,6 +84,103 @@ impl TeamServiceImpl {
             })
             .collect()
     }
+
+    /// Returns the caller's role in the team if it allows managing service accounts.
+    fn service_account_manager_role(
+        &self,
+        team_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<TeamRole, Status> {
+        let role = self
+            .role_repository
+            .get_by_team_and_user_id(team_id, user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        if !role.can_invite_in_team {
+            return Err(Status::permission_denied(
+                "You don' have a permission to do that".to_string(),
+            ));
+        }
+
+        Ok(role)
+    }
+
+    /// Labels are managed by members who can add tasks.
+    fn check_can_manage_labels(&self, team_id: &Uuid, user_id: &Uuid) -> Result<(), Status> {
+        let role = self
+            .role_repository
+            .get_by_team_and_user_id(team_id, user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        if !role.can_add_task {
+            return Err(Status::permission_denied(
+                "You don' have a permission to do that".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn validate_label(&self, name: &str, color: &str) -> Result<(), Status> {
+        if name.is_empty() || name.chars().count() > 50 {
+            return Err(Status::invalid_argument("Invalid label name"));
+        }
+
+        if self.regex_cache.check_label_color(color).is_err() {
+            return Err(Status::invalid_argument("Label color must be #rrggbb"));
+        }
+
+        Ok(())
+    }
+
+    fn map_label_error(e: Error) -> Status {
+        match e {
+            Error::AlreadyExistsError => Status::already_exists("Label name is already taken"),
+            e => Status::internal(format!("Internal Server Error: {}", e)),
+        }
+    }
+
+    /// Loads a service account the caller is allowed to manage.
+    fn managed_service_account(
+        &self,
+        service_account_id: &str,
+        user_id: &Uuid,
+    ) -> Result<UserInformation, Status> {
+        let service_account_id = Uuid::from_str(service_account_id)
+            .map_err(|_| Status::invalid_argument("Invalid service account id"))?;
+
+        let service_account = self
+            .user_repository
+            .get(&service_account_id)
+            .map_err(|_| Status::not_found("Service account not found"))?;
+
+        let team_id = service_account
+            .service_account_team_id
+            .ok_or_else(|| Status::not_found("Service account not found"))?;
+
+        self.service_account_manager_role(&team_id, user_id)?;
+
+        Ok(service_account)
+    }
+
+    fn map_service_account_to_response(
+        &self,
+        service_account: &UserInformation,
+        team_id: &Uuid,
+    ) -> ServiceAccountResponse {
+        let role = self
+            .role_repository
+            .get_by_team_and_user_id(team_id, &service_account.id)
+            .map(|role| role.name)
+            .unwrap_or_default();
+
+        ServiceAccountResponse {
+            id: service_account.id.to_string(),
+            user_name: service_account.user_name.clone(),
+            team_id: team_id.to_string(),
+            role,
+        }
+    }
 }
 
 #[async_trait]
@@ -92,6 +217,7 @@ impl Team for TeamServiceImpl {
                             id: member.id.to_string(),
                             user_name: member.user_name.clone(),
                             role: role.name,
+                            service_account: member.service_account_team_id.is_some(),
                         }
                     })
                     .collect(),
@@ -146,6 +272,17 @@ impl Team for TeamServiceImpl {
 
         let user_id = extract_user_id_from_metadata!(&request);
 
+        if self.email_verification_policy.blocks_team_creation() {
+            let user = self
+                .user_repository
+                .get(&user_id)
+                .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+            if !user.email_verified {
+                return Err(Status::failed_precondition("Email is not verified"));
+            }
+        }
+
         let sign_up_request = request.into_inner();
 
         let new_team = &mut TeamInformation::from(sign_up_request);
@@ -345,4 +482,336 @@ impl Team for TeamServiceImpl {
 
         todo!()
     }
+
+    async fn create_service_account(
+        &self,
+        request: Request<CreateServiceAccountRequest>,
+    ) -> Result<Response<ServiceAccountResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let create_request = request.into_inner();
+
+        let team_id = Uuid::from_str(&create_request.team_id)
+            .map_err(|_| Status::invalid_argument("Invalid team id"))?;
+
+        let role_id = Uuid::from_str(&create_request.role_id)
+            .map_err(|_| Status::invalid_argument("Invalid role id"))?;
+
+        if create_request.user_name.is_empty()
+            || create_request.user_name.chars().count() > USER_NAME_MAX_LENGTH
+        {
+            return Err(Status::invalid_argument("Invalid user name"));
+        }
+
+        let called_role = self.service_account_manager_role(&team_id, &user_id)?;
+
+        let granted_role = self
+            .role_repository
+            .get(&role_id)
+            .map_err(|_| Status::not_found("Role not found"))?;
+
+        if granted_role.team_id != team_id {
+            return Err(Status::invalid_argument("Role belongs to another team"));
+        }
+
+        // Lower priority values are stronger roles, nobody can grant more than they have.
+        if granted_role.priority < called_role.priority {
+            return Err(Status::permission_denied(
+                "Can't grant a role above your own".to_string(),
+            ));
+        }
+
+        if self
+            .user_repository
+            .get_by_name(&create_request.user_name)
+            .is_ok()
+        {
+            return Err(Status::already_exists("User name is already taken"));
+        }
+
+        let service_account_id = Uuid::now_v7();
+
+        // Service accounts have no usable email or password, they only authenticate
+        // with API keys.
+        let service_account = self
+            .user_repository
+            .create_service_account(
+                &UserInformation {
+                    id: service_account_id,
+                    email: format!("{}@service-account.invalid", service_account_id),
+                    user_name: create_request.user_name,
+                    profile_image: None,
+                    password: generate_token(),
+                    email_verified: true,
+                    deletion_requested_timestamp: None,
+                    service_account_team_id: Some(team_id),
+                },
+                &TeamMember {
+                    id: Uuid::now_v7(),
+                    user_id: service_account_id,
+                    team_id,
+                    role_id: granted_role.id,
+                },
+            )
+            .map_err(|e| match e {
+                Error::AlreadyExistsError => Status::already_exists("User name is already taken"),
+                e => Status::internal(format!("Internal Server Error: {}", e)),
+            })?;
+
+        Ok(Response::new(self.map_service_account_to_response(
+            &service_account,
+            &team_id,
+        )))
+    }
+
+    async fn get_service_accounts(
+        &self,
+        request: Request<GetServiceAccountsRequest>,
+    ) -> Result<Response<GetServiceAccountsResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let get_request = request.into_inner();
+
+        let team_id = Uuid::from_str(&get_request.team_id)
+            .map_err(|_| Status::invalid_argument("Invalid team id"))?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        let service_accounts = self
+            .user_repository
+            .get_service_accounts(&team_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(GetServiceAccountsResponse {
+            service_accounts: service_accounts
+                .iter()
+                .map(|service_account| {
+                    self.map_service_account_to_response(service_account, &team_id)
+                })
+                .collect(),
+        }))
+    }
+
+    async fn create_service_account_key(
+        &self,
+        request: Request<CreateServiceAccountKeyRequest>,
+    ) -> Result<Response<CreateServiceAccountKeyResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let create_request = request.into_inner();
+
+        let service_account =
+            self.managed_service_account(&create_request.service_account_id, &user_id)?;
+
+        if create_request.name.is_empty() || create_request.name.chars().count() > 50 {
+            return Err(Status::invalid_argument("Invalid key name"));
+        }
+
+        let scope = AccessTokenScope::from_str(&create_request.scope)
+            .map_err(|_| Status::invalid_argument("Invalid key scope"))?;
+
+        let now = Utc::now().timestamp();
+        let expires_timestamp = create_request
+            .expires_timestamp
+            .map(|timestamp| timestamp.seconds);
+
+        if expires_timestamp.is_some_and(|expires| expires <= now) {
+            return Err(Status::invalid_argument("Expiration must be in the future"));
+        }
+
+        let token = format!("{}{}", ACCESS_TOKEN_PREFIX, generate_token());
+
+        let created = self
+            .access_token_repository
+            .create(&AccessToken {
+                id: Uuid::now_v7(),
+                user_id: service_account.id,
+                name: create_request.name,
+                token_hash: hash_token(&token),
+                scope,
+                created_timestamp: now,
+                expires_timestamp,
+                last_used_timestamp: None,
+            })
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(CreateServiceAccountKeyResponse {
+            id: created.id.to_string(),
+            token,
+        }))
+    }
+
+    async fn revoke_service_account_key(
+        &self,
+        request: Request<RevokeServiceAccountKeyRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let revoke_request = request.into_inner();
+
+        let service_account =
+            self.managed_service_account(&revoke_request.service_account_id, &user_id)?;
+
+        let key_id = Uuid::from_str(&revoke_request.key_id)
+            .map_err(|_| Status::invalid_argument("Invalid key id"))?;
+
+        self.access_token_repository
+            .remove(&key_id, &service_account.id)
+            .map_err(|_| Status::not_found("Key not found"))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn get_service_account_activity(
+        &self,
+        request: Request<GetServiceAccountActivityRequest>,
+    ) -> Result<Response<GetServiceAccountActivityResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let activity_request = request.into_inner();
+
+        let service_account =
+            self.managed_service_account(&activity_request.service_account_id, &user_id)?;
+
+        let page_size = match activity_request.page_size {
+            0 => 50,
+            size => size.clamp(1, 100),
+        } as i64;
+
+        let before_id = match activity_request.page_token.as_str() {
+            "" => None,
+            token => Some(
+                Uuid::from_str(token)
+                    .map_err(|_| Status::invalid_argument("Invalid page token"))?,
+            ),
+        };
+
+        let entries = self
+            .access_token_audit_repository
+            .get_page_for_user(&service_account.id, before_id.as_ref(), page_size)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        let next_page_token = match entries.last() {
+            Some(last) if entries.len() as i64 == page_size => last.id.to_string(),
+            _ => String::new(),
+        };
+
+        Ok(Response::new(GetServiceAccountActivityResponse {
+            entries: entries
+                .into_iter()
+                .map(ServiceAccountActivityResponse::from)
+                .collect(),
+            next_page_token,
+        }))
+    }
+
+    async fn create_label(
+        &self,
+        request: Request<CreateLabelRequest>,
+    ) -> Result<Response<LabelResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let create_request = request.into_inner();
+
+        let team_id = Uuid::from_str(&create_request.team_id)
+            .map_err(|_| Status::invalid_argument("Invalid team id"))?;
+
+        self.check_can_manage_labels(&team_id, &user_id)?;
+        self.validate_label(&create_request.name, &create_request.color)?;
+
+        let created = self
+            .label_repository
+            .create(&TeamLabel {
+                id: Uuid::now_v7(),
+                team_id,
+                name: create_request.name,
+                color: create_request.color.to_lowercase(),
+            })
+            .map_err(Self::map_label_error)?;
+
+        Ok(Response::new(LabelResponse::from(created)))
+    }
+
+    async fn update_label(
+        &self,
+        request: Request<UpdateLabelRequest>,
+    ) -> Result<Response<LabelResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let update_request = request.into_inner();
+
+        let label_id = Uuid::from_str(&update_request.id)
+            .map_err(|_| Status::invalid_argument("Invalid label id"))?;
+
+        let label = self
+            .label_repository
+            .get(&label_id)
+            .map_err(|_| Status::not_found("Label not found"))?;
+
+        self.check_can_manage_labels(&label.team_id, &user_id)?;
+        self.validate_label(&update_request.name, &update_request.color)?;
+
+        let updated = self
+            .label_repository
+            .update(&TeamLabel {
+                name: update_request.name,
+                color: update_request.color.to_lowercase(),
+                ..label
+            })
+            .map_err(Self::map_label_error)?;
+
+        Ok(Response::new(LabelResponse::from(updated)))
+    }
+
+    async fn delete_label(
+        &self,
+        request: Request<DeleteLabelRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let delete_request = request.into_inner();
+
+        let label_id = Uuid::from_str(&delete_request.id)
+            .map_err(|_| Status::invalid_argument("Invalid label id"))?;
+
+        let label = self
+            .label_repository
+            .get(&label_id)
+            .map_err(|_| Status::not_found("Label not found"))?;
+
+        self.check_can_manage_labels(&label.team_id, &user_id)?;
+
+        self.label_repository
+            .delete(&label.id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn get_labels(
+        &self,
+        request: Request<GetLabelsRequest>,
+    ) -> Result<Response<GetLabelsResponse>, Status> {
+        let user_id = extract_user_id_from_metadata!(&request);
+
+        let get_request = request.into_inner();
+
+        let team_id = Uuid::from_str(&get_request.team_id)
+            .map_err(|_| Status::invalid_argument("Invalid team id"))?;
+
+        self.role_repository
+            .get_by_team_and_user_id(&team_id, &user_id)
+            .map_err(|_| Status::permission_denied("You are not a member of this team"))?;
+
+        let labels = self
+            .label_repository
+            .get_all_for_team(&team_id)
+            .map_err(|e| Status::internal(format!("Internal Server Error: {}", e)))?;
+
+        Ok(Response::new(GetLabelsResponse {
+            labels: labels.into_iter().map(LabelResponse::from).collect(),
+        }))
+    }
 }
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use derive_new::new;
+use tracing::{error, info};
+
+use crate::domain::constants::ONE_DAY;
+use crate::domain::error::Result;
+use crate::domain::models::user::user_information::UserInformation;
+use crate::domain::repositories::file::FileRepository;
+use crate::domain::repositories::refresh_token::RedisRefreshTokenRepository;
+use crate::domain::repositories::session::RedisSessionRepository;
+use crate::domain::repositories::user::UserRepository;
+
+/// Deletes accounts whose deletion grace period has passed.
+#[derive(new)]
+pub struct AccountDeletionWorker {
+    user_repository: Arc<dyn UserRepository>,
+    file_repository: Arc<dyn FileRepository>,
+    redis_repository: Arc<dyn RedisSessionRepository>,
+    refresh_token_repository: Arc<dyn RedisRefreshTokenRepository>,
+    grace_days: i64,
+    interval: Duration,
+}
+
+impl AccountDeletionWorker {
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+
+            let requested_before = Utc::now().timestamp() - self.grace_days * ONE_DAY;
+
+            let users = match self.user_repository.get_all_deletion_due(requested_before) {
+                Ok(users) => users,
+                Err(e) => {
+                    error!("Failed to load accounts due for deletion: {:?}", e);
+                    continue;
+                }
+            };
+
+            for user in users {
+                match self.delete_account(&user).await {
+                    Ok(_) => info!("Account {} deleted", user.id),
+                    Err(e) => error!("Failed to delete account {}: {:?}", user.id, e),
+                }
+            }
+        }
+    }
+
+    async fn delete_account(&self, user: &UserInformation) -> Result<()> {
+        self.redis_repository.remove_user_sessions(&user.id, None)?;
+        self.refresh_token_repository.revoke_user(&user.id)?;
+
+        for key in self
+            .file_repository
+            .list("users", &format!("{}/", user.user_name))
+            .await?
+        {
+            self.file_repository.delete("users", &key).await?;
+        }
+
+        self.user_repository.delete(&user.id)
+    }
+}
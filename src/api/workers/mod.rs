@@ -0,0 +1,5 @@
+pub mod account_deletion;
+pub mod recurrence;
+pub mod reminder;
+pub mod revoked_token_sync;
+pub mod task_metrics;
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use derive_new::new;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::core::recurrence::RecurrenceRule;
+use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_status::TaskStatus;
+use crate::domain::repositories::task::TaskRepository;
+
+/// Creates the next occurrence of recurring tasks once the previous one is finished
+/// or past its end timestamp.
+#[derive(new)]
+pub struct RecurrenceWorker {
+    task_repository: Arc<dyn TaskRepository>,
+    interval: Duration,
+}
+
+impl RecurrenceWorker {
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+
+            let now = Utc::now().timestamp();
+
+            let due = match self.task_repository.get_recurrences_due(now) {
+                Ok(due) => due,
+                Err(e) => {
+                    error!("Failed to load recurring tasks: {:?}", e);
+                    continue;
+                }
+            };
+
+            for task in due {
+                let next = task
+                    .recurrence_rule
+                    .as_deref()
+                    .and_then(RecurrenceRule::parse)
+                    .and_then(|rule| Self::next_occurrence(&rule, &task, now));
+
+                match self
+                    .task_repository
+                    .create_next_occurrence(&task.id, next.as_ref())
+                {
+                    Ok(true) => match &next {
+                        Some(next) => info!("Task {} recurred as {}", task.id, next.id),
+                        None => info!("Recurrence of task {} ended", task.id),
+                    },
+                    Ok(false) => {}
+                    Err(e) => error!(
+                        "Failed to create the next occurrence of {}: {:?}",
+                        task.id, e
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Builds the first occurrence after `task` that still ends in the future, the
+    /// ones missed in between are skipped.
+    fn next_occurrence(
+        rule: &RecurrenceRule,
+        task: &TaskInformation,
+        now: i64,
+    ) -> Option<TaskInformation> {
+        let anchor = task.recurrence_anchor.unwrap_or(task.end_timestamp);
+
+        let mut index = task.recurrence_index;
+        let mut end_timestamp;
+
+        loop {
+            end_timestamp = rule.next(anchor, index)?;
+            index += 1;
+
+            if end_timestamp > now {
+                break;
+            }
+        }
+
+        Some(TaskInformation {
+            id: Uuid::now_v7(),
+            created_timestamp: now,
+            end_timestamp,
+            status: TaskStatus::Paused,
+            assigned_users: vec![],
+            column_id: None,
+            position: 0,
+            recurrence_index: index,
+            recurrence_pending: true,
+            children_total: 0,
+            children_finished: 0,
+            checklist_total: 0,
+            checklist_done: 0,
+            labels: vec![],
+            ..task.clone()
+        })
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use derive_new::new;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::core::reminder::{describe_offset, reminder_to_send};
+use crate::domain::constants::ONE_DAY;
+use crate::domain::models::mail::mail_message::MailMessage;
+use crate::domain::models::task::task_deadline::TaskDeadline;
+use crate::domain::models::task::task_reminder::TaskReminder;
+use crate::domain::models::user::user_information::UserInformation;
+use crate::domain::repositories::reminder::ReminderRepository;
+use crate::domain::repositories::task::TaskRepository;
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::services::mail::MailSender;
+
+/// Reminds the assignees and the creator of open tasks `offsets` seconds before their
+/// end timestamp, and once more when they become overdue.
+#[derive(new)]
+pub struct ReminderWorker {
+    task_repository: Arc<dyn TaskRepository>,
+    reminder_repository: Arc<dyn ReminderRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    mail_sender: Arc<dyn MailSender>,
+    /// Largest first.
+    offsets: Vec<i64>,
+    interval: Duration,
+}
+
+impl ReminderWorker {
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+
+            let now = Utc::now().timestamp();
+            let largest_offset = self.offsets.first().copied().unwrap_or(0);
+
+            // Tasks that have been overdue for over a day don't get a late notice, so
+            // old tasks aren't announced all at once.
+            let tasks = match self
+                .task_repository
+                .get_deadlines_between(now - ONE_DAY, now + largest_offset)
+            {
+                Ok(tasks) => tasks,
+                Err(e) => {
+                    error!("Failed to load tasks to remind: {:?}", e);
+                    continue;
+                }
+            };
+
+            for task in tasks {
+                let Some(offset_seconds) = reminder_to_send(
+                    &self.offsets,
+                    task.end_timestamp,
+                    task.last_reminder_offset,
+                    now,
+                ) else {
+                    continue;
+                };
+
+                let claimed = self.reminder_repository.claim(&TaskReminder {
+                    id: Uuid::now_v7(),
+                    task_id: task.id,
+                    offset_seconds,
+                    end_timestamp: task.end_timestamp,
+                    sent_timestamp: now,
+                });
+
+                match claimed {
+                    Ok(true) => {
+                        self.remind(&task, offset_seconds);
+                        info!("Sent the {}s reminder of task {}", offset_seconds, task.id);
+                    }
+                    Ok(false) => {}
+                    Err(e) => error!("Failed to record a reminder of {}: {:?}", task.id, e),
+                }
+            }
+        }
+    }
+
+    fn remind(&self, task: &TaskDeadline, offset_seconds: i64) {
+        let mut recipients: Vec<UserInformation> = self
+            .task_repository
+            .get_assignees(&task.id)
+            .unwrap_or_else(|e| {
+                error!("Failed to load the assignees of {}: {:?}", task.id, e);
+                vec![]
+            });
+
+        if let Ok(creator) = self.user_repository.get(&task.creator) {
+            if !recipients.iter().any(|user| user.id == creator.id) {
+                recipients.push(creator);
+            }
+        }
+
+        let (subject, body) = if offset_seconds == 0 {
+            (
+                format!("Task \"{}\" is overdue", task.name),
+                format!("The task \"{}\" has passed its end date.", task.name),
+            )
+        } else {
+            (
+                format!("Task \"{}\" is due soon", task.name),
+                format!(
+                    "The task \"{}\" ends in {}.",
+                    task.name,
+                    describe_offset(offset_seconds)
+                ),
+            )
+        };
+
+        // Service accounts have no mailbox.
+        for user in recipients
+            .iter()
+            .filter(|user| user.service_account_team_id.is_none())
+        {
+            let sent = self.mail_sender.send(&MailMessage {
+                to: user.email.clone(),
+                subject: subject.clone(),
+                body: body.clone(),
+            });
+
+            if let Err(e) = sent {
+                error!("Failed to send a reminder to {}: {:?}", user.id, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use derive_new::new;
+use tracing::error;
+
+use crate::core::revoked_tokens::RevokedTokens;
+use crate::domain::repositories::revoked_token::RedisRevokedTokenRepository;
+
+/// Copies the deny-list of signed access tokens into `revoked_tokens`, so tokens
+/// revoked on another server are rejected here within one interval.
+#[derive(new)]
+pub struct RevokedTokenSyncWorker {
+    revoked_token_repository: Arc<dyn RedisRevokedTokenRepository>,
+    revoked_tokens: Arc<RevokedTokens>,
+    interval: Duration,
+}
+
+impl RevokedTokenSyncWorker {
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+
+            // The previous copy stays in place while the store can't be reached.
+            match self
+                .revoked_token_repository
+                .get_revoked(Utc::now().timestamp())
+            {
+                Ok(revoked) => self.revoked_tokens.replace(revoked),
+                Err(e) => error!("Failed to load revoked access tokens: {:?}", e),
+            }
+        }
+    }
+}
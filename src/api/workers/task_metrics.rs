@@ -0,0 +1,44 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use derive_new::new;
+use tracing::error;
+
+use crate::core::metrics::set_open_tasks;
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::domain::repositories::task::TaskRepository;
+
+/// Refreshes the open task gauges, one per priority.
+#[derive(new)]
+pub struct TaskMetricsWorker {
+    task_repository: Arc<dyn TaskRepository>,
+    interval: Duration,
+}
+
+impl TaskMetricsWorker {
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+
+            let counts = match self.task_repository.count_open_by_priority() {
+                Ok(counts) => counts,
+                Err(e) => {
+                    error!("Failed to count open tasks: {:?}", e);
+                    continue;
+                }
+            };
+
+            // Priorities without open tasks are missing from the counts and drop back to 0.
+            for priority in TaskPriority::ALL {
+                let count = counts
+                    .iter()
+                    .find(|(counted, _)| *counted == priority)
+                    .map_or(0, |(_, count)| *count);
+
+                set_open_tasks(&String::from(priority), count);
+            }
+        }
+    }
+}
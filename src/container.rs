@@ -1,35 +1,84 @@
 use crate::api::middlewares::auth::AuthMiddlewareLayer;
+use crate::api::password_validator::PasswordValidator;
 use crate::api::services::auth::AuthServiceImpl;
 use crate::api::services::file::FileServiceData;
 use crate::api::services::profile::ProfileServiceImpl;
 use crate::api::services::task::TaskServiceImpl;
 use crate::api::services::team::TeamServiceImpl;
+use crate::api::workers::account_deletion::AccountDeletionWorker;
+use crate::api::workers::recurrence::RecurrenceWorker;
+use crate::api::workers::reminder::ReminderWorker;
+use crate::api::workers::revoked_token_sync::RevokedTokenSyncWorker;
+use crate::api::workers::task_metrics::TaskMetricsWorker;
 use crate::auth::auth_server::AuthServer;
+use crate::core::jwt::JwtKeys;
+use crate::core::password_hashing::argon2_from_env;
 use crate::core::regex::CachedRegexValidator;
+use crate::core::reminder::parse_offsets;
+use crate::core::revoked_tokens::RevokedTokens;
+use crate::core::secret_cipher::SecretCipher;
+use crate::core::webauthn::webauthn_from_env;
+use crate::domain::constants::{
+    ACCOUNT_DELETION_GRACE_DAYS, AUTH_TOKEN_MODE, BREACHED_PASSWORDS_DIR,
+    EMAIL_VERIFICATION_POLICY, MAGIC_LINK_LOGIN, MAIL_OUTBOX_DIR, OIDC_CLIENT_ID,
+    OIDC_CLIENT_SECRET, OIDC_ISSUER_URL, OIDC_REDIRECT_URI, REVOKED_TOKEN_SYNC_SECONDS,
+    TASK_REMINDER_OFFSETS,
+};
+use crate::domain::models::user::auth_token_mode::AuthTokenMode;
+use crate::domain::models::user::email_verification_policy::EmailVerificationPolicy;
+use crate::domain::models::user::login_limits::LoginLimits;
+use crate::domain::models::user::password_policy::PasswordPolicy;
+use crate::domain::services::breached_password::BreachedPasswordChecker;
+use crate::domain::services::oidc::OidcProvider;
 use crate::infrastructure::databases::postgresql::db_pool;
 use crate::infrastructure::databases::redis::redis_pool;
+use crate::infrastructure::mail::log_mail_sender::LogMailSenderImpl;
+use crate::infrastructure::oidc::oidc_provider::{OidcConfig, OidcProviderImpl};
+use crate::infrastructure::passwords::local_breached_password_checker::LocalBreachedPasswordCheckerImpl;
+use crate::infrastructure::repositories::access_token_audit_repository::AccessTokenAuditRepositoryImpl;
+use crate::infrastructure::repositories::access_token_repository::AccessTokenRepositoryImpl;
+use crate::infrastructure::repositories::board_repository::BoardRepositoryImpl;
+use crate::infrastructure::repositories::checklist_repository::ChecklistRepositoryImpl;
+use crate::infrastructure::repositories::comment_repository::CommentRepositoryImpl;
 use crate::infrastructure::repositories::file_repository::FileRepositoryImpl;
+use crate::infrastructure::repositories::label_repository::LabelRepositoryImpl;
+use crate::infrastructure::repositories::login_attempt_repository::RedisLoginAttemptRepositoryImpl;
+use crate::infrastructure::repositories::login_audit_repository::LoginAuditRepositoryImpl;
+use crate::infrastructure::repositories::refresh_token_repository::RedisRefreshTokenRepositoryImpl;
+use crate::infrastructure::repositories::reminder_repository::ReminderRepositoryImpl;
+use crate::infrastructure::repositories::revoked_token_repository::RedisRevokedTokenRepositoryImpl;
 use crate::infrastructure::repositories::role_repository::RoleRepositoryImpl;
 use crate::infrastructure::repositories::session_repository::RedisSessionRepositoryImpl;
 use crate::infrastructure::repositories::task_repository::TaskRepositoryImpl;
 use crate::infrastructure::repositories::team_repository::TeamRepositoryImpl;
+use crate::infrastructure::repositories::token_repository::RedisTokenRepositoryImpl;
+use crate::infrastructure::repositories::two_factor_repository::TwoFactorRepositoryImpl;
+use crate::infrastructure::repositories::user_identity_repository::UserIdentityRepositoryImpl;
 use crate::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::infrastructure::repositories::webauthn_credential_repository::WebauthnCredentialRepositoryImpl;
 use crate::profile::profile_server::ProfileServer;
 use crate::task::task_server::TaskServer;
 use crate::team::team_server::TeamServer;
-use argon2::Argon2;
 use autometrics::autometrics;
 use aws_config::meta::region::RegionProviderChain;
 use aws_config::Region;
 use aws_sdk_s3::config::Credentials;
 use aws_sdk_s3::Client;
 use diesel_migrations::{FileBasedMigrations, MigrationHarness};
+use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 use tower::layer::util::{Identity, Stack};
 use tower::timeout::TimeoutLayer;
 
 pub struct Container {
+    pub account_deletion_worker: AccountDeletionWorker,
+    pub task_metrics_worker: TaskMetricsWorker,
+    pub recurrence_worker: RecurrenceWorker,
+    pub reminder_worker: ReminderWorker,
+    pub revoked_token_sync_worker: RevokedTokenSyncWorker,
     pub auth_server: AuthServer<AuthServiceImpl>,
     pub team_server: TeamServer<TeamServiceImpl>,
     pub task_server: TaskServer<TaskServiceImpl>,
@@ -61,7 +110,7 @@ impl Container {
             .unwrap();
 
         let redis_pool = Arc::new(redis_pool().unwrap());
-        let argon2 = Arc::new(Argon2::default());
+        let argon2 = Arc::new(argon2_from_env());
 
         let aws_config = aws_config::load_from_env().await;
         let s3_client = Arc::new(Client::new(&aws_config));
@@ -80,30 +129,199 @@ impl Container {
             .await
             .unwrap();
 
+        let email_verification_policy = env::var(EMAIL_VERIFICATION_POLICY)
+            .map(|value| {
+                EmailVerificationPolicy::from_str(&value)
+                    .expect("Invalid email verification policy")
+            })
+            .unwrap_or_default();
+
+        let auth_token_mode = env::var(AUTH_TOKEN_MODE)
+            .map(|value| AuthTokenMode::from_str(&value).expect("Invalid auth token mode"))
+            .unwrap_or_default();
+
+        let jwt_keys = match auth_token_mode {
+            AuthTokenMode::Session => None,
+            AuthTokenMode::Jwt => Some(Arc::new(JwtKeys::from_env())),
+        };
+
+        let account_deletion_grace_days = env::var(ACCOUNT_DELETION_GRACE_DAYS)
+            .map(|value| {
+                value
+                    .parse()
+                    .expect("ACCOUNT_DELETION_GRACE_DAYS must be a number")
+            })
+            .unwrap_or(14);
+
+        let magic_link_enabled = env::var(MAGIC_LINK_LOGIN)
+            .map(|value| {
+                value
+                    .parse()
+                    .expect("MAGIC_LINK_LOGIN must be true or false")
+            })
+            .unwrap_or(false);
+
+        let reminder_offsets = parse_offsets(
+            &env::var(TASK_REMINDER_OFFSETS).unwrap_or_else(|_| String::from("24h,1h")),
+        )
+        .expect("TASK_REMINDER_OFFSETS must be a list like 24h,1h");
+
         let user_repository = Arc::new(UserRepositoryImpl::new(pool.clone(), argon2.clone()));
         let team_repository = Arc::new(TeamRepositoryImpl::new(pool.clone()));
         let task_repository = Arc::new(TaskRepositoryImpl::new(pool.clone()));
-        let role_repository = Arc::new(RoleRepositoryImpl::new(pool));
+        let role_repository = Arc::new(RoleRepositoryImpl::new(pool.clone()));
+        let label_repository = Arc::new(LabelRepositoryImpl::new(pool.clone()));
+        let board_repository = Arc::new(BoardRepositoryImpl::new(pool.clone()));
+        let checklist_repository = Arc::new(ChecklistRepositoryImpl::new(pool.clone()));
+        let comment_repository = Arc::new(CommentRepositoryImpl::new(pool.clone()));
+        let two_factor_repository = Arc::new(TwoFactorRepositoryImpl::new(
+            pool.clone(),
+            argon2,
+            Arc::new(SecretCipher::from_env()),
+        ));
+        let access_token_repository = Arc::new(AccessTokenRepositoryImpl::new(pool.clone()));
+        let access_token_audit_repository =
+            Arc::new(AccessTokenAuditRepositoryImpl::new(pool.clone()));
+        let user_identity_repository = Arc::new(UserIdentityRepositoryImpl::new(pool.clone()));
+        let login_audit_repository = Arc::new(LoginAuditRepositoryImpl::new(pool.clone()));
+        let webauthn_credential_repository =
+            Arc::new(WebauthnCredentialRepositoryImpl::new(pool.clone()));
+        let reminder_repository = Arc::new(ReminderRepositoryImpl::new(pool));
         let file_repository = Arc::new(FileRepositoryImpl::new(s3_client));
-        let redis_session_repository = Arc::new(RedisSessionRepositoryImpl::new(redis_pool));
+        let redis_session_repository =
+            Arc::new(RedisSessionRepositoryImpl::new(redis_pool.clone()));
+        let redis_token_repository = Arc::new(RedisTokenRepositoryImpl::new(redis_pool.clone()));
+        let redis_login_attempt_repository =
+            Arc::new(RedisLoginAttemptRepositoryImpl::new(redis_pool.clone()));
+        let redis_refresh_token_repository =
+            Arc::new(RedisRefreshTokenRepositoryImpl::new(redis_pool.clone()));
+        let redis_revoked_token_repository =
+            Arc::new(RedisRevokedTokenRepositoryImpl::new(redis_pool));
+        let revoked_tokens = Arc::new(RevokedTokens::default());
+        let mail_sender = Arc::new(LogMailSenderImpl::new(
+            env::var(MAIL_OUTBOX_DIR).ok().map(PathBuf::from),
+        ));
+
+        let oidc_provider = env::var(OIDC_ISSUER_URL).ok().map(|issuer_url| {
+            Arc::new(OidcProviderImpl::new(OidcConfig {
+                issuer_url,
+                client_id: env::var(OIDC_CLIENT_ID).expect("OIDC_CLIENT_ID must be set"),
+                client_secret: env::var(OIDC_CLIENT_SECRET).ok(),
+                redirect_uri: env::var(OIDC_REDIRECT_URI).expect("OIDC_REDIRECT_URI must be set"),
+            })) as Arc<dyn OidcProvider>
+        });
+
+        let webauthn = webauthn_from_env().map(Arc::new);
+
+        let breached_password_checker = env::var(BREACHED_PASSWORDS_DIR).ok().map(|dir| {
+            Arc::new(LocalBreachedPasswordCheckerImpl::new(PathBuf::from(dir)))
+                as Arc<dyn BreachedPasswordChecker>
+        });
+        let password_validator = Arc::new(PasswordValidator::new(
+            PasswordPolicy::from_env(),
+            breached_password_checker,
+        ));
 
-        let auth_layer = AuthMiddlewareLayer::new(redis_session_repository.clone());
+        let auth_layer = AuthMiddlewareLayer::new(
+            redis_session_repository.clone(),
+            access_token_repository.clone(),
+            jwt_keys.clone(),
+            revoked_tokens.clone(),
+            user_repository.clone(),
+            access_token_audit_repository.clone(),
+        );
         let layer = tower::ServiceBuilder::new()
             .timeout(Duration::from_secs(30))
             .layer(auth_layer.clone())
             .into_inner();
 
         let auth_service = AuthServiceImpl::new(
-            regex_cache,
+            regex_cache.clone(),
             user_repository.clone(),
-            redis_session_repository,
+            redis_session_repository.clone(),
+            redis_token_repository.clone(),
+            mail_sender.clone(),
+            two_factor_repository.clone(),
+            redis_login_attempt_repository,
+            email_verification_policy,
+            LoginLimits::from_env(),
+            user_identity_repository,
+            oidc_provider,
+            redis_refresh_token_repository.clone(),
+            jwt_keys,
+            password_validator.clone(),
+            login_audit_repository.clone(),
+            magic_link_enabled,
+            webauthn_credential_repository.clone(),
+            webauthn.clone(),
+            redis_revoked_token_repository.clone(),
+            revoked_tokens.clone(),
+        );
+        let team_service = TeamServiceImpl::new(
+            team_repository.clone(),
+            role_repository.clone(),
+            user_repository.clone(),
+            email_verification_policy,
+            access_token_repository.clone(),
+            label_repository.clone(),
+            regex_cache.clone(),
+            access_token_audit_repository,
+        );
+        let task_service = TaskServiceImpl::new(
+            task_repository.clone(),
+            role_repository.clone(),
+            label_repository,
+            board_repository,
+            checklist_repository,
+            comment_repository.clone(),
         );
-        let team_service = TeamServiceImpl::new(team_repository, role_repository.clone());
-        let task_service = TaskServiceImpl::new(task_repository, role_repository);
         let profile_service = ProfileServiceImpl::new(
             file_repository.clone(),
             user_repository.clone(),
+            redis_session_repository.clone(),
+            two_factor_repository,
+            access_token_repository,
+            redis_refresh_token_repository.clone(),
+            task_repository.clone(),
+            team_repository,
+            role_repository,
+            account_deletion_grace_days,
+            regex_cache,
+            redis_token_repository,
+            mail_sender.clone(),
+            password_validator,
+            login_audit_repository,
+            webauthn_credential_repository,
+            webauthn,
             String::from("localhost/file"),
+            comment_repository,
+        );
+
+        let account_deletion_worker = AccountDeletionWorker::new(
+            user_repository.clone(),
+            file_repository.clone(),
+            redis_session_repository,
+            redis_refresh_token_repository,
+            account_deletion_grace_days,
+            Duration::from_secs(60 * 60),
+        );
+
+        let revoked_token_sync_worker = RevokedTokenSyncWorker::new(
+            redis_revoked_token_repository,
+            revoked_tokens,
+            Duration::from_secs(REVOKED_TOKEN_SYNC_SECONDS),
+        );
+        let task_metrics_worker =
+            TaskMetricsWorker::new(task_repository.clone(), Duration::from_secs(60));
+        let recurrence_worker =
+            RecurrenceWorker::new(task_repository.clone(), Duration::from_secs(60));
+        let reminder_worker = ReminderWorker::new(
+            task_repository,
+            reminder_repository,
+            user_repository.clone(),
+            mail_sender,
+            reminder_offsets,
+            Duration::from_secs(60),
         );
 
         let auth_server = AuthServer::new(auth_service);
@@ -114,6 +332,11 @@ impl Container {
         let file_service_data = FileServiceData::new(file_repository, user_repository);
 
         Container {
+            account_deletion_worker,
+            task_metrics_worker,
+            recurrence_worker,
+            reminder_worker,
+            revoked_token_sync_worker,
             auth_server,
             team_server,
             task_server,
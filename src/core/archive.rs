@@ -0,0 +1,47 @@
+use std::io::{Cursor, Write};
+
+use zip::result::ZipResult;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Packs `(path, content)` entries into an in-memory zip archive.
+pub fn zip_archive(entries: &[(String, Vec<u8>)]) -> ZipResult<Vec<u8>> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for (path, content) in entries {
+        writer.start_file(path.as_str(), options)?;
+        writer.write_all(content)?;
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use zip::ZipArchive;
+
+    use super::*;
+
+    #[test]
+    fn test_zip_archive_round_trip() {
+        let archive = zip_archive(&[
+            (String::from("data.json"), b"{}".to_vec()),
+            (String::from("files/avatar.jpg"), vec![1, 2, 3]),
+        ])
+        .unwrap();
+
+        let mut reader = ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert_eq!(reader.len(), 2);
+
+        let mut content = Vec::new();
+        reader
+            .by_name("files/avatar.jpg")
+            .unwrap()
+            .read_to_end(&mut content)
+            .unwrap();
+        assert_eq!(content, vec![1, 2, 3]);
+    }
+}
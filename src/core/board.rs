@@ -0,0 +1,95 @@
+use uuid::Uuid;
+
+use crate::domain::models::task::board_column::BoardColumn;
+use crate::domain::models::task::task_status::TaskStatus;
+
+/// Finds the column a task is shown in: its own column when it still belongs to the
+/// board, otherwise the first column of its status.
+pub fn column_index(
+    columns: &[BoardColumn],
+    column_id: Option<Uuid>,
+    status: &TaskStatus,
+) -> Option<usize> {
+    column_id
+        .and_then(|column_id| columns.iter().position(|column| column.id == column_id))
+        .or_else(|| columns.iter().position(|column| &column.status == status))
+}
+
+/// Moving a task into an open column reopens it, which isn't allowed while its
+/// parent is closed.
+pub fn reopens_under_closed_parent(column_status: &TaskStatus, parent_status: &TaskStatus) -> bool {
+    column_status.is_open() && !parent_status.is_open()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(status: TaskStatus, position: i32) -> BoardColumn {
+        BoardColumn {
+            id: Uuid::now_v7(),
+            team_id: Uuid::nil(),
+            name: format!("Column {}", position),
+            status,
+            position,
+        }
+    }
+
+    fn board() -> Vec<BoardColumn> {
+        vec![
+            column(TaskStatus::Paused, 0),
+            column(TaskStatus::InProgress, 1),
+            column(TaskStatus::InProgress, 2),
+            column(TaskStatus::Finished, 3),
+        ]
+    }
+
+    #[test]
+    fn test_column_index_uses_the_tasks_column() {
+        let columns = board();
+
+        assert_eq!(
+            column_index(&columns, Some(columns[2].id), &TaskStatus::InProgress),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_column_index_falls_back_to_first_column_of_status() {
+        let columns = board();
+
+        assert_eq!(
+            column_index(&columns, None, &TaskStatus::InProgress),
+            Some(1)
+        );
+        assert_eq!(
+            column_index(&columns, Some(Uuid::now_v7()), &TaskStatus::Finished),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_column_index_without_matching_column() {
+        assert_eq!(column_index(&board(), None, &TaskStatus::Canceled), None);
+    }
+
+    #[test]
+    fn test_reopens_under_closed_parent() {
+        assert!(reopens_under_closed_parent(
+            &TaskStatus::InProgress,
+            &TaskStatus::Finished
+        ));
+        assert!(reopens_under_closed_parent(
+            &TaskStatus::Paused,
+            &TaskStatus::Canceled
+        ));
+        assert!(!reopens_under_closed_parent(
+            &TaskStatus::Finished,
+            &TaskStatus::Finished
+        ));
+        assert!(!reopens_under_closed_parent(
+            &TaskStatus::InProgress,
+            &TaskStatus::Paused
+        ));
+    }
+}
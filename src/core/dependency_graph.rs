@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+/// Checks whether blocking `task_id` by `blocker_id` would close a cycle, which is the
+/// case when the blocker already waits on the task through `dependencies`, given as
+/// existing `(task_id, blocker_id)` pairs.
+pub fn creates_cycle(task_id: Uuid, blocker_id: Uuid, dependencies: &[(Uuid, Uuid)]) -> bool {
+    let mut blockers: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (task, blocker) in dependencies {
+        blockers.entry(*task).or_default().push(*blocker);
+    }
+
+    let mut visited = HashSet::new();
+    let mut pending = vec![blocker_id];
+
+    while let Some(current) = pending.pop() {
+        if current == task_id {
+            return true;
+        }
+
+        if visited.insert(current) {
+            if let Some(next) = blockers.get(&current) {
+                pending.extend(next);
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_dependency_is_a_cycle() {
+        let task = Uuid::now_v7();
+
+        assert!(creates_cycle(task, task, &[]));
+    }
+
+    #[test]
+    fn test_direct_cycle() {
+        let (a, b) = (Uuid::now_v7(), Uuid::now_v7());
+
+        assert!(creates_cycle(a, b, &[(b, a)]));
+    }
+
+    #[test]
+    fn test_transitive_cycle() {
+        let (a, b, c) = (Uuid::now_v7(), Uuid::now_v7(), Uuid::now_v7());
+
+        assert!(creates_cycle(a, c, &[(c, b), (b, a)]));
+    }
+
+    #[test]
+    fn test_diamond_is_not_a_cycle() {
+        let (a, b, c, d) = (
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+        );
+
+        assert!(!creates_cycle(d, a, &[(d, b), (d, c), (b, a), (c, a)]));
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{Debug, Formatter};
+
+use chrono::Utc;
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::constants::{JWT_ACCESS_TOKEN_EXPIRE_SECONDS, JWT_SIGNING_KEYS};
+
+const DEFAULT_ACCESS_TOKEN_EXPIRE_SECONDS: i64 = 60 * 15;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    pub sub: Uuid,
+    /// Identifies the token, so it can be revoked on logout before it expires.
+    pub jti: Uuid,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// HMAC keys for signed access tokens. Tokens are signed with the first (active) key,
+/// the remaining keys are only used for validation so they can be rotated out once
+/// every token signed with them has expired.
+///
+/// Logout revokes its access token by `jti`. Other revocations, like a password
+/// change, only end refresh token families, so access tokens issued before them stay
+/// valid for at most `access_token_expire_seconds`.
+pub struct JwtKeys {
+    active_kid: String,
+    encoding_key: EncodingKey,
+    decoding_keys: HashMap<String, DecodingKey>,
+    access_token_expire_seconds: i64,
+}
+
+impl Debug for JwtKeys {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtKeys")
+            .field("active_kid", &self.active_kid)
+            .field("kids", &self.decoding_keys.keys())
+            .finish()
+    }
+}
+
+impl JwtKeys {
+    /// Builds the key set from `kid:secret` pairs, the first pair being the active key.
+    pub fn new(keys: &[(&str, &str)], access_token_expire_seconds: i64) -> Self {
+        let (active_kid, active_secret) = keys.first().expect("At least one jwt key is required");
+
+        JwtKeys {
+            active_kid: active_kid.to_string(),
+            encoding_key: EncodingKey::from_secret(active_secret.as_bytes()),
+            decoding_keys: keys
+                .iter()
+                .map(|(kid, secret)| (kid.to_string(), DecodingKey::from_secret(secret.as_bytes())))
+                .collect(),
+            access_token_expire_seconds,
+        }
+    }
+
+    /// Reads keys from `JWT_SIGNING_KEYS` formatted as `kid:secret,kid:secret`.
+    pub fn from_env() -> Self {
+        let value = env::var(JWT_SIGNING_KEYS).expect("JWT_SIGNING_KEYS must be set");
+
+        let keys = value
+            .split(',')
+            .map(|pair| {
+                pair.trim()
+                    .split_once(':')
+                    .expect("JWT_SIGNING_KEYS entries must be kid:secret")
+            })
+            .collect::<Vec<_>>();
+
+        let access_token_expire_seconds = env::var(JWT_ACCESS_TOKEN_EXPIRE_SECONDS)
+            .map(|value| {
+                value
+                    .parse()
+                    .expect("JWT_ACCESS_TOKEN_EXPIRE_SECONDS must be a number")
+            })
+            .unwrap_or(DEFAULT_ACCESS_TOKEN_EXPIRE_SECONDS);
+
+        Self::new(&keys, access_token_expire_seconds)
+    }
+
+    pub fn access_token_expire_seconds(&self) -> i64 {
+        self.access_token_expire_seconds
+    }
+
+    /// Signs a short-lived access token for the user.
+    pub fn issue(&self, user_id: &Uuid) -> String {
+        let now = Utc::now().timestamp();
+
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some(self.active_kid.clone());
+
+        encode(
+            &header,
+            &AccessTokenClaims {
+                sub: *user_id,
+                jti: Uuid::now_v7(),
+                iat: now,
+                exp: now + self.access_token_expire_seconds,
+            },
+            &self.encoding_key,
+        )
+        .expect("Can't sign access token")
+    }
+
+    /// Returns the claims of a validly signed, unexpired access token. Whether the
+    /// token was revoked is up to the caller.
+    pub fn verify(&self, token: &str) -> Option<AccessTokenClaims> {
+        let header = decode_header(token).ok()?;
+        let key = self.decoding_keys.get(&header.kid?)?;
+
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.leeway = 0;
+
+        decode::<AccessTokenClaims>(token, key, &validation)
+            .ok()
+            .map(|data| data.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_verify() {
+        let keys = JwtKeys::new(&[("k1", "secret")], 60);
+        let user_id = Uuid::new_v4();
+
+        let claims = keys.verify(&keys.issue(&user_id)).unwrap();
+
+        assert_eq!(claims.sub, user_id);
+        assert_eq!(claims.exp, claims.iat + 60);
+        assert!(keys.verify("not-a-token").is_none());
+    }
+
+    #[test]
+    fn test_key_rotation() {
+        let old_keys = JwtKeys::new(&[("k1", "old-secret")], 60);
+        let rotated_keys = JwtKeys::new(&[("k2", "new-secret"), ("k1", "old-secret")], 60);
+        let retired_keys = JwtKeys::new(&[("k2", "new-secret")], 60);
+        let user_id = Uuid::new_v4();
+
+        let token = old_keys.issue(&user_id);
+
+        assert_eq!(
+            rotated_keys.verify(&token).map(|claims| claims.sub),
+            Some(user_id)
+        );
+        assert!(retired_keys.verify(&token).is_none());
+    }
+
+    #[test]
+    fn test_expired_token_rejected() {
+        let keys = JwtKeys::new(&[("k1", "secret")], -10);
+
+        assert!(keys.verify(&keys.issue(&Uuid::new_v4())).is_none());
+    }
+
+    #[test]
+    fn test_every_token_gets_its_own_id() {
+        let keys = JwtKeys::new(&[("k1", "secret")], 60);
+        let user_id = Uuid::new_v4();
+
+        let first = keys.verify(&keys.issue(&user_id)).unwrap();
+        let second = keys.verify(&keys.issue(&user_id)).unwrap();
+
+        assert_ne!(first.jti, second.jti);
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::OnceLock;
+
+use prometheus::{Encoder, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder};
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::new)
+}
+
+fn blocked_login_attempts() -> &'static IntCounterVec {
+    static COUNTER: OnceLock<IntCounterVec> = OnceLock::new();
+    COUNTER.get_or_init(|| {
+        let counter = IntCounterVec::new(
+            Opts::new(
+                "taskem_blocked_login_attempts_total",
+                "Login attempts rejected by rate limits or account lockout",
+            ),
+            &["reason"],
+        )
+        .expect("Failed to create blocked login attempts counter");
+
+        registry()
+            .register(Box::new(counter.clone()))
+            .expect("Failed to register blocked login attempts counter");
+
+        counter
+    })
+}
+
+/// Counts a login attempt blocked for `reason` (`ip`, `account` or `lockout`).
+pub fn record_blocked_login(reason: &str) {
+    blocked_login_attempts().with_label_values(&[reason]).inc();
+}
+
+fn open_tasks() -> &'static IntGaugeVec {
+    static GAUGE: OnceLock<IntGaugeVec> = OnceLock::new();
+    GAUGE.get_or_init(|| {
+        let gauge = IntGaugeVec::new(
+            Opts::new(
+                "taskem_open_tasks",
+                "Paused and in progress tasks by priority",
+            ),
+            &["priority"],
+        )
+        .expect("Failed to create open tasks gauge");
+
+        registry()
+            .register(Box::new(gauge.clone()))
+            .expect("Failed to register open tasks gauge");
+
+        gauge
+    })
+}
+
+/// Sets the number of open tasks with `priority`.
+pub fn set_open_tasks(priority: &str, count: i64) {
+    open_tasks().with_label_values(&[priority]).set(count);
+}
+
+/// Encodes the application metrics in the Prometheus text format.
+pub fn encode_to_string() -> Result<String, prometheus::Error> {
+    let mut buffer = Vec::new();
+    TextEncoder::new().encode(&registry().gather(), &mut buffer)?;
+
+    String::from_utf8(buffer).map_err(|e| prometheus::Error::Msg(e.to_string()))
+}
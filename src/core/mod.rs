@@ -1 +1,17 @@
+pub mod archive;
+pub mod board;
+pub mod dependency_graph;
+pub mod jwt;
+pub mod metrics;
+pub mod ordering;
+pub mod password_hashing;
+pub mod pkce;
+pub mod recurrence;
 pub mod regex;
+pub mod reminder;
+pub mod revoked_tokens;
+pub mod secret_cipher;
+pub mod token;
+pub mod totp;
+pub mod user_files;
+pub mod webauthn;
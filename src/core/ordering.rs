@@ -0,0 +1,45 @@
+/// Inserts `item` at `position` of `ordered`, clamped to the bounds of the list, and
+/// returns the index it ended up at.
+pub fn insert_at<T>(ordered: &mut Vec<T>, position: i32, item: T) -> usize {
+    let index = (position.max(0) as usize).min(ordered.len());
+    ordered.insert(index, item);
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_at_position() {
+        let mut ordered = vec!["a", "b", "c"];
+
+        assert_eq!(insert_at(&mut ordered, 1, "x"), 1);
+        assert_eq!(ordered, vec!["a", "x", "b", "c"]);
+    }
+
+    #[test]
+    fn test_insert_at_clamps_negative_positions_to_the_start() {
+        let mut ordered = vec!["a", "b"];
+
+        assert_eq!(insert_at(&mut ordered, -3, "x"), 0);
+        assert_eq!(ordered, vec!["x", "a", "b"]);
+    }
+
+    #[test]
+    fn test_insert_at_clamps_large_positions_to_the_end() {
+        let mut ordered = vec!["a", "b"];
+
+        assert_eq!(insert_at(&mut ordered, 10, "x"), 2);
+        assert_eq!(ordered, vec!["a", "b", "x"]);
+    }
+
+    #[test]
+    fn test_insert_at_into_empty_list() {
+        let mut ordered = vec![];
+
+        assert_eq!(insert_at(&mut ordered, 5, "x"), 0);
+        assert_eq!(ordered, vec!["x"]);
+    }
+}
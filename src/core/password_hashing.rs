@@ -0,0 +1,103 @@
+use std::env;
+
+use argon2::password_hash::PasswordHash;
+use argon2::{Algorithm, Argon2, Params, Version};
+
+use crate::domain::constants::{ARGON2_MEMORY_COST_KIB, ARGON2_PARALLELISM, ARGON2_TIME_COST};
+
+/// Builds the Argon2id hasher from `ARGON2_*` settings, unset values fall back to
+/// the argon2 crate defaults.
+pub fn argon2_from_env() -> Argon2<'static> {
+    fn read(key: &str, default: u32) -> u32 {
+        env::var(key)
+            .ok()
+            .map(|value| {
+                value
+                    .parse()
+                    .unwrap_or_else(|_| panic!("{key} must be a number"))
+            })
+            .unwrap_or(default)
+    }
+
+    let params = Params::new(
+        read(ARGON2_MEMORY_COST_KIB, Params::DEFAULT_M_COST),
+        read(ARGON2_TIME_COST, Params::DEFAULT_T_COST),
+        read(ARGON2_PARALLELISM, Params::DEFAULT_P_COST),
+        None,
+    )
+    .expect("Invalid argon2 parameters");
+
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+}
+
+/// Whether a stored hash was made with another algorithm, version or cost than
+/// `argon2` uses now.
+pub fn needs_rehash(argon2: &Argon2, password_hash: &PasswordHash) -> bool {
+    if password_hash.algorithm != Algorithm::Argon2id.ident()
+        || password_hash.version != Some(Version::V0x13.into())
+    {
+        return true;
+    }
+
+    let current = argon2.params();
+
+    match Params::try_from(password_hash) {
+        Ok(params) => {
+            params.m_cost() != current.m_cost()
+                || params.t_cost() != current.t_cost()
+                || params.p_cost() != current.p_cost()
+        }
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use argon2::password_hash::rand_core::OsRng;
+    use argon2::password_hash::{PasswordHasher, SaltString};
+
+    use super::*;
+
+    fn hash(argon2: &Argon2) -> String {
+        argon2
+            .hash_password(b"password", &SaltString::generate(&mut OsRng))
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_needs_rehash() {
+        let current = Argon2::new(
+            Algorithm::Argon2id,
+            Version::V0x13,
+            Params::new(16 * 1024, 2, 1, None).unwrap(),
+        );
+        let outdated = Argon2::new(
+            Algorithm::Argon2id,
+            Version::V0x13,
+            Params::new(8 * 1024, 1, 1, None).unwrap(),
+        );
+        let other_algorithm = Argon2::new(
+            Algorithm::Argon2i,
+            Version::V0x13,
+            Params::new(16 * 1024, 2, 1, None).unwrap(),
+        );
+
+        let current_hash = hash(&current);
+        let outdated_hash = hash(&outdated);
+        let other_algorithm_hash = hash(&other_algorithm);
+
+        assert!(!needs_rehash(
+            &current,
+            &PasswordHash::new(&current_hash).unwrap()
+        ));
+        assert!(needs_rehash(
+            &current,
+            &PasswordHash::new(&outdated_hash).unwrap()
+        ));
+        assert!(needs_rehash(
+            &current,
+            &PasswordHash::new(&other_algorithm_hash).unwrap()
+        ));
+    }
+}
@@ -0,0 +1,28 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+
+use crate::core::token::generate_token;
+
+/// Generates a PKCE code verifier (RFC 7636), 64 hex characters.
+pub fn generate_code_verifier() -> String {
+    generate_token()
+}
+
+/// Derives the `S256` code challenge for a verifier.
+pub fn code_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_challenge_rfc_7636_example() {
+        assert_eq!(
+            code_challenge("dBjftJeZ4CVP-1mB0B6DYsSHuq3iGd3mxrxV6BvtLXw"),
+            "E9Melhoa2OwvFrEMTJguCHaoeKt8s1VjH7T4U3Xw4wY"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Months, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Subset of an RFC 5545 RRULE: `FREQ` with optional `INTERVAL` and either `COUNT` or
+/// `UNTIL`, e.g. `FREQ=WEEKLY;INTERVAL=2;COUNT=10`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    /// Number of occurrences in the series, the first one included.
+    pub count: Option<u32>,
+    /// Last timestamp an occurrence may end at.
+    pub until: Option<i64>,
+}
+
+impl RecurrenceRule {
+    /// Parses a rule, returns `None` for anything outside the supported subset.
+    pub fn parse(rule: &str) -> Option<RecurrenceRule> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+        let mut frequency = None;
+        let mut interval = None;
+        let mut count = None;
+        let mut until = None;
+
+        for part in rule.split(';') {
+            let (key, value) = part.split_once('=')?;
+
+            match key {
+                "FREQ" if frequency.is_none() => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        _ => return None,
+                    })
+                }
+                "INTERVAL" if interval.is_none() => {
+                    interval = Some(
+                        value
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|n| (1..=365).contains(n))?,
+                    )
+                }
+                "COUNT" if count.is_none() => {
+                    count = Some(value.parse::<u32>().ok().filter(|n| *n >= 1)?)
+                }
+                "UNTIL" if until.is_none() => until = Some(parse_until(value)?),
+                _ => return None,
+            }
+        }
+
+        // RFC 5545 doesn't allow both COUNT and UNTIL in one rule.
+        if count.is_some() && until.is_some() {
+            return None;
+        }
+
+        Some(RecurrenceRule {
+            frequency: frequency?,
+            interval: interval.unwrap_or(1),
+            count,
+            until,
+        })
+    }
+
+    /// End timestamp of the occurrence after number `index`, counting from 0, of the
+    /// series whose first occurrence ends at `anchor`. `None` once the series is over.
+    ///
+    /// Every occurrence is counted from the anchor, so a monthly series on the 31st
+    /// goes back to the 31st after a shorter month.
+    pub fn next(&self, anchor: i64, index: i32) -> Option<i64> {
+        if let Some(count) = self.count {
+            if i64::from(index) + 1 >= i64::from(count) {
+                return None;
+            }
+        }
+
+        let anchor = DateTime::<Utc>::from_timestamp(anchor, 0)?;
+        let steps = u32::try_from(index)
+            .ok()?
+            .checked_add(1)?
+            .checked_mul(self.interval)?;
+
+        let next = match self.frequency {
+            Frequency::Daily => anchor.checked_add_signed(TimeDelta::try_days(steps.into())?),
+            Frequency::Weekly => anchor.checked_add_signed(TimeDelta::try_weeks(steps.into())?),
+            Frequency::Monthly => anchor.checked_add_months(Months::new(steps)),
+        }?
+        .timestamp();
+
+        match self.until {
+            Some(until) if next > until => None,
+            _ => Some(next),
+        }
+    }
+}
+
+impl Display for RecurrenceRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+
+        write!(f, "FREQ={}", frequency)?;
+
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+
+        if let Some(until) = self
+            .until
+            .and_then(|until| DateTime::<Utc>::from_timestamp(until, 0))
+        {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Accepts a UTC date-time (`20261231T235959Z`) or a date, which lasts until its end.
+fn parse_until(value: &str) -> Option<i64> {
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Some(date_time.and_utc().timestamp());
+    }
+
+    let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+
+    Some(date.and_hms_opt(23, 59, 59)?.and_utc().timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 60 * 60 * 24;
+
+    fn timestamp(value: &str) -> i64 {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_utc()
+            .timestamp()
+    }
+
+    #[test]
+    fn test_parse_defaults_interval_to_one() {
+        let rule = RecurrenceRule::parse("FREQ=DAILY").unwrap();
+
+        assert_eq!(rule.frequency, Frequency::Daily);
+        assert_eq!(rule.interval, 1);
+        assert_eq!(rule.count, None);
+        assert_eq!(rule.until, None);
+    }
+
+    #[test]
+    fn test_parse_accepts_rrule_prefix() {
+        let rule = RecurrenceRule::parse("RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=5").unwrap();
+
+        assert_eq!(rule.frequency, Frequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.count, Some(5));
+    }
+
+    #[test]
+    fn test_parse_until_date_lasts_the_whole_day() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY;UNTIL=20261231").unwrap();
+
+        assert_eq!(rule.until, Some(timestamp("2026-12-31 23:59:59")));
+    }
+
+    #[test]
+    fn test_parse_rejects_unsupported_rules() {
+        for rule in [
+            "",
+            "INTERVAL=2",
+            "FREQ=YEARLY",
+            "FREQ=DAILY;BYDAY=MO",
+            "FREQ=DAILY;FREQ=WEEKLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;COUNT=0",
+            "FREQ=DAILY;COUNT=2;UNTIL=20261231",
+            "FREQ=DAILY;UNTIL=tomorrow",
+        ] {
+            assert_eq!(RecurrenceRule::parse(rule), None, "{}", rule);
+        }
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for rule in [
+            "FREQ=DAILY",
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=5",
+            "FREQ=MONTHLY;UNTIL=20261231T235959Z",
+        ] {
+            assert_eq!(RecurrenceRule::parse(rule).unwrap().to_string(), rule);
+        }
+    }
+
+    #[test]
+    fn test_next_adds_the_interval() {
+        let end = timestamp("2026-10-19 12:00:00");
+
+        let daily = RecurrenceRule::parse("FREQ=DAILY;INTERVAL=3").unwrap();
+        assert_eq!(daily.next(end, 0), Some(end + 3 * DAY));
+        assert_eq!(daily.next(end, 1), Some(end + 6 * DAY));
+
+        let weekly = RecurrenceRule::parse("FREQ=WEEKLY").unwrap();
+        assert_eq!(weekly.next(end, 0), Some(end + 7 * DAY));
+    }
+
+    #[test]
+    fn test_next_monthly_clamps_to_the_end_of_the_month() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY").unwrap();
+
+        assert_eq!(
+            rule.next(timestamp("2026-01-31 09:00:00"), 0),
+            Some(timestamp("2026-02-28 09:00:00"))
+        );
+    }
+
+    #[test]
+    fn test_next_monthly_keeps_the_day_of_the_anchor() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY").unwrap();
+        let anchor = timestamp("2026-01-31 09:00:00");
+
+        assert_eq!(rule.next(anchor, 0), Some(timestamp("2026-02-28 09:00:00")));
+        assert_eq!(rule.next(anchor, 1), Some(timestamp("2026-03-31 09:00:00")));
+        assert_eq!(rule.next(anchor, 2), Some(timestamp("2026-04-30 09:00:00")));
+    }
+
+    #[test]
+    fn test_next_stops_after_count() {
+        let rule = RecurrenceRule::parse("FREQ=DAILY;COUNT=3").unwrap();
+        let end = timestamp("2026-10-19 12:00:00");
+
+        assert!(rule.next(end, 1).is_some());
+        assert_eq!(rule.next(end, 2), None);
+    }
+
+    #[test]
+    fn test_next_stops_after_until() {
+        let rule = RecurrenceRule::parse("FREQ=WEEKLY;UNTIL=20261101").unwrap();
+
+        assert!(rule.next(timestamp("2026-10-25 12:00:00"), 0).is_some());
+        assert_eq!(rule.next(timestamp("2026-10-26 12:00:00"), 0), None);
+    }
+}
@@ -1,15 +1,17 @@
 use regex::{Error, Regex};
 
-use crate::domain::constants::EMAIL;
+use crate::domain::constants::{EMAIL, LABEL_COLOR};
 
 #[derive(Default)]
 pub struct CachedRegexValidator {
     email: Option<Regex>,
+    label_color: Option<Regex>,
 }
 
 impl CachedRegexValidator {
     pub fn compile_all(&mut self) {
-        self.email = Some(Regex::new(EMAIL).unwrap())
+        self.email = Some(Regex::new(EMAIL).unwrap());
+        self.label_color = Some(Regex::new(LABEL_COLOR).unwrap());
     }
 
     pub fn check_email(&self, email: &str) -> Result<(), Error> {
@@ -24,4 +26,17 @@ impl CachedRegexValidator {
             }
         }
     }
+
+    pub fn check_label_color(&self, color: &str) -> Result<(), Error> {
+        match &self.label_color {
+            None => Err(Error::Syntax(String::from("Label color pattern not init."))),
+            Some(regex) => {
+                if !regex.is_match(color) {
+                    Err(Error::Syntax(color.to_string()))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
 }
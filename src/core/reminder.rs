@@ -0,0 +1,139 @@
+/// Parses reminder offsets like `24h,1h,30m` into seconds, largest first. Units are
+/// `d`, `h` and `m`. Returns `None` for an invalid or non-positive offset.
+pub fn parse_offsets(offsets: &str) -> Option<Vec<i64>> {
+    let mut parsed = offsets
+        .split(',')
+        .map(str::trim)
+        .filter(|offset| !offset.is_empty())
+        .map(|offset| {
+            let unit = offset.chars().last()?;
+            let amount = offset[..offset.len() - unit.len_utf8()]
+                .parse::<i64>()
+                .ok()
+                .filter(|amount| *amount > 0)?;
+
+            let unit = match unit {
+                'd' => 60 * 60 * 24,
+                'h' => 60 * 60,
+                'm' => 60,
+                _ => return None,
+            };
+
+            amount.checked_mul(unit)
+        })
+        .collect::<Option<Vec<i64>>>()?;
+
+    parsed.sort_unstable_by(|a, b| b.cmp(a));
+    parsed.dedup();
+
+    Some(parsed)
+}
+
+/// Picks the reminder to send for a task ending at `end_timestamp`: the smallest offset
+/// that has been reached, `0` once the task is overdue. `None` before the first one.
+pub fn due_offset(offsets: &[i64], end_timestamp: i64, now: i64) -> Option<i64> {
+    if end_timestamp <= now {
+        return Some(0);
+    }
+
+    offsets
+        .iter()
+        .copied()
+        .filter(|offset| end_timestamp - offset <= now)
+        .min()
+}
+
+/// Picks the reminder to send now, skipping it when `last_offset`, the smallest offset
+/// already sent for this end timestamp, covers it.
+pub fn reminder_to_send(
+    offsets: &[i64],
+    end_timestamp: i64,
+    last_offset: Option<i64>,
+    now: i64,
+) -> Option<i64> {
+    let offset = due_offset(offsets, end_timestamp, now)?;
+
+    match last_offset {
+        Some(last_offset) if last_offset <= offset => None,
+        _ => Some(offset),
+    }
+}
+
+/// Describes an offset for a reminder mail, e.g. `1 hour` or `2 days`.
+pub fn describe_offset(seconds: i64) -> String {
+    let (amount, unit) = if seconds % (60 * 60 * 24) == 0 {
+        (seconds / (60 * 60 * 24), "day")
+    } else if seconds % (60 * 60) == 0 {
+        (seconds / (60 * 60), "hour")
+    } else {
+        (seconds / 60, "minute")
+    };
+
+    if amount == 1 {
+        format!("{} {}", amount, unit)
+    } else {
+        format!("{} {}s", amount, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 60 * 60;
+
+    #[test]
+    fn test_parse_offsets_sorts_largest_first() {
+        assert_eq!(
+            parse_offsets("1h, 2d,30m,1h"),
+            Some(vec![2 * 24 * HOUR, HOUR, 30 * 60])
+        );
+    }
+
+    #[test]
+    fn test_parse_offsets_allows_no_reminders() {
+        assert_eq!(parse_offsets(""), Some(vec![]));
+    }
+
+    #[test]
+    fn test_parse_offsets_rejects_invalid_offsets() {
+        for offsets in ["24", "h", "0h", "-1h", "1w", "1.5h", "1h,x"] {
+            assert_eq!(parse_offsets(offsets), None, "{}", offsets);
+        }
+    }
+
+    #[test]
+    fn test_due_offset_picks_the_closest_reached_offset() {
+        let offsets = [24 * HOUR, HOUR];
+        let end = 100 * HOUR;
+
+        assert_eq!(due_offset(&offsets, end, end - 25 * HOUR), None);
+        assert_eq!(due_offset(&offsets, end, end - 24 * HOUR), Some(24 * HOUR));
+        assert_eq!(due_offset(&offsets, end, end - 30 * 60), Some(HOUR));
+        assert_eq!(due_offset(&offsets, end, end), Some(0));
+    }
+
+    #[test]
+    fn test_reminder_to_send_skips_claimed_offsets() {
+        let offsets = [24 * HOUR, HOUR];
+        let end = 100 * HOUR;
+        let now = end - 30 * 60;
+
+        assert_eq!(reminder_to_send(&offsets, end, None, now), Some(HOUR));
+        assert_eq!(
+            reminder_to_send(&offsets, end, Some(24 * HOUR), now),
+            Some(HOUR)
+        );
+        assert_eq!(reminder_to_send(&offsets, end, Some(HOUR), now), None);
+        assert_eq!(reminder_to_send(&offsets, end, Some(HOUR), end), Some(0));
+        assert_eq!(reminder_to_send(&offsets, end, Some(0), end), None);
+    }
+
+    #[test]
+    fn test_describe_offset() {
+        assert_eq!(describe_offset(24 * HOUR), "1 day");
+        assert_eq!(describe_offset(HOUR), "1 hour");
+        assert_eq!(describe_offset(36 * HOUR), "36 hours");
+        assert_eq!(describe_offset(30 * 60), "30 minutes");
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+use uuid::Uuid;
+
+/// Local copy of the revoked signed access tokens, keyed by `jti`, so verifying a
+/// token needs no store lookup. It is refreshed in the background and tokens revoked
+/// on this server are added right away.
+#[derive(Debug, Default)]
+pub struct RevokedTokens {
+    jtis: RwLock<HashSet<Uuid>>,
+}
+
+impl RevokedTokens {
+    /// Fails closed if a writer panicked while holding the lock.
+    pub fn is_revoked(&self, jti: &Uuid) -> bool {
+        self.jtis
+            .read()
+            .map(|jtis| jtis.contains(jti))
+            .unwrap_or(true)
+    }
+
+    pub fn insert(&self, jti: Uuid) {
+        if let Ok(mut jtis) = self.jtis.write() {
+            jtis.insert(jti);
+        }
+    }
+
+    /// Replaces the copy with the current deny-list, expired tokens drop out of it.
+    pub fn replace(&self, revoked: Vec<Uuid>) {
+        if let Ok(mut jtis) = self.jtis.write() {
+            *jtis = revoked.into_iter().collect();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_revokes() {
+        let revoked_tokens = RevokedTokens::default();
+        let jti = Uuid::now_v7();
+
+        assert!(!revoked_tokens.is_revoked(&jti));
+
+        revoked_tokens.insert(jti);
+
+        assert!(revoked_tokens.is_revoked(&jti));
+    }
+
+    #[test]
+    fn test_replace_drops_expired_tokens() {
+        let revoked_tokens = RevokedTokens::default();
+        let expired = Uuid::now_v7();
+        let current = Uuid::now_v7();
+
+        revoked_tokens.insert(expired);
+        revoked_tokens.replace(vec![current]);
+
+        assert!(!revoked_tokens.is_revoked(&expired));
+        assert!(revoked_tokens.is_revoked(&current));
+    }
+}
@@ -0,0 +1,126 @@
+use std::env;
+use std::fmt::{Debug, Formatter};
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::domain::constants::TWO_FACTOR_ENCRYPTION_KEY;
+
+const PREFIX: &str = "v1:";
+const NONCE_BYTES: usize = 12;
+
+/// Encrypts secrets that have to be read back, like TOTP seeds, with AES-256-GCM.
+/// Encrypted values are `v1:` followed by the base64 of the nonce and ciphertext.
+pub struct SecretCipher {
+    cipher: Aes256Gcm,
+}
+
+impl Debug for SecretCipher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretCipher").finish_non_exhaustive()
+    }
+}
+
+impl SecretCipher {
+    pub fn new(key: &[u8]) -> Option<Self> {
+        Aes256Gcm::new_from_slice(key)
+            .ok()
+            .map(|cipher| SecretCipher { cipher })
+    }
+
+    /// Reads the base64 encoded 32 byte key from `TWO_FACTOR_ENCRYPTION_KEY`.
+    pub fn from_env() -> Self {
+        let key =
+            env::var(TWO_FACTOR_ENCRYPTION_KEY).expect("TWO_FACTOR_ENCRYPTION_KEY must be set");
+
+        STANDARD
+            .decode(key.trim())
+            .ok()
+            .and_then(|key| Self::new(&key))
+            .expect("TWO_FACTOR_ENCRYPTION_KEY must be 32 base64 encoded bytes")
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .expect("Can't encrypt secret");
+
+        let mut bytes = nonce.to_vec();
+        bytes.extend(ciphertext);
+
+        format!("{}{}", PREFIX, STANDARD.encode(bytes))
+    }
+
+    /// Returns `None` for values that weren't encrypted with this key or were tampered with.
+    pub fn decrypt(&self, value: &str) -> Option<String> {
+        let bytes = STANDARD.decode(value.strip_prefix(PREFIX)?).ok()?;
+
+        if bytes.len() < NONCE_BYTES {
+            return None;
+        }
+
+        let (nonce, ciphertext) = bytes.split_at(NONCE_BYTES);
+
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .ok()?;
+
+        String::from_utf8(plaintext).ok()
+    }
+
+    /// Values stored before encryption was introduced don't carry the prefix.
+    pub fn is_encrypted(value: &str) -> bool {
+        value.starts_with(PREFIX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let cipher = SecretCipher::new(&[7; 32]).unwrap();
+
+        let encrypted = cipher.encrypt("JBSWY3DPEHPK3PXP");
+
+        assert!(SecretCipher::is_encrypted(&encrypted));
+        assert!(!encrypted.contains("JBSWY3DPEHPK3PXP"));
+        assert_eq!(
+            cipher.decrypt(&encrypted).as_deref(),
+            Some("JBSWY3DPEHPK3PXP")
+        );
+    }
+
+    #[test]
+    fn test_encrypt_uses_a_fresh_nonce() {
+        let cipher = SecretCipher::new(&[7; 32]).unwrap();
+
+        assert_ne!(cipher.encrypt("secret"), cipher.encrypt("secret"));
+    }
+
+    #[test]
+    fn test_decrypt_rejects_other_keys_and_tampering() {
+        let cipher = SecretCipher::new(&[7; 32]).unwrap();
+        let other = SecretCipher::new(&[8; 32]).unwrap();
+
+        let encrypted = cipher.encrypt("secret");
+        let mut tampered = encrypted.clone();
+        tampered.replace_range(10..11, if &encrypted[10..11] == "A" { "B" } else { "A" });
+
+        assert_eq!(other.decrypt(&encrypted), None);
+        assert_eq!(cipher.decrypt(&tampered), None);
+        assert_eq!(cipher.decrypt("secret"), None);
+    }
+
+    #[test]
+    fn test_new_requires_a_256_bit_key() {
+        assert!(SecretCipher::new(&[7; 16]).is_none());
+    }
+}
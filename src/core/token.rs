@@ -0,0 +1,19 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+const TOKEN_BYTES: usize = 32;
+
+/// Generates a random hex encoded token suitable for sessions and one-time links.
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+
+    base16ct::lower::encode_string(&bytes)
+}
+
+/// Hashes a token so that only the digest has to be persisted.
+pub fn hash_token(token: &str) -> String {
+    let hash = Sha256::digest(token.as_bytes());
+
+    base16ct::lower::encode_string(&hash)
+}
@@ -0,0 +1,138 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use chrono::Utc;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use crate::domain::constants::TOTP_ISSUER;
+
+const DIGITS: usize = 6;
+const SKEW: i64 = 1;
+const STEP: u64 = 30;
+const RECOVERY_CODE_BYTES: usize = 5;
+
+/// Generates a new base32 encoded TOTP secret.
+pub fn generate_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+fn totp(secret: &str, account_name: &str) -> Option<TOTP> {
+    let secret = Secret::Encoded(secret.to_string()).to_bytes().ok()?;
+
+    // Skew is handled by `verify_code`, which has to know the matching step.
+    TOTP::new(
+        Algorithm::SHA1,
+        DIGITS,
+        0,
+        STEP,
+        secret,
+        Some(TOTP_ISSUER.to_string()),
+        account_name.to_string(),
+    )
+    .ok()
+}
+
+/// Builds the `otpauth://` URI authenticator apps use to enroll the secret.
+pub fn provisioning_uri(secret: &str, account_name: &str) -> Option<String> {
+    totp(secret, account_name).map(|totp| totp.get_url())
+}
+
+/// Checks a code against the current time step, allowing one step of clock skew, and
+/// returns the step it matched. Steps up to `last_used_step` are rejected, so a code
+/// can't be replayed while it is still valid.
+pub fn verify_code(secret: &str, code: &str, last_used_step: Option<i64>) -> Option<i64> {
+    verify_code_at(secret, code, last_used_step, Utc::now().timestamp())
+}
+
+fn verify_code_at(secret: &str, code: &str, last_used_step: Option<i64>, now: i64) -> Option<i64> {
+    let totp = totp(secret, "")?;
+    let current_step = now / STEP as i64;
+
+    (current_step - SKEW..=current_step + SKEW)
+        .filter(|step| *step >= 0 && last_used_step.map_or(true, |last| *step > last))
+        .find(|step| totp.check(code, *step as u64 * STEP))
+}
+
+pub fn generate_recovery_codes(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|_| {
+            let mut bytes = [0u8; RECOVERY_CODE_BYTES];
+            OsRng.fill_bytes(&mut bytes);
+            base16ct::lower::encode_string(&bytes)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provisioning_uri() {
+        let secret = generate_secret();
+
+        let uri = provisioning_uri(&secret, "user@taskem.app").unwrap();
+
+        assert!(uri.starts_with("otpauth://totp/Taskem:user%40taskem.app?"));
+        assert!(uri.contains(&format!("secret={}", secret)));
+    }
+
+    const NOW: i64 = 1_790_000_000;
+
+    fn code_at(secret: &str, time: i64) -> String {
+        totp(secret, "").unwrap().generate(time as u64)
+    }
+
+    #[test]
+    fn test_verify_code() {
+        let secret = generate_secret();
+        let code = totp(&secret, "").unwrap().generate_current().unwrap();
+
+        assert!(verify_code(&secret, &code, None).is_some());
+        assert!(verify_code(&secret, "not a code", None).is_none());
+        assert!(verify_code("invalid secret", &code, None).is_none());
+    }
+
+    #[test]
+    fn test_verify_code_returns_the_matched_step() {
+        let secret = generate_secret();
+        let step = NOW / STEP as i64;
+
+        let previous = code_at(&secret, NOW - STEP as i64);
+        let next = code_at(&secret, NOW + STEP as i64);
+        let stale = code_at(&secret, NOW - 2 * STEP as i64);
+
+        assert_eq!(
+            verify_code_at(&secret, &previous, None, NOW),
+            Some(step - 1)
+        );
+        assert_eq!(verify_code_at(&secret, &next, None, NOW), Some(step + 1));
+        assert_eq!(verify_code_at(&secret, &stale, None, NOW), None);
+    }
+
+    #[test]
+    fn test_verify_code_rejects_replayed_steps() {
+        let secret = generate_secret();
+        let code = code_at(&secret, NOW);
+
+        let step = verify_code_at(&secret, &code, None, NOW).unwrap();
+
+        assert_eq!(verify_code_at(&secret, &code, Some(step), NOW), None);
+        assert_eq!(
+            verify_code_at(&secret, &code, Some(step), NOW + STEP as i64),
+            None
+        );
+        assert_eq!(
+            verify_code_at(&secret, &code, Some(step - 1), NOW),
+            Some(step)
+        );
+    }
+
+    #[test]
+    fn test_generate_recovery_codes() {
+        let codes = generate_recovery_codes(10);
+
+        assert_eq!(codes.len(), 10);
+        assert!(codes
+            .iter()
+            .all(|code| code.len() == RECOVERY_CODE_BYTES * 2));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::domain::error::Result;
+use crate::domain::repositories::file::FileRepository;
+
+const USERS_BUCKET: &str = "users";
+
+/// Copies every file stored under `from/` to `to/` and returns the keys of both.
+///
+/// On failure the keys already copied are deleted again, so the caller only has
+/// to clean up when it decides to roll back a successful copy.
+pub async fn copy_user_files(
+    file_repository: &dyn FileRepository,
+    from: &str,
+    to: &str,
+) -> Result<(Vec<String>, Vec<String>)> {
+    let old_keys = file_repository
+        .list(USERS_BUCKET, &format!("{}/", from))
+        .await?;
+
+    let mut new_keys = Vec::with_capacity(old_keys.len());
+
+    for old_key in &old_keys {
+        let new_key = format!("{}{}", to, &old_key[from.len()..]);
+
+        let copied = match file_repository.download(USERS_BUCKET, old_key).await {
+            Ok(content) => {
+                file_repository
+                    .upload(USERS_BUCKET, &new_key, &content)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = copied {
+            delete_user_files(file_repository, &new_keys).await;
+            return Err(e);
+        }
+
+        new_keys.push(new_key);
+    }
+
+    Ok((old_keys, new_keys))
+}
+
+/// Deletes the given keys, logging instead of failing on individual errors.
+pub async fn delete_user_files(file_repository: &dyn FileRepository, keys: &[String]) {
+    for key in keys {
+        if let Err(e) = file_repository.delete(USERS_BUCKET, key).await {
+            tracing::error!("Failed to delete file {}: {:?}", key, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::domain::error::Error;
+    use crate::domain::repositories::file::MockFileRepository;
+
+    #[tokio::test]
+    async fn test_copy_user_files_copies_every_key() {
+        let mut mock = MockFileRepository::default();
+
+        mock.expect_list()
+            .with(eq("users"), eq("alice/"))
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![
+                    String::from("alice/avatar.jpg"),
+                    String::from("alice/cover.png"),
+                ])
+            });
+        mock.expect_download()
+            .times(2)
+            .returning(|_, key| Ok(key.as_bytes().to_vec()));
+        mock.expect_upload()
+            .with(
+                eq("users"),
+                eq("alice2/avatar.jpg"),
+                eq(b"alice/avatar.jpg".to_vec()),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock.expect_upload()
+            .with(
+                eq("users"),
+                eq("alice2/cover.png"),
+                eq(b"alice/cover.png".to_vec()),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock.expect_delete().never();
+
+        let (old_keys, new_keys) = copy_user_files(&mock, "alice", "alice2").await.unwrap();
+
+        assert_eq!(old_keys, vec!["alice/avatar.jpg", "alice/cover.png"]);
+        assert_eq!(new_keys, vec!["alice2/avatar.jpg", "alice2/cover.png"]);
+    }
+
+    #[tokio::test]
+    async fn test_copy_user_files_removes_partial_copy_on_failure() {
+        let mut mock = MockFileRepository::default();
+
+        mock.expect_list().times(1).returning(|_, _| {
+            Ok(vec![
+                String::from("alice/avatar.jpg"),
+                String::from("alice/cover.png"),
+            ])
+        });
+        mock.expect_download()
+            .with(eq("users"), eq("alice/avatar.jpg"))
+            .returning(|_, _| Ok(vec![1]));
+        mock.expect_download()
+            .with(eq("users"), eq("alice/cover.png"))
+            .returning(|_, _| Err(Error::FileError));
+        mock.expect_upload().times(1).returning(|_, _, _| Ok(()));
+        mock.expect_delete()
+            .with(eq("users"), eq("alice2/avatar.jpg"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = copy_user_files(&mock, "alice", "alice2").await;
+
+        assert!(matches!(result, Err(Error::FileError)));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_files_continues_after_error() {
+        let mut mock = MockFileRepository::default();
+
+        mock.expect_delete()
+            .with(eq("users"), eq("alice/avatar.jpg"))
+            .times(1)
+            .returning(|_, _| Err(Error::FileError));
+        mock.expect_delete()
+            .with(eq("users"), eq("alice/cover.png"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        delete_user_files(
+            &mock,
+            &[
+                String::from("alice/avatar.jpg"),
+                String::from("alice/cover.png"),
+            ],
+        )
+        .await;
+    }
+}
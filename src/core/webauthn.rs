@@ -0,0 +1,117 @@
+use std::env;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use webauthn_rs::prelude::{Credential, CredentialID, Passkey, Url};
+use webauthn_rs::{Webauthn, WebauthnBuilder};
+
+use crate::domain::constants::{WEBAUTHN_RP_ID, WEBAUTHN_RP_NAME, WEBAUTHN_RP_ORIGIN};
+
+/// Builds the relying party from `WEBAUTHN_RP_ID` and `WEBAUTHN_RP_ORIGIN`, or `None` when
+/// passkeys are not configured. Registrations request "none" attestation, so any
+/// authenticator is accepted without vendor certificates.
+pub fn webauthn_from_env() -> Option<Webauthn> {
+    let rp_id = env::var(WEBAUTHN_RP_ID).ok()?;
+    let rp_origin = env::var(WEBAUTHN_RP_ORIGIN).expect("WEBAUTHN_RP_ORIGIN must be set");
+    let rp_origin = Url::parse(&rp_origin).expect("WEBAUTHN_RP_ORIGIN must be a valid url");
+    let rp_name = env::var(WEBAUTHN_RP_NAME).unwrap_or_else(|_| String::from("Taskem"));
+
+    let webauthn = WebauthnBuilder::new(&rp_id, &rp_origin)
+        .expect("Invalid WebAuthn relying party")
+        .rp_name(&rp_name)
+        .build()
+        .expect("Invalid WebAuthn relying party");
+
+    Some(webauthn)
+}
+
+/// Encodes a credential id the way it is stored and sent by browsers.
+pub fn encode_credential_id(credential_id: &CredentialID) -> String {
+    URL_SAFE_NO_PAD.encode(credential_id.as_ref())
+}
+
+/// Returns the signature counter the authenticator reported for the passkey.
+pub fn passkey_sign_count(passkey: &Passkey) -> i64 {
+    i64::from(Credential::from(passkey.clone()).counter)
+}
+
+/// Checks the counter of a new signature against the stored one. Authenticators without
+/// a counter always report zero. Otherwise the counter has to grow, a repeated or lower
+/// value means the credential was likely cloned.
+pub fn is_sign_count_valid(stored: i64, reported: i64) -> bool {
+    (stored == 0 && reported == 0) || reported > stored
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use webauthn_authenticator_rs::softpasskey::SoftPasskey;
+    use webauthn_authenticator_rs::WebauthnAuthenticator;
+
+    use super::*;
+
+    fn test_webauthn() -> (Webauthn, Url) {
+        let origin = Url::parse("https://localhost:8080").unwrap();
+        let webauthn = WebauthnBuilder::new("localhost", &origin)
+            .unwrap()
+            .rp_name("Taskem")
+            .build()
+            .unwrap();
+
+        (webauthn, origin)
+    }
+
+    #[test]
+    fn test_passkey_register_then_login() {
+        let (webauthn, origin) = test_webauthn();
+        let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+
+        let (challenge, registration) = webauthn
+            .start_passkey_registration(Uuid::now_v7(), "alice", "alice", None)
+            .unwrap();
+        let credential = authenticator
+            .do_registration(origin.clone(), challenge)
+            .unwrap();
+        let mut passkey = webauthn
+            .finish_passkey_registration(&credential, &registration)
+            .unwrap();
+        let registered_sign_count = passkey_sign_count(&passkey);
+
+        let (challenge, authentication) = webauthn
+            .start_passkey_authentication(&[passkey.clone()])
+            .unwrap();
+        let options = serde_json::to_value(&challenge).unwrap();
+        assert_eq!(
+            options["publicKey"]["userVerification"].as_str(),
+            Some("required")
+        );
+
+        let credential = authenticator.do_authentication(origin, challenge).unwrap();
+        let result = webauthn
+            .finish_passkey_authentication(&credential, &authentication)
+            .unwrap();
+
+        assert!(result.user_verified());
+        assert_eq!(
+            encode_credential_id(result.cred_id()),
+            encode_credential_id(passkey.cred_id())
+        );
+        assert!(is_sign_count_valid(
+            registered_sign_count,
+            i64::from(result.counter())
+        ));
+
+        passkey.update_credential(&result);
+        assert_eq!(passkey_sign_count(&passkey), i64::from(result.counter()));
+    }
+
+    #[test]
+    fn test_sign_count_has_to_grow() {
+        assert!(is_sign_count_valid(0, 0));
+        assert!(is_sign_count_valid(0, 1));
+        assert!(is_sign_count_valid(4, 5));
+        assert!(!is_sign_count_valid(5, 5));
+        assert!(!is_sign_count_valid(5, 3));
+        assert!(!is_sign_count_valid(5, 0));
+    }
+}
@@ -1,14 +1,109 @@
+use uuid::Uuid;
+
 pub const POSTGRESQL_DB_URI: &str = "DATABASE_URL";
 
 pub const REDIS_CON_STRING: &str = "REDIS_URL";
 
 pub const MIDDLEWARE_AUTH_SESSION_KEY: &str = "authorization";
 
+pub const MIDDLEWARE_AUTH_USER_KEY: &str = "x-user-id";
+
+pub const ACCESS_TOKEN_PREFIX: &str = "tkm_pat_";
+
 pub const CACHE_POOL_MAX_OPEN: u32 = 16;
 pub const CACHE_POOL_MIN_IDLE: u32 = 8;
 
 pub const CACHE_POOL_EXPIRE_SECONDS: u64 = 60;
 
+pub const USER_NAME_MAX_LENGTH: usize = 25;
+
+pub const TASK_PAGE_SIZE: i64 = 50;
+pub const TASK_PAGE_SIZE_MAX: i64 = 200;
+pub const TASK_SEARCH_LIMIT: i64 = 20;
+pub const TASK_SEARCH_LIMIT_MAX: i64 = 50;
+pub const TASK_BOARD_PAGE_SIZE: i64 = 500;
+pub const TASK_CHECKLIST_MAX_ITEMS: usize = 100;
+pub const TASK_COMMENT_MAX_LENGTH: usize = 5000;
+
+pub const EXPORT_CHUNK_BYTES: usize = 1024 * 1024;
+pub const EXPORT_MAX_BYTES: usize = 256 * 1024 * 1024;
+
+pub const LABEL_COLOR: &str = r"^#[0-9a-fA-F]{6}$";
 pub const EMAIL: &str = r"^(([^<>()\[\]\\.,;:\s@”]+(\.[^<>()\[\]\\.,;:\s@”]+)*)|(“.+”))@((\[[0–9]{1,3}\.[0–9]{1,3}\.[0–9]{1,3}\.[0–9]{1,3}])|(([a-zA-Z\-0–9]+\.)+[a-zA-Z]{2,}))$";
 
 pub const ONE_DAY: i64 = 60 * 60 * 24;
+
+pub const SESSION_EXPIRE_SECONDS: i64 = ONE_DAY * 3;
+
+pub const REFRESH_TOKEN_EXPIRE_SECONDS: i64 = ONE_DAY * 30;
+
+pub const PASSWORD_RESET_TOKEN: &str = "password_reset";
+pub const PASSWORD_RESET_EXPIRE_SECONDS: i64 = 60 * 30;
+
+pub const EMAIL_VERIFICATION_TOKEN: &str = "email_verification";
+pub const EMAIL_VERIFICATION_EXPIRE_SECONDS: i64 = ONE_DAY;
+
+pub const EMAIL_CHANGE_TOKEN: &str = "email_change";
+pub const EMAIL_CHANGE_EXPIRE_SECONDS: i64 = ONE_DAY;
+
+pub const MAGIC_LINK_TOKEN: &str = "magic_link";
+pub const MAGIC_LINK_EXPIRE_SECONDS: i64 = 60 * 15;
+pub const MAGIC_LINK_RATE_LIMIT: u32 = 3;
+pub const MAGIC_LINK_RATE_LIMIT_WINDOW_SECONDS: i64 = 60 * 60;
+
+pub const TWO_FACTOR_CHALLENGE_TOKEN: &str = "two_factor_challenge";
+pub const TWO_FACTOR_CHALLENGE_EXPIRE_SECONDS: i64 = 60 * 5;
+
+pub const PASSKEY_REGISTRATION_TOKEN: &str = "passkey_registration";
+pub const PASSKEY_AUTHENTICATION_TOKEN: &str = "passkey_authentication";
+pub const PASSKEY_CHALLENGE_EXPIRE_SECONDS: i64 = 60 * 5;
+
+pub const OIDC_LOGIN_STATE_TOKEN: &str = "oidc_login_state";
+pub const OIDC_LOGIN_STATE_EXPIRE_SECONDS: i64 = 60 * 10;
+
+/// Owner of tasks and teams left behind by deleted accounts.
+pub const DELETED_USER_ID: Uuid = Uuid::nil();
+
+pub const TOTP_ISSUER: &str = "Taskem";
+pub const RECOVERY_CODES_COUNT: usize = 10;
+
+pub const TWO_FACTOR_ENCRYPTION_KEY: &str = "TWO_FACTOR_ENCRYPTION_KEY";
+
+pub const MAIL_OUTBOX_DIR: &str = "MAIL_OUTBOX_DIR";
+
+pub const EMAIL_VERIFICATION_POLICY: &str = "EMAIL_VERIFICATION_POLICY";
+
+pub const ACCOUNT_DELETION_GRACE_DAYS: &str = "ACCOUNT_DELETION_GRACE_DAYS";
+
+pub const PASSWORD_MIN_LENGTH: &str = "PASSWORD_MIN_LENGTH";
+pub const PASSWORD_MIN_ENTROPY_BITS: &str = "PASSWORD_MIN_ENTROPY_BITS";
+pub const PASSWORD_DISALLOW_PERSONAL_INFO: &str = "PASSWORD_DISALLOW_PERSONAL_INFO";
+pub const BREACHED_PASSWORDS_DIR: &str = "BREACHED_PASSWORDS_DIR";
+
+pub const ARGON2_MEMORY_COST_KIB: &str = "ARGON2_MEMORY_COST_KIB";
+pub const ARGON2_TIME_COST: &str = "ARGON2_TIME_COST";
+pub const ARGON2_PARALLELISM: &str = "ARGON2_PARALLELISM";
+
+pub const MAGIC_LINK_LOGIN: &str = "MAGIC_LINK_LOGIN";
+
+pub const TASK_REMINDER_OFFSETS: &str = "TASK_REMINDER_OFFSETS";
+
+pub const AUTH_TOKEN_MODE: &str = "AUTH_TOKEN_MODE";
+pub const JWT_SIGNING_KEYS: &str = "JWT_SIGNING_KEYS";
+pub const JWT_ACCESS_TOKEN_EXPIRE_SECONDS: &str = "JWT_ACCESS_TOKEN_EXPIRE_SECONDS";
+pub const REVOKED_TOKEN_SYNC_SECONDS: u64 = 10;
+
+pub const OIDC_ISSUER_URL: &str = "OIDC_ISSUER_URL";
+pub const OIDC_CLIENT_ID: &str = "OIDC_CLIENT_ID";
+pub const OIDC_CLIENT_SECRET: &str = "OIDC_CLIENT_SECRET";
+pub const OIDC_REDIRECT_URI: &str = "OIDC_REDIRECT_URI";
+
+pub const WEBAUTHN_RP_ID: &str = "WEBAUTHN_RP_ID";
+pub const WEBAUTHN_RP_ORIGIN: &str = "WEBAUTHN_RP_ORIGIN";
+pub const WEBAUTHN_RP_NAME: &str = "WEBAUTHN_RP_NAME";
+
+pub const LOGIN_RATE_LIMIT_WINDOW_SECONDS: &str = "LOGIN_RATE_LIMIT_WINDOW_SECONDS";
+pub const LOGIN_RATE_LIMIT_IP: &str = "LOGIN_RATE_LIMIT_IP";
+pub const LOGIN_RATE_LIMIT_ACCOUNT: &str = "LOGIN_RATE_LIMIT_ACCOUNT";
+pub const LOGIN_LOCKOUT_THRESHOLD: &str = "LOGIN_LOCKOUT_THRESHOLD";
+pub const LOGIN_LOCKOUT_BASE_SECONDS: &str = "LOGIN_LOCKOUT_BASE_SECONDS";
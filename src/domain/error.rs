@@ -12,6 +12,9 @@ pub enum Error {
     ChecksumError,
     GetPoolError,
     MapFromError,
+    OidcError,
+    AlreadyExistsError,
+    DependencyCycleError,
 }
 
 impl Display for Error {
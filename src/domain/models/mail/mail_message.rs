@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailMessage {
+    pub(crate) to: String,
+    pub(crate) subject: String,
+    pub(crate) body: String,
+}
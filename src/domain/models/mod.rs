@@ -1,3 +1,4 @@
+pub mod mail;
 pub mod task;
 pub mod team;
 pub mod user;
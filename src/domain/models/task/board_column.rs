@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::domain::models::task::task_status::TaskStatus;
+
+/// A column of a team's board, tasks in it take its status.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BoardColumn {
+    pub(crate) id: Uuid,
+    pub(crate) team_id: Uuid,
+    pub(crate) name: String,
+    pub(crate) status: TaskStatus,
+    pub(crate) position: i32,
+}
@@ -0,0 +1,56 @@
+use uuid::Uuid;
+
+/// A step of a task's checklist, items are ordered by position within their task.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChecklistItem {
+    pub(crate) id: Uuid,
+    pub(crate) task_id: Uuid,
+    pub(crate) text: String,
+    pub(crate) done: bool,
+    pub(crate) assignee_id: Option<Uuid>,
+    pub(crate) position: i32,
+}
+
+impl ChecklistItem {
+    /// Members who can add tasks edit the checklist, the assignee of an item may also
+    /// tick it off.
+    pub fn can_toggle(&self, user_id: &Uuid, can_add_task: bool) -> bool {
+        can_add_task || self.assignee_id == Some(*user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(assignee_id: Option<Uuid>) -> ChecklistItem {
+        ChecklistItem {
+            id: Uuid::now_v7(),
+            task_id: Uuid::now_v7(),
+            text: String::from("Check the release notes"),
+            done: false,
+            assignee_id,
+            position: 0,
+        }
+    }
+
+    #[test]
+    fn test_can_toggle_with_task_rights() {
+        assert!(item(None).can_toggle(&Uuid::now_v7(), true));
+    }
+
+    #[test]
+    fn test_can_toggle_as_assignee() {
+        let user_id = Uuid::now_v7();
+
+        assert!(item(Some(user_id)).can_toggle(&user_id, false));
+    }
+
+    #[test]
+    fn test_cannot_toggle_without_rights() {
+        let user_id = Uuid::now_v7();
+
+        assert!(!item(None).can_toggle(&user_id, false));
+        assert!(!item(Some(Uuid::now_v7())).can_toggle(&user_id, false));
+    }
+}
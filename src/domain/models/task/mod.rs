@@ -1,3 +1,14 @@
+pub mod board_column;
+pub mod checklist_item;
 pub mod task_assign;
+pub mod task_comment;
+pub mod task_cursor;
+pub mod task_deadline;
+pub mod task_dependency;
+pub mod task_filter;
 pub mod task_information;
+pub mod task_label;
+pub mod task_priority;
+pub mod task_reminder;
+pub mod task_search_result;
 pub mod task_status;
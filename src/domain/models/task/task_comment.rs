@@ -0,0 +1,11 @@
+use uuid::Uuid;
+
+/// A comment on a task, comments of a task are ordered by creation.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TaskComment {
+    pub(crate) id: Uuid,
+    pub(crate) task_id: Uuid,
+    pub(crate) author_id: Uuid,
+    pub(crate) body: String,
+    pub(crate) created_timestamp: i64,
+}
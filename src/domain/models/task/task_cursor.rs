@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::domain::models::task::task_status::TaskStatus;
+
+/// Where a page of a task query ends: the sortable fields of its last task and the id
+/// that breaks ties. The values are copied into the cursor, so editing that task later
+/// doesn't move the page boundary, and the task itself is never looked up again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskCursor {
+    pub(crate) id: Uuid,
+    pub(crate) end_timestamp: i64,
+    pub(crate) created_timestamp: i64,
+    pub(crate) priority: TaskPriority,
+    pub(crate) name: String,
+    pub(crate) status: TaskStatus,
+    pub(crate) position: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EncodedTaskCursor {
+    id: Uuid,
+    end_timestamp: i64,
+    created_timestamp: i64,
+    priority: String,
+    name: String,
+    status: String,
+    position: i32,
+}
+
+impl TaskCursor {
+    /// Encodes the cursor as an opaque, url safe token.
+    pub fn encode(&self) -> String {
+        let encoded = EncodedTaskCursor {
+            id: self.id,
+            end_timestamp: self.end_timestamp,
+            created_timestamp: self.created_timestamp,
+            priority: String::from(self.priority),
+            name: self.name.clone(),
+            status: String::from(self.status.clone()),
+            position: self.position,
+        };
+
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(&encoded).unwrap_or_default())
+    }
+
+    /// Reads a token made by `encode`, `None` when it is malformed.
+    pub fn decode(token: &str) -> Option<TaskCursor> {
+        let bytes = URL_SAFE_NO_PAD.decode(token).ok()?;
+        let encoded = serde_json::from_slice::<EncodedTaskCursor>(&bytes).ok()?;
+
+        Some(TaskCursor {
+            id: encoded.id,
+            end_timestamp: encoded.end_timestamp,
+            created_timestamp: encoded.created_timestamp,
+            priority: TaskPriority::from_str(&encoded.priority).ok()?,
+            name: encoded.name,
+            status: TaskStatus::from_str(&encoded.status).ok()?,
+            position: encoded.position,
+        })
+    }
+}
+
+impl From<&TaskInformation> for TaskCursor {
+    fn from(value: &TaskInformation) -> Self {
+        TaskCursor {
+            id: value.id,
+            end_timestamp: value.end_timestamp,
+            created_timestamp: value.created_timestamp,
+            priority: value.priority,
+            name: value.name.clone(),
+            status: value.status.clone(),
+            position: value.position,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor() -> TaskCursor {
+        TaskCursor {
+            id: Uuid::now_v7(),
+            end_timestamp: 1_800_000_000,
+            created_timestamp: 1_700_000_000,
+            priority: TaskPriority::High,
+            name: String::from("Release \"v2\" / notes"),
+            status: TaskStatus::InProgress,
+            position: 3,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cursor = cursor();
+
+        assert_eq!(TaskCursor::decode(&cursor.encode()), Some(cursor));
+    }
+
+    #[test]
+    fn test_token_is_url_safe() {
+        let token = cursor().encode();
+
+        assert!(token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    }
+
+    #[test]
+    fn test_malformed_tokens_are_rejected() {
+        assert_eq!(TaskCursor::decode(""), None);
+        assert_eq!(TaskCursor::decode("not a cursor"), None);
+        assert_eq!(TaskCursor::decode(&Uuid::now_v7().to_string()), None);
+        assert_eq!(
+            TaskCursor::decode(&URL_SAFE_NO_PAD.encode(b"{\"id\":\"x\"}")),
+            None
+        );
+    }
+}
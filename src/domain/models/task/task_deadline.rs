@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+/// The parts of an open task the reminder worker needs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TaskDeadline {
+    pub(crate) id: Uuid,
+    pub(crate) name: String,
+    pub(crate) creator: Uuid,
+    pub(crate) end_timestamp: i64,
+    /// Smallest offset already reminded of for the current end timestamp.
+    pub(crate) last_reminder_offset: Option<i64>,
+}
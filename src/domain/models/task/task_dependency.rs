@@ -0,0 +1,9 @@
+use uuid::Uuid;
+
+/// `task_id` can't progress until `blocker_id` is finished.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TaskDependency {
+    pub(crate) id: Uuid,
+    pub(crate) task_id: Uuid,
+    pub(crate) blocker_id: Uuid,
+}
@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::domain::models::task::task_status::TaskStatus;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TaskSortField {
+    EndTimestamp,
+    CreatedTimestamp,
+    Priority,
+    Name,
+    Status,
+    /// Position within the board column.
+    Position,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TaskSort {
+    pub(crate) field: TaskSortField,
+    pub(crate) descending: bool,
+}
+
+/// Narrows a task list, empty fields don't filter anything.
+#[derive(Clone, Default)]
+pub struct TaskFilter {
+    /// Only tasks of the teams this user is a member of.
+    pub(crate) member_id: Option<Uuid>,
+    pub(crate) team_id: Option<Uuid>,
+    pub(crate) statuses: Vec<TaskStatus>,
+    pub(crate) assignee_id: Option<Uuid>,
+    pub(crate) creator_id: Option<Uuid>,
+    pub(crate) due_before: Option<i64>,
+    pub(crate) due_after: Option<i64>,
+    /// Matched against the name and the description.
+    pub(crate) text: Option<String>,
+    /// Tasks must carry every one of these labels.
+    pub(crate) label_ids: Vec<Uuid>,
+    /// Tasks must have one of these priorities.
+    pub(crate) priorities: Vec<TaskPriority>,
+    /// Applied in order with the id as the last tie breaker, the end timestamp when empty.
+    pub(crate) sort: Vec<TaskSort>,
+}
@@ -1,4 +1,6 @@
+use crate::domain::models::task::task_priority::TaskPriority;
 use crate::domain::models::task::task_status::TaskStatus;
+use crate::domain::models::team::team_label::TeamLabel;
 use crate::domain::models::user::user_information::UserInformation;
 use uuid::Uuid;
 
@@ -13,4 +15,30 @@ pub struct TaskInformation {
     pub(crate) status: TaskStatus,
     pub(crate) creator: Uuid,
     pub(crate) assigned_users: Vec<UserInformation>,
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) priority: TaskPriority,
+    /// Board column, none puts the task in the first column of its status.
+    pub(crate) column_id: Option<Uuid>,
+    pub(crate) position: i32,
+    pub(crate) recurrence_rule: Option<String>,
+    /// Occurrence number within the series, counting from 0.
+    pub(crate) recurrence_index: i32,
+    /// Whether the next occurrence still has to be created.
+    pub(crate) recurrence_pending: bool,
+    /// End timestamp of the first occurrence, every later one is counted from it.
+    pub(crate) recurrence_anchor: Option<i64>,
+    /// Direct subtasks that aren't canceled.
+    pub(crate) children_total: i64,
+    pub(crate) children_finished: i64,
+    pub(crate) checklist_total: i64,
+    pub(crate) checklist_done: i64,
+    pub(crate) labels: Vec<TeamLabel>,
+}
+
+impl TaskInformation {
+    /// An open task is overdue once its end timestamp has passed.
+    pub fn is_overdue(&self, now: i64) -> bool {
+        matches!(self.status, TaskStatus::InProgress | TaskStatus::Paused)
+            && self.end_timestamp < now
+    }
 }
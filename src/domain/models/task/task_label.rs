@@ -0,0 +1,8 @@
+use uuid::Uuid;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct TaskLabel {
+    pub(crate) id: Uuid,
+    pub(crate) task_id: Uuid,
+    pub(crate) label_id: Uuid,
+}
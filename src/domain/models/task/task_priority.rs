@@ -0,0 +1,49 @@
+use std::str::FromStr;
+
+/// Declared from lowest to highest, so the derived ordering ranks urgency.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TaskPriority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+impl TaskPriority {
+    pub const ALL: [TaskPriority; 5] = [
+        TaskPriority::None,
+        TaskPriority::Low,
+        TaskPriority::Medium,
+        TaskPriority::High,
+        TaskPriority::Urgent,
+    ];
+}
+
+impl From<TaskPriority> for String {
+    fn from(value: TaskPriority) -> String {
+        match value {
+            TaskPriority::None => String::from("none"),
+            TaskPriority::Low => String::from("low"),
+            TaskPriority::Medium => String::from("medium"),
+            TaskPriority::High => String::from("high"),
+            TaskPriority::Urgent => String::from("urgent"),
+        }
+    }
+}
+
+impl FromStr for TaskPriority {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(TaskPriority::None),
+            "low" => Ok(TaskPriority::Low),
+            "medium" => Ok(TaskPriority::Medium),
+            "high" => Ok(TaskPriority::High),
+            "urgent" => Ok(TaskPriority::Urgent),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+/// A sent reminder, remembered so it isn't sent again.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TaskReminder {
+    pub(crate) id: Uuid,
+    pub(crate) task_id: Uuid,
+    /// Seconds before the end timestamp, 0 for the overdue notice.
+    pub(crate) offset_seconds: i64,
+    /// End timestamp the reminder was sent for, a moved deadline gets new reminders.
+    pub(crate) end_timestamp: i64,
+    pub(crate) sent_timestamp: i64,
+}
@@ -0,0 +1,9 @@
+use crate::domain::models::task::task_information::TaskInformation;
+
+#[derive(Clone)]
+pub struct TaskSearchResult {
+    pub(crate) task: TaskInformation,
+    pub(crate) rank: f32,
+    /// Matching fragments with the matched words wrapped in `<mark>`.
+    pub(crate) snippet: String,
+}
@@ -1,4 +1,6 @@
-#[derive(Clone, PartialEq, Eq)]
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TaskStatus {
     InProgress,
     Paused,
@@ -6,6 +8,13 @@ pub enum TaskStatus {
     Canceled,
 }
 
+impl TaskStatus {
+    /// Paused and in progress tasks are still open, finished and canceled ones are closed.
+    pub fn is_open(&self) -> bool {
+        matches!(self, TaskStatus::InProgress | TaskStatus::Paused)
+    }
+}
+
 impl From<TaskStatus> for String {
     fn from(value: TaskStatus) -> String {
         match value {
@@ -16,3 +25,17 @@ impl From<TaskStatus> for String {
         }
     }
 }
+
+impl FromStr for TaskStatus {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "in progress" => Ok(TaskStatus::InProgress),
+            "paused" => Ok(TaskStatus::Paused),
+            "finished" => Ok(TaskStatus::Finished),
+            "canceled" => Ok(TaskStatus::Canceled),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod team_information;
+pub mod team_label;
 pub mod team_leave;
 pub mod team_member;
 pub mod team_role;
@@ -0,0 +1,10 @@
+use uuid::Uuid;
+
+/// A team-scoped label, `color` is a `#rrggbb` hex value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamLabel {
+    pub(crate) id: Uuid,
+    pub(crate) team_id: Uuid,
+    pub(crate) name: String,
+    pub(crate) color: String,
+}
@@ -0,0 +1,14 @@
+use crate::domain::models::user::access_token_scope::AccessTokenScope;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessToken {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) name: String,
+    pub(crate) token_hash: String,
+    pub(crate) scope: AccessTokenScope,
+    pub(crate) created_timestamp: i64,
+    pub(crate) expires_timestamp: Option<i64>,
+    pub(crate) last_used_timestamp: Option<i64>,
+}
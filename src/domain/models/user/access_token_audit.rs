@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+/// A single request authenticated with an access token, attributed to the token's user.
+/// Entries outlive the token, so `access_token_id` may point to a revoked token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessTokenAudit {
+    pub(crate) id: Uuid,
+    pub(crate) access_token_id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) method: String,
+    pub(crate) created_timestamp: i64,
+}
@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessTokenScope {
+    ReadOnly,
+    Tasks,
+    Teams,
+}
+
+/// Methods every scope may call. Reads of the profile, the login history and the
+/// service accounts of a team aren't part of any scope.
+fn is_read(service: &str, method: &str) -> bool {
+    match service {
+        "task.Task" => matches!(
+            method,
+            "GetAll"
+                | "Query"
+                | "Get"
+                | "GetChildren"
+                | "GetBlockers"
+                | "Search"
+                | "GetBoard"
+                | "GetChecklist"
+                | "GetComments"
+        ),
+        "team.Team" => matches!(
+            method,
+            "Get" | "GetUserTeams" | "GetAllCanJoin" | "GetRoles" | "GetLabels"
+        ),
+        _ => false,
+    }
+}
+
+impl AccessTokenScope {
+    /// Checks whether a token with this scope may call the gRPC method at `path`,
+    /// e.g. `/task.Task/Create`. Every scope can call the task and team reads.
+    pub fn allows(&self, path: &str) -> bool {
+        let mut segments = path.trim_start_matches('/').split('/');
+        let service = segments.next().unwrap_or_default();
+        let method = segments.next().unwrap_or_default();
+
+        if is_read(service, method) {
+            return true;
+        }
+
+        match self {
+            AccessTokenScope::ReadOnly => false,
+            AccessTokenScope::Tasks => service == "task.Task",
+            AccessTokenScope::Teams => service == "team.Team",
+        }
+    }
+}
+
+impl From<AccessTokenScope> for String {
+    fn from(value: AccessTokenScope) -> String {
+        match value {
+            AccessTokenScope::ReadOnly => String::from("read_only"),
+            AccessTokenScope::Tasks => String::from("tasks"),
+            AccessTokenScope::Teams => String::from("teams"),
+        }
+    }
+}
+
+impl FromStr for AccessTokenScope {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "read_only" => Ok(AccessTokenScope::ReadOnly),
+            "tasks" => Ok(AccessTokenScope::Tasks),
+            "teams" => Ok(AccessTokenScope::Teams),
+            _ => Err(format!("Unknown access token scope: {}", value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_allows_only_reads() {
+        let scope = AccessTokenScope::ReadOnly;
+
+        assert!(scope.allows("/task.Task/GetAll"));
+        assert!(scope.allows("/task.Task/Query"));
+        assert!(scope.allows("/team.Team/Get"));
+        assert!(!scope.allows("/task.Task/Create"));
+        assert!(!scope.allows("/profile.Profile/ChangePassword"));
+    }
+
+    #[test]
+    fn test_reads_are_keyed_by_service() {
+        for scope in [
+            AccessTokenScope::ReadOnly,
+            AccessTokenScope::Tasks,
+            AccessTokenScope::Teams,
+        ] {
+            assert!(!scope.allows("/profile.Profile/GetProfile"));
+            assert!(!scope.allows("/profile.Profile/GetLoginHistory"));
+            assert!(!scope.allows("/auth.Auth/Get"));
+        }
+
+        assert!(!AccessTokenScope::ReadOnly.allows("/team.Team/GetServiceAccounts"));
+        assert!(!AccessTokenScope::Tasks.allows("/team.Team/GetServiceAccountActivity"));
+    }
+
+    #[test]
+    fn test_service_scopes() {
+        assert!(AccessTokenScope::Tasks.allows("/task.Task/Complete"));
+        assert!(!AccessTokenScope::Tasks.allows("/team.Team/Join"));
+        assert!(AccessTokenScope::Teams.allows("/team.Team/Join"));
+        assert!(!AccessTokenScope::Teams.allows("/task.Task/Create"));
+        assert!(!AccessTokenScope::Teams.allows("/profile.Profile/CreateAccessToken"));
+    }
+}
@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+/// How clients are authenticated after login.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AuthTokenMode {
+    /// Opaque session ids looked up in Redis on every request.
+    #[default]
+    Session,
+    /// Short-lived signed access tokens validated locally, renewed with refresh tokens.
+    Jwt,
+}
+
+impl FromStr for AuthTokenMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "session" => Ok(AuthTokenMode::Session),
+            "jwt" => Ok(AuthTokenMode::Jwt),
+            _ => Err(format!("Unknown auth token mode: {}", value)),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::str::FromStr;
+
+/// Decides which actions are blocked until the user confirms their email.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmailVerificationPolicy {
+    #[default]
+    Disabled,
+    BlockLogin,
+    BlockTeamCreation,
+}
+
+impl EmailVerificationPolicy {
+    pub fn blocks_login(&self) -> bool {
+        matches!(self, EmailVerificationPolicy::BlockLogin)
+    }
+
+    pub fn blocks_team_creation(&self) -> bool {
+        !matches!(self, EmailVerificationPolicy::Disabled)
+    }
+}
+
+impl FromStr for EmailVerificationPolicy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "disabled" => Ok(EmailVerificationPolicy::Disabled),
+            "login" => Ok(EmailVerificationPolicy::BlockLogin),
+            "team_creation" => Ok(EmailVerificationPolicy::BlockTeamCreation),
+            _ => Err(format!("Unknown email verification policy: {}", value)),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use uuid::Uuid;
+
+/// A single login attempt. `user_id` is empty when the account couldn't be resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginAudit {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Option<Uuid>,
+    pub(crate) success: bool,
+    pub(crate) reason: String,
+    pub(crate) ip: Option<String>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) two_factor_used: bool,
+    pub(crate) suspicious: bool,
+    pub(crate) created_timestamp: i64,
+}
@@ -0,0 +1,93 @@
+use std::env;
+use std::str::FromStr;
+
+use crate::domain::constants::{
+    LOGIN_LOCKOUT_BASE_SECONDS, LOGIN_LOCKOUT_THRESHOLD, LOGIN_RATE_LIMIT_ACCOUNT,
+    LOGIN_RATE_LIMIT_IP, LOGIN_RATE_LIMIT_WINDOW_SECONDS, ONE_DAY,
+};
+
+const MAX_LOCKOUT_SECONDS: i64 = 60 * 60;
+
+/// Throttling settings for login attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoginLimits {
+    pub(crate) window_seconds: i64,
+    pub(crate) ip_limit: u32,
+    pub(crate) account_limit: u32,
+    pub(crate) lockout_threshold: u32,
+    pub(crate) lockout_base_seconds: i64,
+}
+
+impl Default for LoginLimits {
+    fn default() -> Self {
+        LoginLimits {
+            window_seconds: 60,
+            ip_limit: 20,
+            account_limit: 5,
+            lockout_threshold: 5,
+            lockout_base_seconds: 30,
+        }
+    }
+}
+
+impl LoginLimits {
+    pub fn from_env() -> Self {
+        fn read<T: FromStr>(key: &str, default: T) -> T {
+            env::var(key)
+                .ok()
+                .map(|value| {
+                    value
+                        .parse()
+                        .unwrap_or_else(|_| panic!("{key} must be a number"))
+                })
+                .unwrap_or(default)
+        }
+
+        let default = LoginLimits::default();
+
+        LoginLimits {
+            window_seconds: read(LOGIN_RATE_LIMIT_WINDOW_SECONDS, default.window_seconds),
+            ip_limit: read(LOGIN_RATE_LIMIT_IP, default.ip_limit),
+            account_limit: read(LOGIN_RATE_LIMIT_ACCOUNT, default.account_limit),
+            lockout_threshold: read(LOGIN_LOCKOUT_THRESHOLD, default.lockout_threshold),
+            lockout_base_seconds: read(LOGIN_LOCKOUT_BASE_SECONDS, default.lockout_base_seconds),
+        }
+    }
+
+    /// How long failed attempts are remembered before the counter resets.
+    pub fn failures_expire_seconds(&self) -> i64 {
+        ONE_DAY
+    }
+
+    /// Lockout duration after `failures` consecutive failures, doubling with every
+    /// failure past the threshold and capped at one hour.
+    pub fn lockout_seconds(&self, failures: u32) -> Option<i64> {
+        if failures < self.lockout_threshold {
+            return None;
+        }
+
+        let exponent = (failures - self.lockout_threshold).min(16);
+
+        Some(
+            self.lockout_base_seconds
+                .saturating_mul(1 << exponent)
+                .min(MAX_LOCKOUT_SECONDS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockout_seconds() {
+        let limits = LoginLimits::default();
+
+        assert_eq!(limits.lockout_seconds(4), None);
+        assert_eq!(limits.lockout_seconds(5), Some(30));
+        assert_eq!(limits.lockout_seconds(6), Some(60));
+        assert_eq!(limits.lockout_seconds(8), Some(240));
+        assert_eq!(limits.lockout_seconds(100), Some(MAX_LOCKOUT_SECONDS));
+    }
+}
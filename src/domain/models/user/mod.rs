@@ -1,2 +1,18 @@
+pub mod access_token;
+pub mod access_token_audit;
+pub mod access_token_scope;
+pub mod auth_token_mode;
+pub mod email_verification_policy;
+pub mod login_audit;
 pub mod login_information;
+pub mod login_limits;
+pub mod oidc_claims;
+pub mod oidc_login_state;
+pub mod passkey_ceremony_state;
+pub mod password_policy;
+pub mod personal_data_export;
+pub mod service_account;
+pub mod two_factor_information;
+pub mod user_identity;
 pub mod user_information;
+pub mod webauthn_credential;
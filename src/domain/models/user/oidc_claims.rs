@@ -0,0 +1,9 @@
+/// Identity of a user as asserted by a validated OpenID Connect ID token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcClaims {
+    pub(crate) issuer: String,
+    pub(crate) subject: String,
+    pub(crate) email: Option<String>,
+    pub(crate) email_verified: bool,
+    pub(crate) preferred_username: Option<String>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// Secrets of an authorization request kept server side until the callback.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OidcLoginState {
+    pub(crate) code_verifier: String,
+    pub(crate) nonce: String,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use webauthn_rs::prelude::{PasskeyAuthentication, PasskeyRegistration};
+
+/// Server side state of a passkey registration, kept until the client answers the challenge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyRegistrationState {
+    pub(crate) user_id: Uuid,
+    pub(crate) registration: PasskeyRegistration,
+}
+
+/// Server side state of a passkey login, kept until the client answers the challenge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyAuthenticationState {
+    pub(crate) user_id: Uuid,
+    pub(crate) authentication: PasskeyAuthentication,
+}
@@ -0,0 +1,218 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::domain::constants::{
+    PASSWORD_DISALLOW_PERSONAL_INFO, PASSWORD_MIN_ENTROPY_BITS, PASSWORD_MIN_LENGTH,
+};
+
+/// Requirements a new password has to satisfy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PasswordPolicy {
+    pub(crate) min_length: usize,
+    pub(crate) min_entropy_bits: f64,
+    pub(crate) disallow_personal_info: bool,
+}
+
+/// A single reason why a password was rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasswordViolation {
+    TooShort {
+        min_length: usize,
+    },
+    TooWeak {
+        entropy_bits: f64,
+        min_entropy_bits: f64,
+    },
+    ContainsEmail,
+    ContainsUserName,
+    Breached,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            min_length: 8,
+            min_entropy_bits: 40.0,
+            disallow_personal_info: true,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    pub fn from_env() -> Self {
+        fn read<T: FromStr>(key: &str, default: T) -> T {
+            env::var(key)
+                .ok()
+                .map(|value| {
+                    value
+                        .parse()
+                        .unwrap_or_else(|_| panic!("{key} has an invalid value"))
+                })
+                .unwrap_or(default)
+        }
+
+        let default = PasswordPolicy::default();
+
+        PasswordPolicy {
+            min_length: read(PASSWORD_MIN_LENGTH, default.min_length),
+            min_entropy_bits: read(PASSWORD_MIN_ENTROPY_BITS, default.min_entropy_bits),
+            disallow_personal_info: read(
+                PASSWORD_DISALLOW_PERSONAL_INFO,
+                default.disallow_personal_info,
+            ),
+        }
+    }
+
+    /// Checks the password against the policy, `email` and `user_name` are the
+    /// account details it must not contain.
+    pub fn check(&self, password: &str, email: &str, user_name: &str) -> Vec<PasswordViolation> {
+        let mut violations = Vec::new();
+
+        if password.chars().count() < self.min_length {
+            violations.push(PasswordViolation::TooShort {
+                min_length: self.min_length,
+            });
+        }
+
+        let entropy_bits = estimate_entropy_bits(password);
+
+        if entropy_bits < self.min_entropy_bits {
+            violations.push(PasswordViolation::TooWeak {
+                entropy_bits,
+                min_entropy_bits: self.min_entropy_bits,
+            });
+        }
+
+        if self.disallow_personal_info {
+            let password = password.to_lowercase();
+            let email_name = email.split('@').next().unwrap_or_default().to_lowercase();
+
+            if email_name.len() >= 3 && password.contains(&email_name) {
+                violations.push(PasswordViolation::ContainsEmail);
+            }
+
+            if user_name.len() >= 3 && password.contains(&user_name.to_lowercase()) {
+                violations.push(PasswordViolation::ContainsUserName);
+            }
+        }
+
+        violations
+    }
+}
+
+/// Rough brute-force entropy: the size of the used character classes raised to the
+/// number of characters, where immediately repeated characters don't count.
+pub fn estimate_entropy_bits(password: &str) -> f64 {
+    let (mut lower, mut upper, mut digit, mut symbol, mut other) =
+        (false, false, false, false, false);
+
+    for c in password.chars() {
+        match c {
+            'a'..='z' => lower = true,
+            'A'..='Z' => upper = true,
+            '0'..='9' => digit = true,
+            c if c.is_ascii() => symbol = true,
+            _ => other = true,
+        }
+    }
+
+    let pool = [
+        (lower, 26),
+        (upper, 26),
+        (digit, 10),
+        (symbol, 33),
+        (other, 100),
+    ]
+    .iter()
+    .filter(|(used, _)| *used)
+    .map(|(_, size)| size)
+    .sum::<u32>();
+
+    if pool == 0 {
+        return 0.0;
+    }
+
+    let mut chars = password.chars().collect::<Vec<_>>();
+    chars.dedup();
+
+    chars.len() as f64 * (pool as f64).log2()
+}
+
+impl PasswordViolation {
+    /// Stable identifier of the violation for clients.
+    pub fn code(&self) -> &'static str {
+        match self {
+            PasswordViolation::TooShort { .. } => "too_short",
+            PasswordViolation::TooWeak { .. } => "too_weak",
+            PasswordViolation::ContainsEmail => "contains_email",
+            PasswordViolation::ContainsUserName => "contains_user_name",
+            PasswordViolation::Breached => "breached",
+        }
+    }
+}
+
+impl Display for PasswordViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordViolation::TooShort { min_length } => {
+                write!(
+                    f,
+                    "Password must be at least {} characters long",
+                    min_length
+                )
+            }
+            PasswordViolation::TooWeak {
+                entropy_bits,
+                min_entropy_bits,
+            } => write!(
+                f,
+                "Password is too easy to guess ({:.0} of {:.0} bits)",
+                entropy_bits, min_entropy_bits
+            ),
+            PasswordViolation::ContainsEmail => write!(f, "Password must not contain the email"),
+            PasswordViolation::ContainsUserName => {
+                write!(f, "Password must not contain the user name")
+            }
+            PasswordViolation::Breached => {
+                write!(f, "Password appears in a known data breach")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_entropy_bits() {
+        assert_eq!(estimate_entropy_bits(""), 0.0);
+        assert_eq!(estimate_entropy_bits("aaaaaaaa"), 26f64.log2());
+        assert!(estimate_entropy_bits("correct horse battery staple") > 100.0);
+    }
+
+    #[test]
+    fn test_check() {
+        let policy = PasswordPolicy::default();
+
+        assert!(policy
+            .check("Tr0ub4dor&3-horse", "john@example.com", "johnny")
+            .is_empty());
+
+        assert_eq!(
+            policy.check("abc", "john@example.com", "johnny"),
+            vec![
+                PasswordViolation::TooShort { min_length: 8 },
+                PasswordViolation::TooWeak {
+                    entropy_bits: 3.0 * 26f64.log2(),
+                    min_entropy_bits: 40.0
+                }
+            ]
+        );
+
+        let violations = policy.check("xX_Johnny_1987!", "johnny@example.com", "johnny");
+        assert!(violations.contains(&PasswordViolation::ContainsEmail));
+        assert!(violations.contains(&PasswordViolation::ContainsUserName));
+    }
+}
@@ -0,0 +1,102 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::models::task::task_comment::TaskComment;
+use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::team::team_information::TeamInformation;
+use crate::domain::models::team::team_role::TeamRole;
+use crate::domain::models::user::user_information::UserInformation;
+
+/// Everything stored about a user, written as `data.json` into the export archive.
+#[derive(Debug, Serialize)]
+pub struct PersonalDataExport {
+    pub(crate) profile: ExportedProfile,
+    pub(crate) memberships: Vec<ExportedMembership>,
+    pub(crate) tasks_created: Vec<ExportedTask>,
+    pub(crate) tasks_assigned: Vec<ExportedTask>,
+    pub(crate) comments: Vec<ExportedComment>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedProfile {
+    pub(crate) id: Uuid,
+    pub(crate) email: String,
+    pub(crate) email_verified: bool,
+    pub(crate) user_name: String,
+    pub(crate) profile_image: Option<String>,
+    pub(crate) deletion_requested_timestamp: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedMembership {
+    pub(crate) team_id: Uuid,
+    pub(crate) team_name: String,
+    pub(crate) role: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedTask {
+    pub(crate) id: Uuid,
+    pub(crate) team_id: Uuid,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) status: String,
+    pub(crate) created_timestamp: i64,
+    pub(crate) end_timestamp: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedComment {
+    pub(crate) id: Uuid,
+    pub(crate) task_id: Uuid,
+    pub(crate) body: String,
+    pub(crate) created_timestamp: i64,
+}
+
+impl From<&UserInformation> for ExportedProfile {
+    fn from(value: &UserInformation) -> Self {
+        ExportedProfile {
+            id: value.id,
+            email: value.email.clone(),
+            email_verified: value.email_verified,
+            user_name: value.user_name.clone(),
+            profile_image: value.profile_image.clone(),
+            deletion_requested_timestamp: value.deletion_requested_timestamp,
+        }
+    }
+}
+
+impl ExportedMembership {
+    pub fn new(team: &TeamInformation, role: Option<&TeamRole>) -> Self {
+        ExportedMembership {
+            team_id: team.id,
+            team_name: team.name.clone(),
+            role: role.map(|role| role.name.clone()),
+        }
+    }
+}
+
+impl From<&TaskInformation> for ExportedTask {
+    fn from(value: &TaskInformation) -> Self {
+        ExportedTask {
+            id: value.id,
+            team_id: value.team_id,
+            name: value.name.clone(),
+            description: value.description.clone(),
+            status: String::from(value.status.clone()),
+            created_timestamp: value.created_timestamp,
+            end_timestamp: value.end_timestamp,
+        }
+    }
+}
+
+impl From<&TaskComment> for ExportedComment {
+    fn from(value: &TaskComment) -> Self {
+        ExportedComment {
+            id: value.id,
+            task_id: value.task_id,
+            body: value.body.clone(),
+            created_timestamp: value.created_timestamp,
+        }
+    }
+}
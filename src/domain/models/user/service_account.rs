@@ -0,0 +1,105 @@
+/// Checks whether a service account may call the gRPC method at `path`, e.g.
+/// `/task.Task/Create`. Service accounts only act inside the team that owns them, so
+/// they get the task methods and the team methods needed to work with tasks, nothing
+/// that changes memberships, roles or credentials.
+pub fn service_account_allows(path: &str) -> bool {
+    let mut segments = path.trim_start_matches('/').split('/');
+    let service = segments.next().unwrap_or_default();
+    let method = segments.next().unwrap_or_default();
+
+    match service {
+        "task.Task" => matches!(
+            method,
+            "Create"
+                | "GetAll"
+                | "Query"
+                | "Get"
+                | "Assign"
+                | "Complete"
+                | "CreateSubtask"
+                | "MoveTask"
+                | "GetChildren"
+                | "AddBlocker"
+                | "RemoveBlocker"
+                | "GetBlockers"
+                | "AddTaskLabel"
+                | "RemoveTaskLabel"
+                | "SetPriority"
+                | "Search"
+                | "GetBoard"
+                | "CreateBoardColumn"
+                | "UpdateBoardColumn"
+                | "DeleteBoardColumn"
+                | "MoveToColumn"
+                | "SetRecurrence"
+                | "GetChecklist"
+                | "AddChecklistItem"
+                | "ToggleChecklistItem"
+                | "MoveChecklistItem"
+                | "DeleteChecklistItem"
+                | "GetComments"
+                | "AddComment"
+                | "DeleteComment"
+        ),
+        "team.Team" => matches!(
+            method,
+            "Get"
+                | "GetUserTeams"
+                | "GetRoles"
+                | "GetLabels"
+                | "CreateLabel"
+                | "UpdateLabel"
+                | "DeleteLabel"
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_is_forbidden() {
+        assert!(!service_account_allows("/profile.Profile/Get"));
+        assert!(!service_account_allows(
+            "/profile.Profile/CreateAccessToken"
+        ));
+        assert!(!service_account_allows("/profile.Profile/ChangePassword"));
+    }
+
+    #[test]
+    fn test_team_membership_changes_are_forbidden() {
+        assert!(!service_account_allows("/team.Team/Create"));
+        assert!(!service_account_allows("/team.Team/Join"));
+        assert!(!service_account_allows("/team.Team/Leave"));
+        assert!(!service_account_allows("/team.Team/ChangeRole"));
+        assert!(service_account_allows("/team.Team/Get"));
+        assert!(service_account_allows("/team.Team/GetRoles"));
+    }
+
+    #[test]
+    fn test_credential_management_is_forbidden() {
+        assert!(!service_account_allows("/team.Team/CreateServiceAccount"));
+        assert!(!service_account_allows(
+            "/team.Team/CreateServiceAccountKey"
+        ));
+        assert!(!service_account_allows(
+            "/team.Team/RevokeServiceAccountKey"
+        ));
+    }
+
+    #[test]
+    fn test_task_methods_are_allowed() {
+        assert!(service_account_allows("/task.Task/Create"));
+        assert!(service_account_allows("/task.Task/Complete"));
+        assert!(service_account_allows("/task.Task/AddComment"));
+    }
+
+    #[test]
+    fn test_unknown_methods_are_forbidden() {
+        assert!(!service_account_allows("/task.Task/Unknown"));
+        assert!(!service_account_allows("/team.Team/"));
+        assert!(!service_account_allows("/other.Other/Get"));
+    }
+}
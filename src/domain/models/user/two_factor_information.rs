@@ -0,0 +1,10 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwoFactorInformation {
+    pub(crate) user_id: Uuid,
+    pub(crate) secret: String,
+    pub(crate) confirmed: bool,
+    /// Time step of the last accepted code, older and equal steps are rejected.
+    pub(crate) last_used_step: Option<i64>,
+}
@@ -0,0 +1,10 @@
+use uuid::Uuid;
+
+/// Link between a local user and an account at an external identity provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserIdentity {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) issuer: String,
+    pub(crate) subject: String,
+}
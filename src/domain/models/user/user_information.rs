@@ -7,4 +7,8 @@ pub struct UserInformation {
     pub(crate) user_name: String,
     pub(crate) profile_image: Option<String>,
     pub(crate) password: String,
+    pub(crate) email_verified: bool,
+    pub(crate) deletion_requested_timestamp: Option<i64>,
+    /// The owning team of a service account, `None` for people.
+    pub(crate) service_account_team_id: Option<Uuid>,
 }
@@ -0,0 +1,15 @@
+use uuid::Uuid;
+
+/// A registered passkey. `passkey` is the serialized public key credential and
+/// `sign_count` the last signature counter reported by the authenticator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebauthnCredential {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) credential_id: String,
+    pub(crate) name: String,
+    pub(crate) passkey: String,
+    pub(crate) sign_count: i64,
+    pub(crate) created_timestamp: i64,
+    pub(crate) last_used_timestamp: Option<i64>,
+}
@@ -0,0 +1,14 @@
+use std::fmt::Debug;
+
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::user::access_token::AccessToken;
+
+pub trait AccessTokenRepository: Send + Sync + Debug {
+    fn create(&self, new_access_token: &AccessToken) -> Result<AccessToken>;
+    fn get_all_for_user(&self, user_id: &Uuid) -> Result<Vec<AccessToken>>;
+    /// Finds a token that isn't expired by its hash and records it as used.
+    fn find_active(&self, token_hash: &str) -> Result<AccessToken>;
+    fn remove(&self, token_id: &Uuid, user_id: &Uuid) -> Result<()>;
+}
@@ -0,0 +1,17 @@
+use std::fmt::Debug;
+
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::user::access_token_audit::AccessTokenAudit;
+
+pub trait AccessTokenAuditRepository: Send + Sync + Debug {
+    fn create(&self, new_access_token_audit: &AccessTokenAudit) -> Result<AccessTokenAudit>;
+    /// Returns up to `limit` entries of the user, newest first, older than `before_id`.
+    fn get_page_for_user(
+        &self,
+        user_id: &Uuid,
+        before_id: Option<&Uuid>,
+        limit: i64,
+    ) -> Result<Vec<AccessTokenAudit>>;
+}
@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::task::board_column::BoardColumn;
+
+pub trait BoardRepository: Send + Sync {
+    fn get_column(&self, column_id: &Uuid) -> Result<BoardColumn>;
+    /// Returns the columns of a team ordered by position.
+    fn get_columns(&self, team_id: &Uuid) -> Result<Vec<BoardColumn>>;
+    fn create_column(&self, new_column: &BoardColumn) -> Result<BoardColumn>;
+    /// Saves the name, status and position of several columns at once.
+    fn update_columns(&self, columns: &[BoardColumn]) -> Result<()>;
+    /// Counts the tasks placed in a column.
+    fn count_tasks(&self, column_id: &Uuid) -> Result<i64>;
+    /// Deletes a column, its tasks go back to the first column of their status.
+    fn delete_column(&self, column_id: &Uuid) -> Result<()>;
+}
@@ -0,0 +1,16 @@
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::task::checklist_item::ChecklistItem;
+
+pub trait ChecklistRepository: Send + Sync {
+    fn get_item(&self, item_id: &Uuid) -> Result<ChecklistItem>;
+    /// Returns the checklist of a task ordered by position.
+    fn get_items(&self, task_id: &Uuid) -> Result<Vec<ChecklistItem>>;
+    /// Appends an item to the end of its task's checklist, ignoring its position.
+    fn add_item(&self, new_item: &ChecklistItem) -> Result<ChecklistItem>;
+    fn set_done(&self, item_id: &Uuid, done: bool) -> Result<ChecklistItem>;
+    /// Moves an item to `position` and renumbers the rest of the checklist.
+    fn move_item(&self, item: &ChecklistItem, position: i32) -> Result<()>;
+    fn delete_item(&self, item: &ChecklistItem) -> Result<()>;
+}
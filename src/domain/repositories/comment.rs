@@ -0,0 +1,14 @@
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::task::task_comment::TaskComment;
+
+pub trait CommentRepository: Send + Sync {
+    fn get(&self, comment_id: &Uuid) -> Result<TaskComment>;
+    /// Returns the comments of a task, oldest first.
+    fn get_for_task(&self, task_id: &Uuid) -> Result<Vec<TaskComment>>;
+    /// Returns every comment written by the user, oldest first.
+    fn get_all_by_author(&self, author_id: &Uuid) -> Result<Vec<TaskComment>>;
+    fn create(&self, new_comment: &TaskComment) -> Result<TaskComment>;
+    fn delete(&self, comment_id: &Uuid) -> Result<()>;
+}
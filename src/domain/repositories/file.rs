@@ -7,4 +7,5 @@ pub trait FileRepository: Send + Sync {
     async fn upload(&self, bucket: &str, key: &str, data: &[u8]) -> Result<()>;
     async fn download(&self, bucket: &str, key: &str) -> Result<Vec<u8>>;
     async fn delete(&self, bucket: &str, key: &str) -> Result<()>;
+    async fn list(&self, bucket: &str, prefix: &str) -> Result<Vec<String>>;
 }
@@ -0,0 +1,16 @@
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::task::task_label::TaskLabel;
+use crate::domain::models::team::team_label::TeamLabel;
+
+pub trait LabelRepository: Send + Sync {
+    fn get(&self, label_id: &Uuid) -> Result<TeamLabel>;
+    fn get_all_for_team(&self, team_id: &Uuid) -> Result<Vec<TeamLabel>>;
+    fn create(&self, new_label: &TeamLabel) -> Result<TeamLabel>;
+    fn update(&self, label: &TeamLabel) -> Result<TeamLabel>;
+    /// Deletes a label and detaches it from every task.
+    fn delete(&self, label_id: &Uuid) -> Result<()>;
+    fn attach(&self, new_task_label: &TaskLabel) -> Result<()>;
+    fn detach(&self, task_id: &Uuid, label_id: &Uuid) -> Result<()>;
+}
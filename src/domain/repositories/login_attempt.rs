@@ -0,0 +1,16 @@
+use std::fmt::Debug;
+
+use crate::domain::error::Result;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RedisLoginAttemptRepository: Send + Sync + Debug {
+    /// Records an attempt in the sliding window of `key` and returns `false`
+    /// if `limit` attempts were already made within the last `window_seconds`.
+    fn check_rate_limit(&self, key: &str, limit: u32, window_seconds: i64) -> Result<bool>;
+    /// Increments the failed attempts of an account and returns the new count.
+    fn register_failure(&self, account: &str, expire_seconds: i64) -> Result<u32>;
+    fn reset_failures(&self, account: &str) -> Result<()>;
+    fn lock(&self, account: &str, lockout_seconds: i64) -> Result<()>;
+    /// Returns the remaining lockout of an account in seconds, if it's locked.
+    fn lockout_remaining(&self, account: &str) -> Result<Option<i64>>;
+}
@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::user::login_audit::LoginAudit;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait LoginAuditRepository: Send + Sync {
+    fn create(&self, new_login_audit: &LoginAudit) -> Result<LoginAudit>;
+    /// Returns up to `limit` entries of the user, newest first, older than `before_id`.
+    fn get_page_for_user(
+        &self,
+        user_id: &Uuid,
+        before_id: Option<&Uuid>,
+        limit: i64,
+    ) -> Result<Vec<LoginAudit>>;
+    fn set_suspicious(
+        &self,
+        login_audit_id: &Uuid,
+        user_id: &Uuid,
+        suspicious: bool,
+    ) -> Result<LoginAudit>;
+}
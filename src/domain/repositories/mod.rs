@@ -1,6 +1,21 @@
+pub mod access_token;
+pub mod access_token_audit;
+pub mod board;
+pub mod checklist;
+pub mod comment;
 pub mod file;
+pub mod label;
+pub mod login_attempt;
+pub mod login_audit;
+pub mod refresh_token;
+pub mod reminder;
+pub mod revoked_token;
 pub mod role;
 pub mod session;
 pub mod task;
 pub mod team;
+pub mod token;
+pub mod two_factor;
 pub mod user;
+pub mod user_identity;
+pub mod webauthn_credential;
@@ -0,0 +1,25 @@
+use std::fmt::Debug;
+
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+
+/// Long-lived refresh tokens. Every login starts a token family, each refresh
+/// replaces the presented token with a new one of the same family.
+#[cfg_attr(test, mockall::automock)]
+pub trait RedisRefreshTokenRepository: Send + Sync + Debug {
+    /// Starts a new token family for the user and returns its first token.
+    fn create(&self, user_id: &Uuid) -> Result<String>;
+
+    /// Exchanges a refresh token for its successor, returning the owner and the new token.
+    ///
+    /// Presenting a token that was already rotated revokes the whole family, since
+    /// it means the token has leaked.
+    fn rotate(&self, refresh_token: &str) -> Result<(Uuid, String)>;
+
+    /// Revokes the family of the token.
+    fn revoke(&self, refresh_token: &str) -> Result<()>;
+
+    /// Revokes every token family of the user.
+    fn revoke_user(&self, user_id: &Uuid) -> Result<()>;
+}
@@ -0,0 +1,7 @@
+use crate::domain::error::Result;
+use crate::domain::models::task::task_reminder::TaskReminder;
+
+pub trait ReminderRepository: Send + Sync {
+    /// Records a reminder, returns false if the same one was already recorded.
+    fn claim(&self, reminder: &TaskReminder) -> Result<bool>;
+}
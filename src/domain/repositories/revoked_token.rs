@@ -0,0 +1,14 @@
+use std::fmt::Debug;
+
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+
+/// Deny-list of signed access tokens revoked before they expire, keyed by `jti`.
+#[cfg_attr(test, mockall::automock)]
+pub trait RedisRevokedTokenRepository: Send + Sync + Debug {
+    /// Revokes the token until `expires_timestamp`, after which it is rejected anyway.
+    fn revoke(&self, jti: &Uuid, expires_timestamp: i64) -> Result<()>;
+    /// Returns the tokens still revoked at `now`, dropping the expired ones.
+    fn get_revoked(&self, now: i64) -> Result<Vec<Uuid>>;
+}
@@ -4,9 +4,11 @@ use uuid::Uuid;
 
 use crate::domain::error::Result;
 
+#[cfg_attr(test, mockall::automock)]
 pub trait RedisSessionRepository: Send + Sync + Debug {
     fn create(&self, user_id: &Uuid) -> Result<String>;
-    fn validate(&self, session_id: &str) -> Result<String>;
-    fn session_expand(&self, session_id: &str) -> Result<()>;
+    fn validate(&self, session_id: &str) -> Result<Uuid>;
+    fn session_expand(&self, session_id: &str) -> Result<Uuid>;
     fn remove_session(&self, session_id: &str) -> Result<()>;
+    fn remove_user_sessions(&self, user_id: &Uuid, keep_session_id: Option<&str>) -> Result<()>;
 }
@@ -1,15 +1,80 @@
 use uuid::Uuid;
 
 use crate::domain::error::Result;
+use crate::domain::models::task::board_column::BoardColumn;
 use crate::domain::models::task::task_assign::TaskAssign;
+use crate::domain::models::task::task_cursor::TaskCursor;
+use crate::domain::models::task::task_deadline::TaskDeadline;
+use crate::domain::models::task::task_dependency::TaskDependency;
+use crate::domain::models::task::task_filter::TaskFilter;
 use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::domain::models::task::task_search_result::TaskSearchResult;
+use crate::domain::models::user::user_information::UserInformation;
 
 pub trait TaskRepository: Send + Sync {
     fn get(&self, task_id: &Uuid) -> Result<TaskInformation>;
-    fn get_all(&self) -> Result<Vec<TaskInformation>>;
-    fn get_all_for_team(&self, team_id: &Uuid) -> Result<Vec<TaskInformation>>;
-    fn get_all_for_user(&self, user_id: &Uuid) -> Result<Vec<TaskInformation>>;
+    /// Returns up to `limit` tasks matching `filter`, ordered after `after`.
+    fn query(
+        &self,
+        filter: &TaskFilter,
+        after: Option<&TaskCursor>,
+        limit: i64,
+    ) -> Result<Vec<TaskInformation>>;
+    /// Full-text search over the name and description of the tasks in the teams of
+    /// `member_id`, best matches first.
+    fn search(
+        &self,
+        member_id: &Uuid,
+        team_id: Option<&Uuid>,
+        text: &str,
+        limit: i64,
+    ) -> Result<Vec<TaskSearchResult>>;
+    fn get_all_created_by(&self, user_id: &Uuid) -> Result<Vec<TaskInformation>>;
     fn create(&self, new_task_information: &TaskInformation) -> Result<TaskInformation>;
-    fn assign(&self, new_task_assign: &TaskAssign) -> Result<TaskAssign>;
+    /// Assigns a user, `start` also moves the task to in progress.
+    fn assign(&self, new_task_assign: &TaskAssign, start: bool) -> Result<TaskAssign>;
     fn complete(&self, task_id: &Uuid) -> Result<TaskInformation>;
+    fn get_children(&self, parent_id: &Uuid) -> Result<Vec<TaskInformation>>;
+    /// Moves a task below `parent_id`, or to the top level. Fails with
+    /// `DependencyCycleError` when the parent is the task itself or one of its subtasks.
+    fn set_parent(
+        &self,
+        task_id: &Uuid,
+        parent_id: Option<&Uuid>,
+        team_id: &Uuid,
+    ) -> Result<TaskInformation>;
+    fn set_priority(&self, task_id: &Uuid, priority: TaskPriority) -> Result<TaskInformation>;
+    /// Puts a task at `position` of a board column, shifting the tasks after it, and
+    /// gives it the column's status.
+    fn move_to_column(
+        &self,
+        task_id: &Uuid,
+        column: &BoardColumn,
+        position: i32,
+    ) -> Result<TaskInformation>;
+    /// Sets or clears the recurrence rule, a set rule makes the task create its next
+    /// occurrence.
+    fn set_recurrence(&self, task_id: &Uuid, rule: Option<&str>) -> Result<TaskInformation>;
+    /// Returns recurring tasks that are finished or past their end timestamp and haven't
+    /// created their next occurrence yet.
+    fn get_recurrences_due(&self, now: i64) -> Result<Vec<TaskInformation>>;
+    /// Creates `next` with the assignees of the previous occurrence, none ends the series.
+    /// Returns false if the previous occurrence was already handled.
+    fn create_next_occurrence(
+        &self,
+        previous_id: &Uuid,
+        next: Option<&TaskInformation>,
+    ) -> Result<bool>;
+    /// Returns the deadlines of paused and in progress tasks ending after `from` and no
+    /// later than `until`.
+    fn get_deadlines_between(&self, from: i64, until: i64) -> Result<Vec<TaskDeadline>>;
+    fn get_assignees(&self, task_id: &Uuid) -> Result<Vec<UserInformation>>;
+    /// Counts paused and in progress tasks, priorities without open tasks are left out.
+    fn count_open_by_priority(&self) -> Result<Vec<(TaskPriority, i64)>>;
+    /// Adds a dependency between two tasks of `team_id`, failing with
+    /// `DependencyCycleError` if the blocker already waits on the task.
+    fn add_dependency(&self, new_dependency: &TaskDependency, team_id: &Uuid) -> Result<()>;
+    fn remove_dependency(&self, task_id: &Uuid, blocker_id: &Uuid) -> Result<()>;
+    fn get_blockers(&self, task_id: &Uuid) -> Result<Vec<TaskInformation>>;
 }
@@ -0,0 +1,17 @@
+use std::fmt::Debug;
+
+use crate::domain::error::Result;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RedisTokenRepository: Send + Sync + Debug {
+    /// Issues a single-use token for `purpose` holding `value` for `expire_seconds`.
+    ///
+    /// Only a hash of the token is stored, the plain token is returned to the caller.
+    fn create(&self, purpose: &str, value: &str, expire_seconds: i64) -> Result<String>;
+
+    /// Returns the value stored for the token without using it up.
+    fn peek(&self, purpose: &str, token: &str) -> Result<String>;
+
+    /// Returns the value stored for the token and removes it, so it can't be used twice.
+    fn consume(&self, purpose: &str, token: &str) -> Result<String>;
+}
@@ -0,0 +1,20 @@
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::user::two_factor_information::TwoFactorInformation;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait TwoFactorRepository: Send + Sync {
+    fn get(&self, user_id: &Uuid) -> Result<TwoFactorInformation>;
+    /// Stores a new secret, replacing a previous enrollment of the user.
+    fn create(&self, two_factor_information: &TwoFactorInformation)
+        -> Result<TwoFactorInformation>;
+    /// Marks the enrollment as confirmed and replaces the recovery codes with hashes of `recovery_codes`.
+    fn confirm(&self, user_id: &Uuid, recovery_codes: &[String]) -> Result<()>;
+    /// Records `step` as the last accepted code, returns `false` if a code of the same
+    /// or a later step was already accepted.
+    fn use_step(&self, user_id: &Uuid, step: i64) -> Result<bool>;
+    /// Checks a recovery code and removes it on success, so every code works only once.
+    fn use_recovery_code(&self, user_id: &Uuid, recovery_code: &str) -> Result<()>;
+    fn remove(&self, user_id: &Uuid) -> Result<()>;
+}
@@ -1,9 +1,12 @@
 use crate::domain::error::Result;
+use crate::domain::models::team::team_member::TeamMember;
 use crate::domain::models::user::login_information::LoginInformation;
 use crate::domain::models::user::user_information::UserInformation;
+use std::fmt::Debug;
 use uuid::Uuid;
 
-pub trait UserRepository: Send + Sync {
+#[cfg_attr(test, mockall::automock)]
+pub trait UserRepository: Send + Sync + Debug {
     /// Creates a new user record in the repository with the provided `UserInformation`.
     ///
     /// # Parameters
@@ -65,4 +68,157 @@ pub trait UserRepository: Send + Sync {
     /// # Errors
     /// This function will return an error if the login information is incorrect (e.g., wrong username or password) or if a database error occurs during authentication.
     fn login(&self, login_information: &LoginInformation) -> Result<UserInformation>;
+
+    /// Retrieves a user's information by their email.
+    ///
+    /// # Parameters
+    /// - `email`: A reference to the `str` of the email to retrieve.
+    ///
+    /// # Returns
+    /// A `Result` wrapping `UserInformation` if found, or an error if no user is found with the given email or if a database error occurs.
+    ///
+    /// # Errors
+    /// This function will return an error if the email does not exist or if a database error occurs.
+    fn get_by_email(&self, email: &str) -> Result<UserInformation>;
+
+    /// Checks the provided password against the stored hash of a user.
+    ///
+    /// # Parameters
+    /// - `user_id`: A reference to the `Uuid` of the user whose password is checked.
+    /// - `password`: A string slice with the plain password to verify.
+    ///
+    /// # Returns
+    /// An empty `Result` if the password matches.
+    ///
+    /// # Errors
+    /// This function will return an error if the user ID does not exist, the password doesn't match or if a database error occurs.
+    fn verify_password(&self, user_id: &Uuid, password: &str) -> Result<()>;
+
+    /// Hashes and stores a new password for a user.
+    ///
+    /// # Parameters
+    /// - `user_id`: A reference to the `Uuid` of the user whose password is being set.
+    /// - `password`: A string slice with the new plain password.
+    ///
+    /// # Returns
+    /// A `Result` wrapping `UserInformation` of the user with the updated password hash, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return an error if the user ID does not exist, hashing fails or if the database operation fails.
+    fn set_password(&self, user_id: &Uuid, password: &str) -> Result<UserInformation>;
+
+    /// Marks the email of a user as verified.
+    ///
+    /// # Parameters
+    /// - `user_id`: A reference to the `Uuid` of the user who confirmed their email.
+    ///
+    /// # Returns
+    /// A `Result` wrapping `UserInformation` of the verified user, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return an error if the user ID does not exist or if the database operation fails.
+    fn set_email_verified(&self, user_id: &Uuid) -> Result<UserInformation>;
+
+    /// Sets or clears the time at which the user asked for their account to be deleted.
+    ///
+    /// # Parameters
+    /// - `user_id`: A reference to the `Uuid` of the user.
+    /// - `timestamp`: The request time in unix seconds, or `None` to cancel the deletion.
+    ///
+    /// # Returns
+    /// A `Result` wrapping the updated `UserInformation`, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return an error if the user ID does not exist or if the database operation fails.
+    fn set_deletion_requested(
+        &self,
+        user_id: &Uuid,
+        timestamp: Option<i64>,
+    ) -> Result<UserInformation>;
+
+    /// Retrieves users whose deletion was requested before the given time.
+    ///
+    /// # Parameters
+    /// - `requested_before`: Unix seconds, requests made at or before this time are returned.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `Vec` of `UserInformation` due for deletion, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return an error if the database operation fails.
+    fn get_all_deletion_due(&self, requested_before: i64) -> Result<Vec<UserInformation>>;
+
+    /// Permanently deletes a user. Tasks and teams they created are handed over to the
+    /// `DELETED_USER_ID` placeholder, memberships, assignments and credentials are removed.
+    ///
+    /// # Parameters
+    /// - `user_id`: A reference to the `Uuid` of the user to delete.
+    ///
+    /// # Returns
+    /// A `Result` which is `Ok` if the user was deleted, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return an error if the database transaction fails, in which case nothing is deleted.
+    fn delete(&self, user_id: &Uuid) -> Result<()>;
+
+    /// Replaces the email of a user with an already confirmed address.
+    ///
+    /// # Parameters
+    /// - `user_id`: A reference to the `Uuid` of the user.
+    /// - `email`: A string slice with the new, verified email.
+    ///
+    /// # Returns
+    /// A `Result` wrapping the updated `UserInformation`, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return `AlreadyExistsError` if the email belongs to another user, or an error if the database operation fails.
+    fn set_email(&self, user_id: &Uuid, email: &str) -> Result<UserInformation>;
+
+    /// Renames a user together with the url of their profile image.
+    ///
+    /// # Parameters
+    /// - `user_id`: A reference to the `Uuid` of the user.
+    /// - `user_name`: A string slice with the new user name.
+    /// - `profile_image`: The profile image url pointing to the new user name, if the user has one.
+    ///
+    /// # Returns
+    /// A `Result` wrapping the updated `UserInformation`, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return `AlreadyExistsError` if the user name is taken, or an error if the database operation fails.
+    fn set_user_name(
+        &self,
+        user_id: &Uuid,
+        user_name: &str,
+        profile_image: Option<&str>,
+    ) -> Result<UserInformation>;
+
+    /// Retrieves the service accounts owned by a team.
+    ///
+    /// # Parameters
+    /// - `team_id`: A reference to the `Uuid` of the owning team.
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `Vec` of the team's service accounts, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return an error if the database operation fails.
+    fn get_service_accounts(&self, team_id: &Uuid) -> Result<Vec<UserInformation>>;
+
+    /// Creates a service account together with its team membership, in a single transaction.
+    ///
+    /// # Parameters
+    /// - `user_information`: A reference to the `UserInformation` of the service account.
+    /// - `team_member`: A reference to the `TeamMember` joining the service account to its team.
+    ///
+    /// # Returns
+    /// A `Result` wrapping `UserInformation` of the created service account, or an error if the operation fails.
+    ///
+    /// # Errors
+    /// This function will return `AlreadyExistsError` if the user name is taken, or an error if the database operation fails.
+    fn create_service_account(
+        &self,
+        user_information: &UserInformation,
+        team_member: &TeamMember,
+    ) -> Result<UserInformation>;
 }
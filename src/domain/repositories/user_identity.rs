@@ -0,0 +1,8 @@
+use crate::domain::error::Result;
+use crate::domain::models::user::user_identity::UserIdentity;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait UserIdentityRepository: Send + Sync {
+    fn get(&self, issuer: &str, subject: &str) -> Result<UserIdentity>;
+    fn create(&self, new_user_identity: &UserIdentity) -> Result<UserIdentity>;
+}
@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use crate::domain::error::Result;
+use crate::domain::models::user::webauthn_credential::WebauthnCredential;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait WebauthnCredentialRepository: Send + Sync {
+    fn create(&self, new_credential: &WebauthnCredential) -> Result<WebauthnCredential>;
+    fn get_all_for_user(&self, user_id: &Uuid) -> Result<Vec<WebauthnCredential>>;
+    /// Stores the state of a credential after a successful authentication. Nothing is
+    /// stored and `false` is returned unless `sign_count` is above the stored counter,
+    /// or both are zero for authenticators without a counter.
+    fn update_usage(
+        &self,
+        credential_id: &Uuid,
+        passkey: &str,
+        sign_count: i64,
+        used_timestamp: i64,
+    ) -> Result<bool>;
+    fn remove(&self, credential_id: &Uuid, user_id: &Uuid) -> Result<()>;
+}
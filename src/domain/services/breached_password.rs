@@ -0,0 +1,6 @@
+use crate::domain::error::Result;
+
+pub trait BreachedPasswordChecker: Send + Sync {
+    /// Returns whether the password is known from a public data breach.
+    fn is_breached(&self, password: &str) -> Result<bool>;
+}
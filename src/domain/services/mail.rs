@@ -0,0 +1,7 @@
+use crate::domain::error::Result;
+use crate::domain::models::mail::mail_message::MailMessage;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait MailSender: Send + Sync {
+    fn send(&self, message: &MailMessage) -> Result<()>;
+}
@@ -0,0 +1,3 @@
+pub mod breached_password;
+pub mod mail;
+pub mod oidc;
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::error::Result;
+use crate::domain::models::user::oidc_claims::OidcClaims;
+
+#[async_trait]
+pub trait OidcProvider: Send + Sync {
+    /// Builds the provider authorization url for the authorization code flow with PKCE.
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String>;
+
+    /// Exchanges an authorization code and returns the claims of the validated ID token.
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcClaims>;
+}
@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use derive_new::new;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::mail::mail_message::MailMessage;
+use crate::domain::services::mail::MailSender;
+
+/// Mail sender for local development, messages are logged and optionally
+/// written to `outbox_dir` instead of being delivered.
+#[derive(new, Clone)]
+pub struct LogMailSenderImpl {
+    outbox_dir: Option<PathBuf>,
+}
+
+impl MailSender for LogMailSenderImpl {
+    fn send(&self, message: &MailMessage) -> Result<()> {
+        info!(to = %message.to, subject = %message.subject, "Mail sent");
+
+        let Some(outbox_dir) = &self.outbox_dir else {
+            return Ok(());
+        };
+
+        fs::create_dir_all(outbox_dir).map_err(|e| {
+            error!("{:?}", e);
+            Error::FileError
+        })?;
+
+        let path = outbox_dir.join(format!(
+            "{}-{}.txt",
+            Utc::now().format("%Y%m%d%H%M%S"),
+            Uuid::now_v7()
+        ));
+
+        fs::write(
+            path,
+            format!(
+                "To: {}\nSubject: {}\n\n{}\n",
+                message.to, message.subject, message.body
+            ),
+        )
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::FileError
+        })
+    }
+}
@@ -1,4 +1,7 @@
 pub mod databases;
+pub mod mail;
 mod models;
+pub mod oidc;
+pub mod passwords;
 pub mod repositories;
 pub mod schema;
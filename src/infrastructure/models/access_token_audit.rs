@@ -0,0 +1,40 @@
+use crate::domain::models::user::access_token_audit::AccessTokenAudit;
+use crate::infrastructure::schema::access_token_audit;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Selectable, PartialEq, Eq)]
+#[diesel(table_name = access_token_audit)]
+#[diesel(primary_key(id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AccessTokenAuditDiesel {
+    pub id: Uuid,
+    pub access_token_id: Uuid,
+    pub user_id: Uuid,
+    pub method: String,
+    pub created_timestamp: i64,
+}
+
+impl From<AccessTokenAuditDiesel> for AccessTokenAudit {
+    fn from(value: AccessTokenAuditDiesel) -> AccessTokenAudit {
+        AccessTokenAudit {
+            id: value.id,
+            access_token_id: value.access_token_id,
+            user_id: value.user_id,
+            method: value.method,
+            created_timestamp: value.created_timestamp,
+        }
+    }
+}
+
+impl From<AccessTokenAudit> for AccessTokenAuditDiesel {
+    fn from(value: AccessTokenAudit) -> Self {
+        AccessTokenAuditDiesel {
+            id: value.id,
+            access_token_id: value.access_token_id,
+            user_id: value.user_id,
+            method: value.method,
+            created_timestamp: value.created_timestamp,
+        }
+    }
+}
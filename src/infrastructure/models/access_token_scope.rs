@@ -0,0 +1,78 @@
+use crate::domain::models::user::access_token_scope::AccessTokenScope;
+use crate::infrastructure::schema::sql_types::AccessTokenScope as AccessTokenScopeScheme;
+use diesel::deserialize::FromSql;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{IsNull, Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::{AsExpression, FromSqlRow};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
+
+#[derive(Clone, Debug, FromSqlRow, AsExpression, PartialEq, Eq)]
+#[diesel(sql_type = AccessTokenScopeScheme)]
+pub enum AccessTokenScopeDiesel {
+    ReadOnly,
+    Tasks,
+    Teams,
+}
+
+struct ParseEnumError {}
+
+impl Debug for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Error while parsing enum AccessTokenScope")
+    }
+}
+
+impl Display for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Error while parsing enum AccessTokenScope")
+    }
+}
+
+impl Error for ParseEnumError {}
+
+impl From<AccessTokenScopeDiesel> for AccessTokenScope {
+    fn from(value: AccessTokenScopeDiesel) -> Self {
+        match value {
+            AccessTokenScopeDiesel::ReadOnly => AccessTokenScope::ReadOnly,
+            AccessTokenScopeDiesel::Tasks => AccessTokenScope::Tasks,
+            AccessTokenScopeDiesel::Teams => AccessTokenScope::Teams,
+        }
+    }
+}
+
+impl From<AccessTokenScope> for AccessTokenScopeDiesel {
+    fn from(value: AccessTokenScope) -> Self {
+        match value {
+            AccessTokenScope::ReadOnly => AccessTokenScopeDiesel::ReadOnly,
+            AccessTokenScope::Tasks => AccessTokenScopeDiesel::Tasks,
+            AccessTokenScope::Teams => AccessTokenScopeDiesel::Teams,
+        }
+    }
+}
+
+impl ToSql<AccessTokenScopeScheme, Pg> for AccessTokenScopeDiesel {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> diesel::serialize::Result {
+        match *self {
+            AccessTokenScopeDiesel::ReadOnly => out.write_all(b"read_only")?,
+            AccessTokenScopeDiesel::Tasks => out.write_all(b"tasks")?,
+            AccessTokenScopeDiesel::Teams => out.write_all(b"teams")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AccessTokenScopeScheme, Pg> for AccessTokenScopeDiesel {
+    fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+        let binding = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+        let scope = binding.as_str();
+        match scope {
+            "read_only" => Ok(AccessTokenScopeDiesel::ReadOnly),
+            "tasks" => Ok(AccessTokenScopeDiesel::Tasks),
+            "teams" => Ok(AccessTokenScopeDiesel::Teams),
+            _ => Err(Box::new(ParseEnumError {})),
+        }
+    }
+}
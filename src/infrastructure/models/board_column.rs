@@ -0,0 +1,44 @@
+use crate::domain::models::task::board_column::BoardColumn;
+use crate::domain::models::task::task_status::TaskStatus;
+use crate::infrastructure::models::task_status::TaskStatusDiesel;
+use crate::infrastructure::models::team_information::TeamInformationDiesel;
+use crate::infrastructure::schema::board_column;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = board_column)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(TeamInformationDiesel, foreign_key = team_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct BoardColumnDiesel {
+    pub id: Uuid,
+    pub team_id: Uuid,
+    pub name: String,
+    pub status: TaskStatusDiesel,
+    pub position: i32,
+}
+
+impl From<BoardColumnDiesel> for BoardColumn {
+    fn from(value: BoardColumnDiesel) -> BoardColumn {
+        BoardColumn {
+            id: value.id,
+            team_id: value.team_id,
+            name: value.name,
+            status: TaskStatus::from(value.status),
+            position: value.position,
+        }
+    }
+}
+
+impl From<BoardColumn> for BoardColumnDiesel {
+    fn from(value: BoardColumn) -> Self {
+        BoardColumnDiesel {
+            id: value.id,
+            team_id: value.team_id,
+            name: value.name,
+            status: TaskStatusDiesel::from(value.status),
+            position: value.position,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::domain::models::task::checklist_item::ChecklistItem;
+use crate::infrastructure::models::task_information::TaskInformationDiesel;
+use crate::infrastructure::schema::task_checklist_item;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = task_checklist_item)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(TaskInformationDiesel, foreign_key = task_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ChecklistItemDiesel {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub text: String,
+    pub done: bool,
+    pub assignee_id: Option<Uuid>,
+    pub position: i32,
+}
+
+impl From<ChecklistItemDiesel> for ChecklistItem {
+    fn from(value: ChecklistItemDiesel) -> ChecklistItem {
+        ChecklistItem {
+            id: value.id,
+            task_id: value.task_id,
+            text: value.text,
+            done: value.done,
+            assignee_id: value.assignee_id,
+            position: value.position,
+        }
+    }
+}
+
+impl From<ChecklistItem> for ChecklistItemDiesel {
+    fn from(value: ChecklistItem) -> Self {
+        ChecklistItemDiesel {
+            id: value.id,
+            task_id: value.task_id,
+            text: value.text,
+            done: value.done,
+            assignee_id: value.assignee_id,
+            position: value.position,
+        }
+    }
+}
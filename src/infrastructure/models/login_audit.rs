@@ -0,0 +1,52 @@
+use crate::domain::models::user::login_audit::LoginAudit;
+use crate::infrastructure::schema::login_audit;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Selectable, PartialEq, Eq)]
+#[diesel(table_name = login_audit)]
+#[diesel(primary_key(id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct LoginAuditDiesel {
+    pub id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub success: bool,
+    pub reason: String,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub two_factor_used: bool,
+    pub suspicious: bool,
+    pub created_timestamp: i64,
+}
+
+impl From<LoginAuditDiesel> for LoginAudit {
+    fn from(value: LoginAuditDiesel) -> LoginAudit {
+        LoginAudit {
+            id: value.id,
+            user_id: value.user_id,
+            success: value.success,
+            reason: value.reason,
+            ip: value.ip,
+            user_agent: value.user_agent,
+            two_factor_used: value.two_factor_used,
+            suspicious: value.suspicious,
+            created_timestamp: value.created_timestamp,
+        }
+    }
+}
+
+impl From<LoginAudit> for LoginAuditDiesel {
+    fn from(value: LoginAudit) -> Self {
+        LoginAuditDiesel {
+            id: value.id,
+            user_id: value.user_id,
+            success: value.success,
+            reason: value.reason,
+            ip: value.ip,
+            user_agent: value.user_agent,
+            two_factor_used: value.two_factor_used,
+            suspicious: value.suspicious,
+            created_timestamp: value.created_timestamp,
+        }
+    }
+}
@@ -1,7 +1,24 @@
+pub mod access_token_audit;
+pub mod access_token_scope;
+pub mod board_column;
+pub mod checklist_item;
+pub mod login_audit;
+pub mod personal_access_token;
 pub mod task_assign;
+pub mod task_comment;
+pub mod task_dependency;
 pub mod task_information;
+pub mod task_label;
+pub mod task_priority;
+pub mod task_reminder;
+pub mod task_search_result;
 pub mod task_status;
 pub mod team_information;
+pub mod team_label;
 pub mod team_members;
 pub mod team_role;
+pub mod user_identity;
 pub mod user_information;
+pub mod user_recovery_code;
+pub mod user_two_factor;
+pub mod webauthn_credential;
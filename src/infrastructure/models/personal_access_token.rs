@@ -0,0 +1,53 @@
+use crate::domain::models::user::access_token::AccessToken;
+use crate::domain::models::user::access_token_scope::AccessTokenScope;
+use crate::infrastructure::models::access_token_scope::AccessTokenScopeDiesel;
+use crate::infrastructure::models::user_information::UserInformationDiesel;
+use crate::infrastructure::schema::personal_access_token;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = personal_access_token)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(UserInformationDiesel, foreign_key = user_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PersonalAccessTokenDiesel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub token_hash: String,
+    pub scope: AccessTokenScopeDiesel,
+    pub created_timestamp: i64,
+    pub expires_timestamp: Option<i64>,
+    pub last_used_timestamp: Option<i64>,
+}
+
+impl From<PersonalAccessTokenDiesel> for AccessToken {
+    fn from(value: PersonalAccessTokenDiesel) -> AccessToken {
+        AccessToken {
+            id: value.id,
+            user_id: value.user_id,
+            name: value.name,
+            token_hash: value.token_hash,
+            scope: AccessTokenScope::from(value.scope),
+            created_timestamp: value.created_timestamp,
+            expires_timestamp: value.expires_timestamp,
+            last_used_timestamp: value.last_used_timestamp,
+        }
+    }
+}
+
+impl From<AccessToken> for PersonalAccessTokenDiesel {
+    fn from(value: AccessToken) -> Self {
+        PersonalAccessTokenDiesel {
+            id: value.id,
+            user_id: value.user_id,
+            name: value.name,
+            token_hash: value.token_hash,
+            scope: AccessTokenScopeDiesel::from(value.scope),
+            created_timestamp: value.created_timestamp,
+            expires_timestamp: value.expires_timestamp,
+            last_used_timestamp: value.last_used_timestamp,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::domain::models::task::task_comment::TaskComment;
+use crate::infrastructure::models::task_information::TaskInformationDiesel;
+use crate::infrastructure::schema::task_comment;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = task_comment)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(TaskInformationDiesel, foreign_key = task_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TaskCommentDiesel {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_timestamp: i64,
+}
+
+impl From<TaskCommentDiesel> for TaskComment {
+    fn from(value: TaskCommentDiesel) -> TaskComment {
+        TaskComment {
+            id: value.id,
+            task_id: value.task_id,
+            author_id: value.author_id,
+            body: value.body,
+            created_timestamp: value.created_timestamp,
+        }
+    }
+}
+
+impl From<TaskComment> for TaskCommentDiesel {
+    fn from(value: TaskComment) -> Self {
+        TaskCommentDiesel {
+            id: value.id,
+            task_id: value.task_id,
+            author_id: value.author_id,
+            body: value.body,
+            created_timestamp: value.created_timestamp,
+        }
+    }
+}
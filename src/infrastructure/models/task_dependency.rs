@@ -0,0 +1,34 @@
+use crate::domain::models::task::task_dependency::TaskDependency;
+use crate::infrastructure::schema::task_dependency;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Selectable, PartialEq, Eq)]
+#[diesel(table_name = task_dependency)]
+#[diesel(primary_key(id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TaskDependencyDiesel {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub blocker_id: Uuid,
+}
+
+impl From<TaskDependencyDiesel> for TaskDependency {
+    fn from(value: TaskDependencyDiesel) -> TaskDependency {
+        TaskDependency {
+            id: value.id,
+            task_id: value.task_id,
+            blocker_id: value.blocker_id,
+        }
+    }
+}
+
+impl From<TaskDependency> for TaskDependencyDiesel {
+    fn from(value: TaskDependency) -> Self {
+        TaskDependencyDiesel {
+            id: value.id,
+            task_id: value.task_id,
+            blocker_id: value.blocker_id,
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_priority::TaskPriority;
 use crate::domain::models::task::task_status::TaskStatus;
+use crate::infrastructure::models::task_priority::TaskPriorityDiesel;
 use crate::infrastructure::models::task_status::TaskStatusDiesel;
 use crate::infrastructure::models::team_information::TeamInformationDiesel;
 use crate::infrastructure::models::user_information::UserInformationDiesel;
 use crate::infrastructure::schema::task_information;
-use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use diesel::{Associations, Identifiable, Insertable, Queryable, QueryableByName, Selectable};
 use uuid::Uuid;
 
-#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[derive(
+    Insertable, Queryable, QueryableByName, Identifiable, Associations, Selectable, PartialEq, Eq,
+)]
 #[diesel(table_name = task_information)]
 #[diesel(primary_key(id))]
 #[diesel(belongs_to(TeamInformationDiesel, foreign_key = team_id))]
@@ -22,6 +26,14 @@ pub struct TaskInformationDiesel {
     pub status: TaskStatusDiesel,
     pub team_id: Uuid,
     pub creator: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub priority: TaskPriorityDiesel,
+    pub column_id: Option<Uuid>,
+    pub position: i32,
+    pub recurrence_rule: Option<String>,
+    pub recurrence_index: i32,
+    pub recurrence_pending: bool,
+    pub recurrence_anchor: Option<i64>,
 }
 
 impl From<TaskInformationDiesel> for TaskInformation {
@@ -36,6 +48,19 @@ impl From<TaskInformationDiesel> for TaskInformation {
             status: TaskStatus::from(value.status),
             creator: value.creator,
             assigned_users: vec![],
+            parent_id: value.parent_id,
+            priority: TaskPriority::from(value.priority),
+            column_id: value.column_id,
+            position: value.position,
+            recurrence_rule: value.recurrence_rule,
+            recurrence_index: value.recurrence_index,
+            recurrence_pending: value.recurrence_pending,
+            recurrence_anchor: value.recurrence_anchor,
+            children_total: 0,
+            children_finished: 0,
+            checklist_total: 0,
+            checklist_done: 0,
+            labels: vec![],
         }
     }
 }
@@ -52,6 +77,19 @@ impl From<&TaskInformationDiesel> for TaskInformation {
             status: TaskStatus::from(value.status.clone()),
             creator: value.creator,
             assigned_users: vec![],
+            parent_id: value.parent_id,
+            priority: TaskPriority::from(value.priority.clone()),
+            column_id: value.column_id,
+            position: value.position,
+            recurrence_rule: value.recurrence_rule.clone(),
+            recurrence_index: value.recurrence_index,
+            recurrence_pending: value.recurrence_pending,
+            recurrence_anchor: value.recurrence_anchor,
+            children_total: 0,
+            children_finished: 0,
+            checklist_total: 0,
+            checklist_done: 0,
+            labels: vec![],
         }
     }
 }
@@ -67,6 +105,14 @@ impl From<TaskInformation> for TaskInformationDiesel {
             status: TaskStatusDiesel::from(value.status),
             team_id: value.team_id,
             creator: value.creator,
+            parent_id: value.parent_id,
+            priority: TaskPriorityDiesel::from(value.priority),
+            column_id: value.column_id,
+            position: value.position,
+            recurrence_rule: value.recurrence_rule,
+            recurrence_index: value.recurrence_index,
+            recurrence_pending: value.recurrence_pending,
+            recurrence_anchor: value.recurrence_anchor,
         }
     }
 }
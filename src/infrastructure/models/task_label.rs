@@ -0,0 +1,38 @@
+use crate::domain::models::task::task_label::TaskLabel;
+use crate::infrastructure::models::task_information::TaskInformationDiesel;
+use crate::infrastructure::models::team_label::TeamLabelDiesel;
+use crate::infrastructure::schema::task_label;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = task_label)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(TaskInformationDiesel, foreign_key = task_id))]
+#[diesel(belongs_to(TeamLabelDiesel, foreign_key = label_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TaskLabelDiesel {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub label_id: Uuid,
+}
+
+impl From<TaskLabelDiesel> for TaskLabel {
+    fn from(value: TaskLabelDiesel) -> TaskLabel {
+        TaskLabel {
+            id: value.id,
+            task_id: value.task_id,
+            label_id: value.label_id,
+        }
+    }
+}
+
+impl From<TaskLabel> for TaskLabelDiesel {
+    fn from(value: TaskLabel) -> Self {
+        TaskLabelDiesel {
+            id: value.id,
+            task_id: value.task_id,
+            label_id: value.label_id,
+        }
+    }
+}
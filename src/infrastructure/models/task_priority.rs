@@ -0,0 +1,88 @@
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::infrastructure::schema::sql_types::TaskPriority as TaskPriorityScheme;
+use diesel::deserialize::FromSql;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{IsNull, Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::{AsExpression, FromSqlRow};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
+
+#[derive(Clone, Debug, FromSqlRow, AsExpression, PartialEq, Eq)]
+#[diesel(sql_type = TaskPriorityScheme)]
+pub enum TaskPriorityDiesel {
+    None,
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+struct ParseEnumError {}
+
+impl Debug for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Error while parsing enum TaskPriority")
+    }
+}
+
+impl Display for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Error while parsing enum TaskPriority")
+    }
+}
+
+impl Error for ParseEnumError {}
+
+impl From<TaskPriorityDiesel> for TaskPriority {
+    fn from(value: TaskPriorityDiesel) -> Self {
+        match value {
+            TaskPriorityDiesel::None => TaskPriority::None,
+            TaskPriorityDiesel::Low => TaskPriority::Low,
+            TaskPriorityDiesel::Medium => TaskPriority::Medium,
+            TaskPriorityDiesel::High => TaskPriority::High,
+            TaskPriorityDiesel::Urgent => TaskPriority::Urgent,
+        }
+    }
+}
+
+impl From<TaskPriority> for TaskPriorityDiesel {
+    fn from(value: TaskPriority) -> Self {
+        match value {
+            TaskPriority::None => TaskPriorityDiesel::None,
+            TaskPriority::Low => TaskPriorityDiesel::Low,
+            TaskPriority::Medium => TaskPriorityDiesel::Medium,
+            TaskPriority::High => TaskPriorityDiesel::High,
+            TaskPriority::Urgent => TaskPriorityDiesel::Urgent,
+        }
+    }
+}
+
+impl ToSql<TaskPriorityScheme, Pg> for TaskPriorityDiesel {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> diesel::serialize::Result {
+        match *self {
+            TaskPriorityDiesel::None => out.write_all(b"none")?,
+            TaskPriorityDiesel::Low => out.write_all(b"low")?,
+            TaskPriorityDiesel::Medium => out.write_all(b"medium")?,
+            TaskPriorityDiesel::High => out.write_all(b"high")?,
+            TaskPriorityDiesel::Urgent => out.write_all(b"urgent")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<TaskPriorityScheme, Pg> for TaskPriorityDiesel {
+    fn from_sql(bytes: PgValue) -> diesel::deserialize::Result<Self> {
+        let binding = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+        let priority = binding.as_str();
+        match priority {
+            "none" => Ok(TaskPriorityDiesel::None),
+            "low" => Ok(TaskPriorityDiesel::Low),
+            "medium" => Ok(TaskPriorityDiesel::Medium),
+            "high" => Ok(TaskPriorityDiesel::High),
+            "urgent" => Ok(TaskPriorityDiesel::Urgent),
+            _ => Err(Box::new(ParseEnumError {})),
+        }
+    }
+}
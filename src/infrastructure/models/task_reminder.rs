@@ -0,0 +1,42 @@
+use crate::domain::models::task::task_reminder::TaskReminder;
+use crate::infrastructure::models::task_information::TaskInformationDiesel;
+use crate::infrastructure::schema::task_reminder;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = task_reminder)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(TaskInformationDiesel, foreign_key = task_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TaskReminderDiesel {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub offset_seconds: i64,
+    pub end_timestamp: i64,
+    pub sent_timestamp: i64,
+}
+
+impl From<TaskReminderDiesel> for TaskReminder {
+    fn from(value: TaskReminderDiesel) -> TaskReminder {
+        TaskReminder {
+            id: value.id,
+            task_id: value.task_id,
+            offset_seconds: value.offset_seconds,
+            end_timestamp: value.end_timestamp,
+            sent_timestamp: value.sent_timestamp,
+        }
+    }
+}
+
+impl From<TaskReminder> for TaskReminderDiesel {
+    fn from(value: TaskReminder) -> Self {
+        TaskReminderDiesel {
+            id: value.id,
+            task_id: value.task_id,
+            offset_seconds: value.offset_seconds,
+            end_timestamp: value.end_timestamp,
+            sent_timestamp: value.sent_timestamp,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use diesel::sql_types::{Float4, Text};
+use diesel::QueryableByName;
+
+use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_search_result::TaskSearchResult;
+use crate::infrastructure::models::task_information::TaskInformationDiesel;
+
+#[derive(QueryableByName)]
+pub struct TaskSearchResultDiesel {
+    #[diesel(embed)]
+    pub task: TaskInformationDiesel,
+    #[diesel(sql_type = Float4)]
+    pub rank: f32,
+    #[diesel(sql_type = Text)]
+    pub snippet: String,
+}
+
+impl From<TaskSearchResultDiesel> for TaskSearchResult {
+    fn from(value: TaskSearchResultDiesel) -> Self {
+        TaskSearchResult {
+            task: TaskInformation::from(value.task),
+            rank: value.rank,
+            snippet: value.snippet,
+        }
+    }
+}
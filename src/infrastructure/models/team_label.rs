@@ -0,0 +1,39 @@
+use crate::domain::models::team::team_label::TeamLabel;
+use crate::infrastructure::models::team_information::TeamInformationDiesel;
+use crate::infrastructure::schema::team_label;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = team_label)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(TeamInformationDiesel, foreign_key = team_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TeamLabelDiesel {
+    pub id: Uuid,
+    pub team_id: Uuid,
+    pub name: String,
+    pub color: String,
+}
+
+impl From<TeamLabelDiesel> for TeamLabel {
+    fn from(value: TeamLabelDiesel) -> TeamLabel {
+        TeamLabel {
+            id: value.id,
+            team_id: value.team_id,
+            name: value.name,
+            color: value.color,
+        }
+    }
+}
+
+impl From<TeamLabel> for TeamLabelDiesel {
+    fn from(value: TeamLabel) -> Self {
+        TeamLabelDiesel {
+            id: value.id,
+            team_id: value.team_id,
+            name: value.name,
+            color: value.color,
+        }
+    }
+}
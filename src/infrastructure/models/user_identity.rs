@@ -0,0 +1,39 @@
+use crate::domain::models::user::user_identity::UserIdentity;
+use crate::infrastructure::models::user_information::UserInformationDiesel;
+use crate::infrastructure::schema::user_identity;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = user_identity)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(UserInformationDiesel, foreign_key = user_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct UserIdentityDiesel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub issuer: String,
+    pub subject: String,
+}
+
+impl From<UserIdentityDiesel> for UserIdentity {
+    fn from(value: UserIdentityDiesel) -> UserIdentity {
+        UserIdentity {
+            id: value.id,
+            user_id: value.user_id,
+            issuer: value.issuer,
+            subject: value.subject,
+        }
+    }
+}
+
+impl From<UserIdentity> for UserIdentityDiesel {
+    fn from(value: UserIdentity) -> Self {
+        UserIdentityDiesel {
+            id: value.id,
+            user_id: value.user_id,
+            issuer: value.issuer,
+            subject: value.subject,
+        }
+    }
+}
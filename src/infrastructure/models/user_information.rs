@@ -13,6 +13,9 @@ pub struct UserInformationDiesel {
     pub profile_image: Option<String>,
     pub user_name: String,
     pub email: String,
+    pub email_verified: bool,
+    pub deletion_requested_timestamp: Option<i64>,
+    pub service_account_team_id: Option<Uuid>,
 }
 
 impl From<UserInformationDiesel> for UserInformation {
@@ -23,6 +26,9 @@ impl From<UserInformationDiesel> for UserInformation {
             user_name: value.user_name,
             profile_image: value.profile_image,
             email: value.email,
+            email_verified: value.email_verified,
+            deletion_requested_timestamp: value.deletion_requested_timestamp,
+            service_account_team_id: value.service_account_team_id,
         }
     }
 }
@@ -35,6 +41,9 @@ impl From<&UserInformationDiesel> for UserInformation {
             user_name: value.user_name.clone(),
             profile_image: value.profile_image.clone(),
             email: value.email.clone(),
+            email_verified: value.email_verified,
+            deletion_requested_timestamp: value.deletion_requested_timestamp,
+            service_account_team_id: value.service_account_team_id,
         }
     }
 }
@@ -47,6 +56,9 @@ impl From<UserInformation> for UserInformationDiesel {
             user_name: value.user_name,
             profile_image: value.profile_image,
             email: value.email,
+            email_verified: value.email_verified,
+            deletion_requested_timestamp: value.deletion_requested_timestamp,
+            service_account_team_id: value.service_account_team_id,
         }
     }
 }
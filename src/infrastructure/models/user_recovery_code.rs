@@ -0,0 +1,15 @@
+use crate::infrastructure::models::user_information::UserInformationDiesel;
+use crate::infrastructure::schema::user_recovery_code;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = user_recovery_code)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(UserInformationDiesel, foreign_key = user_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct UserRecoveryCodeDiesel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+}
@@ -0,0 +1,41 @@
+use crate::domain::models::user::two_factor_information::TwoFactorInformation;
+use crate::infrastructure::models::user_information::UserInformationDiesel;
+use crate::infrastructure::schema::user_two_factor;
+use diesel::{AsChangeset, Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(
+    Insertable, Queryable, AsChangeset, Identifiable, Associations, Selectable, PartialEq, Eq,
+)]
+#[diesel(table_name = user_two_factor)]
+#[diesel(primary_key(user_id))]
+#[diesel(belongs_to(UserInformationDiesel, foreign_key = user_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct UserTwoFactorDiesel {
+    pub user_id: Uuid,
+    pub secret: String,
+    pub confirmed: bool,
+    pub last_used_step: Option<i64>,
+}
+
+impl From<UserTwoFactorDiesel> for TwoFactorInformation {
+    fn from(value: UserTwoFactorDiesel) -> TwoFactorInformation {
+        TwoFactorInformation {
+            user_id: value.user_id,
+            secret: value.secret,
+            confirmed: value.confirmed,
+            last_used_step: value.last_used_step,
+        }
+    }
+}
+
+impl From<TwoFactorInformation> for UserTwoFactorDiesel {
+    fn from(value: TwoFactorInformation) -> Self {
+        UserTwoFactorDiesel {
+            user_id: value.user_id,
+            secret: value.secret,
+            confirmed: value.confirmed,
+            last_used_step: value.last_used_step,
+        }
+    }
+}
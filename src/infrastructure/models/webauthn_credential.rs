@@ -0,0 +1,51 @@
+use crate::domain::models::user::webauthn_credential::WebauthnCredential;
+use crate::infrastructure::models::user_information::UserInformationDiesel;
+use crate::infrastructure::schema::webauthn_credential;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable, Identifiable, Associations, Selectable, PartialEq, Eq)]
+#[diesel(table_name = webauthn_credential)]
+#[diesel(primary_key(id))]
+#[diesel(belongs_to(UserInformationDiesel, foreign_key = user_id))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebauthnCredentialDiesel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub credential_id: String,
+    pub name: String,
+    pub passkey: String,
+    pub sign_count: i64,
+    pub created_timestamp: i64,
+    pub last_used_timestamp: Option<i64>,
+}
+
+impl From<WebauthnCredentialDiesel> for WebauthnCredential {
+    fn from(value: WebauthnCredentialDiesel) -> WebauthnCredential {
+        WebauthnCredential {
+            id: value.id,
+            user_id: value.user_id,
+            credential_id: value.credential_id,
+            name: value.name,
+            passkey: value.passkey,
+            sign_count: value.sign_count,
+            created_timestamp: value.created_timestamp,
+            last_used_timestamp: value.last_used_timestamp,
+        }
+    }
+}
+
+impl From<WebauthnCredential> for WebauthnCredentialDiesel {
+    fn from(value: WebauthnCredential) -> Self {
+        WebauthnCredentialDiesel {
+            id: value.id,
+            user_id: value.user_id,
+            credential_id: value.credential_id,
+            name: value.name,
+            passkey: value.passkey,
+            sign_count: value.sign_count,
+            created_timestamp: value.created_timestamp,
+            last_used_timestamp: value.last_used_timestamp,
+        }
+    }
+}
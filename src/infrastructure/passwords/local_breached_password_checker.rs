@@ -0,0 +1,71 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use derive_new::new;
+use sha1::{Digest, Sha1};
+use tracing::error;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::services::breached_password::BreachedPasswordChecker;
+
+const PREFIX_LENGTH: usize = 5;
+
+/// Checks passwords against a local copy of a k-anonymity hash-prefix list, in the
+/// layout of the Have I Been Pwned range API: one file per 5 character SHA-1 prefix,
+/// each line being the remaining hash suffix and an occurrence count (`SUFFIX:COUNT`).
+/// Only the prefix is needed to find the candidate file, so the list can be
+/// partial and no password leaves the server.
+#[derive(new, Clone)]
+pub struct LocalBreachedPasswordCheckerImpl {
+    ranges_dir: PathBuf,
+}
+
+impl BreachedPasswordChecker for LocalBreachedPasswordCheckerImpl {
+    fn is_breached(&self, password: &str) -> Result<bool> {
+        let hash = base16ct::upper::encode_string(&Sha1::digest(password.as_bytes()));
+        let (prefix, suffix) = hash.split_at(PREFIX_LENGTH);
+
+        let range = match fs::read_to_string(self.ranges_dir.join(prefix)) {
+            Ok(range) => range,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(false),
+            Err(e) => {
+                error!("{:?}", e);
+                return Err(Error::FileError);
+            }
+        };
+
+        Ok(range.lines().any(|line| {
+            line.split(':')
+                .next()
+                .is_some_and(|candidate| candidate.trim().eq_ignore_ascii_case(suffix))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_breached() {
+        let ranges_dir =
+            std::env::temp_dir().join(format!("taskem-ranges-{}", uuid::Uuid::now_v7()));
+        fs::create_dir_all(&ranges_dir).unwrap();
+
+        // SHA-1 of "password" is 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8.
+        fs::write(
+            ranges_dir.join("5BAA6"),
+            "003D68EB55068C33ACE09247EE4C639306B:3\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:9545824\r\n",
+        )
+        .unwrap();
+
+        let checker = LocalBreachedPasswordCheckerImpl::new(ranges_dir.clone());
+
+        assert!(checker.is_breached("password").unwrap());
+        assert!(!checker.is_breached("correct horse battery staple").unwrap());
+
+        fs::remove_dir_all(ranges_dir).unwrap();
+    }
+}
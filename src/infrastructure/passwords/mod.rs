@@ -0,0 +1 @@
+pub mod local_breached_password_checker;
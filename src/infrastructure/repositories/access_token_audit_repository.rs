@@ -0,0 +1,74 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{insert_into, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::user::access_token_audit::AccessTokenAudit;
+use crate::domain::repositories::access_token_audit::AccessTokenAuditRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::access_token_audit::AccessTokenAuditDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+
+#[derive(Clone, new)]
+pub struct AccessTokenAuditRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl Debug for AccessTokenAuditRepositoryImpl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessTokenAuditRepositoryImpl").finish()
+    }
+}
+
+impl MapFrom for AccessTokenAuditRepositoryImpl {}
+
+impl GetPool for AccessTokenAuditRepositoryImpl {}
+
+impl AccessTokenAuditRepository for AccessTokenAuditRepositoryImpl {
+    fn create(&self, new_access_token_audit: &AccessTokenAudit) -> Result<AccessTokenAudit> {
+        use crate::infrastructure::schema::access_token_audit::dsl::access_token_audit;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(access_token_audit)
+            .values(AccessTokenAuditDiesel::from(new_access_token_audit.clone()))
+            .get_result::<AccessTokenAuditDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(AccessTokenAudit::from(created))
+    }
+
+    fn get_page_for_user(
+        &self,
+        id_user: &Uuid,
+        before_id: Option<&Uuid>,
+        limit: i64,
+    ) -> Result<Vec<AccessTokenAudit>> {
+        use crate::infrastructure::schema::access_token_audit::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        // Ids are UUIDv7, so ordering by id is ordering by time.
+        let mut query = access_token_audit
+            .filter(user_id.eq(id_user))
+            .select(AccessTokenAuditDiesel::as_select())
+            .order_by(id.desc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(before_id) = before_id {
+            query = query.filter(id.lt(before_id));
+        }
+
+        self.map_from(query.load(&mut conn))
+    }
+}
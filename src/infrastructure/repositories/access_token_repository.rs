@@ -0,0 +1,107 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use chrono::Utc;
+use derive_new::new;
+use diesel::{
+    delete, insert_into, update, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::user::access_token::AccessToken;
+use crate::domain::repositories::access_token::AccessTokenRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::personal_access_token::PersonalAccessTokenDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+
+#[derive(Clone, new)]
+pub struct AccessTokenRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl Debug for AccessTokenRepositoryImpl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessTokenRepositoryImpl").finish()
+    }
+}
+
+impl MapFrom for AccessTokenRepositoryImpl {}
+
+impl GetPool for AccessTokenRepositoryImpl {}
+
+impl AccessTokenRepository for AccessTokenRepositoryImpl {
+    fn create(&self, new_access_token: &AccessToken) -> Result<AccessToken> {
+        use crate::infrastructure::schema::personal_access_token::dsl::personal_access_token;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(personal_access_token)
+            .values(PersonalAccessTokenDiesel::from(new_access_token.clone()))
+            .get_result::<PersonalAccessTokenDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(AccessToken::from(created))
+    }
+
+    fn get_all_for_user(&self, id_user: &Uuid) -> Result<Vec<AccessToken>> {
+        use crate::infrastructure::schema::personal_access_token::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = personal_access_token
+            .filter(user_id.eq(id_user))
+            .select(PersonalAccessTokenDiesel::as_select())
+            .order_by(created_timestamp)
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn find_active(&self, provided_token_hash: &str) -> Result<AccessToken> {
+        use crate::infrastructure::schema::personal_access_token::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+        let now = Utc::now().timestamp();
+
+        let token = update(personal_access_token)
+            .filter(token_hash.eq(provided_token_hash))
+            .filter(expires_timestamp.is_null().or(expires_timestamp.gt(now)))
+            .set(last_used_timestamp.eq(now))
+            .get_result::<PersonalAccessTokenDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(AccessToken::from(token))
+    }
+
+    fn remove(&self, token_id: &Uuid, id_user: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::personal_access_token::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let removed = delete(personal_access_token)
+            .filter(id.eq(token_id))
+            .filter(user_id.eq(id_user))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        if removed == 0 {
+            return Err(Error::RepositoryError);
+        }
+
+        Ok(())
+    }
+}
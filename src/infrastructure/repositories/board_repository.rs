@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{
+    delete, insert_into, update, Connection, ExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::task::board_column::BoardColumn;
+use crate::domain::repositories::board::BoardRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::board_column::BoardColumnDiesel;
+use crate::infrastructure::models::task_status::TaskStatusDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+use crate::infrastructure::repositories::map_unique_violation::MapUniqueViolation;
+
+#[derive(Clone, new)]
+pub struct BoardRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl MapFrom for BoardRepositoryImpl {}
+
+impl GetPool for BoardRepositoryImpl {}
+
+impl MapUniqueViolation for BoardRepositoryImpl {}
+
+impl BoardRepository for BoardRepositoryImpl {
+    fn get_column(&self, column_id: &Uuid) -> Result<BoardColumn> {
+        use crate::infrastructure::schema::board_column::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let column = board_column
+            .filter(id.eq(column_id))
+            .select(BoardColumnDiesel::as_select())
+            .first(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(BoardColumn::from(column))
+    }
+
+    fn get_columns(&self, id_team: &Uuid) -> Result<Vec<BoardColumn>> {
+        use crate::infrastructure::schema::board_column::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = board_column
+            .filter(team_id.eq(id_team))
+            .select(BoardColumnDiesel::as_select())
+            .order_by((position, id))
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn create_column(&self, new_column: &BoardColumn) -> Result<BoardColumn> {
+        use crate::infrastructure::schema::board_column::dsl::board_column;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(board_column)
+            .values(BoardColumnDiesel::from(new_column.clone()))
+            .get_result::<BoardColumnDiesel>(&mut conn)
+            .map_err(Self::map_unique_violation)?;
+
+        Ok(BoardColumn::from(created))
+    }
+
+    fn update_columns(&self, columns: &[BoardColumn]) -> Result<()> {
+        use crate::infrastructure::schema::board_column::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            for column in columns {
+                update(board_column)
+                    .filter(id.eq(column.id))
+                    .set((
+                        name.eq(&column.name),
+                        status.eq(TaskStatusDiesel::from(column.status.clone())),
+                        position.eq(column.position),
+                    ))
+                    .execute(conn)?;
+            }
+
+            Ok(())
+        })
+        .map_err(Self::map_unique_violation)
+    }
+
+    fn count_tasks(&self, id_column: &Uuid) -> Result<i64> {
+        use crate::infrastructure::schema::task_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        task_information
+            .filter(column_id.eq(id_column))
+            .count()
+            .get_result(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })
+    }
+
+    fn delete_column(&self, column_id: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::{board_column, task_information};
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            update(task_information::table)
+                .filter(task_information::column_id.eq(column_id))
+                .set((
+                    task_information::column_id.eq(None::<Uuid>),
+                    task_information::position.eq(0),
+                ))
+                .execute(conn)?;
+
+            delete(board_column::table)
+                .filter(board_column::id.eq(column_id))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+}
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::dsl::max;
+use diesel::sql_types::Text;
+use diesel::{
+    delete, insert_into, sql_query, update, Connection, ExpressionMethods, PgConnection, QueryDsl,
+    RunQueryDsl, SelectableHelper,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::ordering::insert_at;
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::task::checklist_item::ChecklistItem;
+use crate::domain::repositories::checklist::ChecklistRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::checklist_item::ChecklistItemDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+
+#[derive(Clone, new)]
+pub struct ChecklistRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl MapFrom for ChecklistRepositoryImpl {}
+
+impl GetPool for ChecklistRepositoryImpl {}
+
+impl ChecklistRepositoryImpl {
+    /// Serializes changes to the order of a checklist, so two items can't take the same
+    /// position.
+    fn lock_checklist(
+        conn: &mut PgConnection,
+        task_id: &Uuid,
+    ) -> std::result::Result<(), diesel::result::Error> {
+        sql_query("select pg_advisory_xact_lock(hashtext($1))")
+            .bind::<Text, _>(task_id.to_string())
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    /// Numbers the items from 0 in the given order.
+    fn renumber(
+        conn: &mut PgConnection,
+        ordered: &[Uuid],
+    ) -> std::result::Result<(), diesel::result::Error> {
+        use crate::infrastructure::schema::task_checklist_item::dsl::*;
+
+        for (index, ordered_id) in ordered.iter().enumerate() {
+            update(task_checklist_item)
+                .filter(id.eq(ordered_id))
+                .set(position.eq(index as i32))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ChecklistRepository for ChecklistRepositoryImpl {
+    fn get_item(&self, item_id: &Uuid) -> Result<ChecklistItem> {
+        use crate::infrastructure::schema::task_checklist_item::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let item = task_checklist_item
+            .filter(id.eq(item_id))
+            .select(ChecklistItemDiesel::as_select())
+            .first(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(ChecklistItem::from(item))
+    }
+
+    fn get_items(&self, id_task: &Uuid) -> Result<Vec<ChecklistItem>> {
+        use crate::infrastructure::schema::task_checklist_item::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = task_checklist_item
+            .filter(task_id.eq(id_task))
+            .select(ChecklistItemDiesel::as_select())
+            .order_by((position, id))
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn add_item(&self, new_item: &ChecklistItem) -> Result<ChecklistItem> {
+        use crate::infrastructure::schema::task_checklist_item::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            Self::lock_checklist(conn, &new_item.task_id)?;
+
+            let last = task_checklist_item
+                .filter(task_id.eq(new_item.task_id))
+                .select(max(position))
+                .first::<Option<i32>>(conn)?;
+
+            insert_into(task_checklist_item)
+                .values(ChecklistItemDiesel::from(ChecklistItem {
+                    position: last.map_or(0, |last| last + 1),
+                    ..new_item.clone()
+                }))
+                .get_result::<ChecklistItemDiesel>(conn)
+        });
+
+        match result {
+            Ok(item) => Ok(ChecklistItem::from(item)),
+            Err(e) => {
+                error!("{:?}", e);
+                Err(Error::RepositoryError)
+            }
+        }
+    }
+
+    fn set_done(&self, item_id: &Uuid, is_done: bool) -> Result<ChecklistItem> {
+        use crate::infrastructure::schema::task_checklist_item::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let item = update(task_checklist_item)
+            .filter(id.eq(item_id))
+            .set(done.eq(is_done))
+            .get_result::<ChecklistItemDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(ChecklistItem::from(item))
+    }
+
+    fn move_item(&self, item: &ChecklistItem, new_position: i32) -> Result<()> {
+        use crate::infrastructure::schema::task_checklist_item::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            Self::lock_checklist(conn, &item.task_id)?;
+
+            let mut ordered = task_checklist_item
+                .filter(task_id.eq(item.task_id))
+                .filter(id.ne(item.id))
+                .select(id)
+                .order_by((position, id))
+                .load::<Uuid>(conn)?;
+
+            insert_at(&mut ordered, new_position, item.id);
+
+            Self::renumber(conn, &ordered)
+        })
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+
+    fn delete_item(&self, item: &ChecklistItem) -> Result<()> {
+        use crate::infrastructure::schema::task_checklist_item::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            Self::lock_checklist(conn, &item.task_id)?;
+
+            delete(task_checklist_item)
+                .filter(id.eq(item.id))
+                .execute(conn)?;
+
+            let ordered = task_checklist_item
+                .filter(task_id.eq(item.task_id))
+                .select(id)
+                .order_by((position, id))
+                .load::<Uuid>(conn)?;
+
+            Self::renumber(conn, &ordered)
+        })
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+}
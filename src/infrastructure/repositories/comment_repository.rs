@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{delete, insert_into, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::task::task_comment::TaskComment;
+use crate::domain::repositories::comment::CommentRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::task_comment::TaskCommentDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+
+#[derive(Clone, new)]
+pub struct CommentRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl MapFrom for CommentRepositoryImpl {}
+
+impl GetPool for CommentRepositoryImpl {}
+
+impl CommentRepository for CommentRepositoryImpl {
+    fn get(&self, comment_id: &Uuid) -> Result<TaskComment> {
+        use crate::infrastructure::schema::task_comment::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let comment = task_comment
+            .filter(id.eq(comment_id))
+            .select(TaskCommentDiesel::as_select())
+            .first(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(TaskComment::from(comment))
+    }
+
+    fn get_for_task(&self, id_task: &Uuid) -> Result<Vec<TaskComment>> {
+        use crate::infrastructure::schema::task_comment::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        // Ids are UUIDv7, so ordering by id is ordering by time.
+        let query = task_comment
+            .filter(task_id.eq(id_task))
+            .select(TaskCommentDiesel::as_select())
+            .order_by(id.asc())
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn get_all_by_author(&self, id_author: &Uuid) -> Result<Vec<TaskComment>> {
+        use crate::infrastructure::schema::task_comment::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = task_comment
+            .filter(author_id.eq(id_author))
+            .select(TaskCommentDiesel::as_select())
+            .order_by(id.asc())
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn create(&self, new_comment: &TaskComment) -> Result<TaskComment> {
+        use crate::infrastructure::schema::task_comment::dsl::task_comment;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(task_comment)
+            .values(TaskCommentDiesel::from(new_comment.clone()))
+            .returning(TaskCommentDiesel::as_returning())
+            .get_result(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(TaskComment::from(created))
+    }
+
+    fn delete(&self, comment_id: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::task_comment::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let deleted = delete(task_comment)
+            .filter(id.eq(comment_id))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        if deleted == 0 {
+            return Err(Error::RepositoryError);
+        }
+
+        Ok(())
+    }
+}
@@ -85,6 +85,34 @@ impl FileRepository for FileRepositoryImpl {
             })?;
         Ok(())
     }
+
+    async fn list(&self, bucket: &str, prefix: &str) -> crate::domain::error::Result<Vec<String>> {
+        let client = self.client.clone();
+
+        let mut pages = client
+            .list_objects_v2()
+            .bucket(bucket)
+            .prefix(prefix)
+            .into_paginator()
+            .send();
+
+        let mut keys = Vec::new();
+
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|e| {
+                tracing::error!("Failed to list files: {:?}", e);
+                Error::RepositoryError
+            })?;
+
+            keys.extend(
+                page.contents()
+                    .iter()
+                    .filter_map(|object| object.key.clone()),
+            );
+        }
+
+        Ok(keys)
+    }
 }
 
 #[cfg(test)]
@@ -137,4 +165,17 @@ mod tests {
         let result = mock.delete("test-bucket", "test.txt").await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_list_success() {
+        let mut mock = MockFileRepository::default();
+
+        mock.expect_list()
+            .with(eq("test-bucket"), eq("user/"))
+            .times(1)
+            .returning(|_, _| Ok(vec![String::from("user/avatar.jpg")]));
+
+        let result = mock.list("test-bucket", "user/").await;
+        assert_eq!(result.unwrap(), vec![String::from("user/avatar.jpg")]);
+    }
 }
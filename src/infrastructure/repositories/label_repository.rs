@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{
+    delete, insert_into, update, Connection, ExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::task::task_label::TaskLabel;
+use crate::domain::models::team::team_label::TeamLabel;
+use crate::domain::repositories::label::LabelRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::task_label::TaskLabelDiesel;
+use crate::infrastructure::models::team_label::TeamLabelDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+use crate::infrastructure::repositories::map_unique_violation::MapUniqueViolation;
+
+#[derive(Clone, new)]
+pub struct LabelRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl MapFrom for LabelRepositoryImpl {}
+
+impl GetPool for LabelRepositoryImpl {}
+
+impl MapUniqueViolation for LabelRepositoryImpl {}
+
+impl LabelRepository for LabelRepositoryImpl {
+    fn get(&self, label_id: &Uuid) -> Result<TeamLabel> {
+        use crate::infrastructure::schema::team_label::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let label = team_label
+            .filter(id.eq(label_id))
+            .select(TeamLabelDiesel::as_select())
+            .first(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(TeamLabel::from(label))
+    }
+
+    fn get_all_for_team(&self, id_team: &Uuid) -> Result<Vec<TeamLabel>> {
+        use crate::infrastructure::schema::team_label::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = team_label
+            .filter(team_id.eq(id_team))
+            .select(TeamLabelDiesel::as_select())
+            .order_by(name)
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn create(&self, new_label: &TeamLabel) -> Result<TeamLabel> {
+        use crate::infrastructure::schema::team_label::dsl::team_label;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(team_label)
+            .values(TeamLabelDiesel::from(new_label.clone()))
+            .get_result::<TeamLabelDiesel>(&mut conn)
+            .map_err(Self::map_unique_violation)?;
+
+        Ok(TeamLabel::from(created))
+    }
+
+    fn update(&self, label: &TeamLabel) -> Result<TeamLabel> {
+        use crate::infrastructure::schema::team_label::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let updated = update(team_label)
+            .filter(id.eq(label.id))
+            .set((name.eq(&label.name), color.eq(&label.color)))
+            .get_result::<TeamLabelDiesel>(&mut conn)
+            .map_err(Self::map_unique_violation)?;
+
+        Ok(TeamLabel::from(updated))
+    }
+
+    fn delete(&self, label_id: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::{task_label, team_label};
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            delete(task_label::table)
+                .filter(task_label::label_id.eq(label_id))
+                .execute(conn)?;
+
+            delete(team_label::table)
+                .filter(team_label::id.eq(label_id))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+
+    fn attach(&self, new_task_label: &TaskLabel) -> Result<()> {
+        use crate::infrastructure::schema::task_label::dsl::task_label;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        insert_into(task_label)
+            .values(TaskLabelDiesel::from(new_task_label.clone()))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(())
+    }
+
+    fn detach(&self, id_task: &Uuid, id_label: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::task_label::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let removed = delete(task_label)
+            .filter(task_id.eq(id_task))
+            .filter(label_id.eq(id_label))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        if removed == 0 {
+            return Err(Error::RepositoryError);
+        }
+
+        Ok(())
+    }
+}
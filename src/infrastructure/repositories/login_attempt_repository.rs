@@ -0,0 +1,125 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use chrono::Utc;
+use derive_new::new;
+use r2d2_redis::r2d2::Pool;
+use r2d2_redis::redis;
+use r2d2_redis::redis::Commands;
+use r2d2_redis::RedisConnectionManager;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::repositories::login_attempt::RedisLoginAttemptRepository;
+
+#[derive(new, Clone, Debug)]
+pub struct RedisLoginAttemptRepositoryImpl {
+    pool: Arc<Pool<RedisConnectionManager>>,
+}
+
+impl RedisLoginAttemptRepositoryImpl {
+    fn failures_key(account: &str) -> String {
+        format!("login_failures:{}", account)
+    }
+
+    fn lockout_key(account: &str) -> String {
+        format!("login_lockout:{}", account)
+    }
+}
+
+impl RedisLoginAttemptRepository for RedisLoginAttemptRepositoryImpl {
+    fn check_rate_limit(&self, key: &str, limit: u32, window_seconds: i64) -> Result<bool> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let now = Utc::now().timestamp_millis();
+        let window_start = now - window_seconds * 1000;
+
+        let (attempts,): (u32,) = redis::pipe()
+            .atomic()
+            .zrembyscore(key, 0, window_start)
+            .ignore()
+            .zcard(key)
+            .zadd(key, Uuid::now_v7().to_string(), now)
+            .ignore()
+            .expire(key, window_seconds as usize)
+            .ignore()
+            .query(&mut *connection)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        Ok(attempts < limit)
+    }
+
+    fn register_failure(&self, account: &str, expire_seconds: i64) -> Result<u32> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let key = Self::failures_key(account);
+
+        let (failures,): (u32,) = redis::pipe()
+            .atomic()
+            .incr(&key, 1)
+            .expire(&key, expire_seconds as usize)
+            .ignore()
+            .query(&mut *connection)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        Ok(failures)
+    }
+
+    fn reset_failures(&self, account: &str) -> Result<()> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        connection
+            .del::<&str, ()>(&Self::failures_key(account))
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })
+    }
+
+    fn lock(&self, account: &str, lockout_seconds: i64) -> Result<()> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        connection
+            .set_ex::<&str, i64, ()>(
+                &Self::lockout_key(account),
+                lockout_seconds,
+                lockout_seconds as usize,
+            )
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })
+    }
+
+    fn lockout_remaining(&self, account: &str) -> Result<Option<i64>> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let remaining = connection
+            .ttl::<&str, i64>(&Self::lockout_key(account))
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        Ok((remaining > 0).then_some(remaining))
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{insert_into, update, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::user::login_audit::LoginAudit;
+use crate::domain::repositories::login_audit::LoginAuditRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::login_audit::LoginAuditDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+
+#[derive(Clone, new)]
+pub struct LoginAuditRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl MapFrom for LoginAuditRepositoryImpl {}
+
+impl GetPool for LoginAuditRepositoryImpl {}
+
+impl LoginAuditRepository for LoginAuditRepositoryImpl {
+    fn create(&self, new_login_audit: &LoginAudit) -> Result<LoginAudit> {
+        use crate::infrastructure::schema::login_audit::dsl::login_audit;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(login_audit)
+            .values(LoginAuditDiesel::from(new_login_audit.clone()))
+            .get_result::<LoginAuditDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(LoginAudit::from(created))
+    }
+
+    fn get_page_for_user(
+        &self,
+        id_user: &Uuid,
+        before_id: Option<&Uuid>,
+        limit: i64,
+    ) -> Result<Vec<LoginAudit>> {
+        use crate::infrastructure::schema::login_audit::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        // Ids are UUIDv7, so ordering by id is ordering by time.
+        let mut query = login_audit
+            .filter(user_id.eq(id_user))
+            .select(LoginAuditDiesel::as_select())
+            .order_by(id.desc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(before_id) = before_id {
+            query = query.filter(id.lt(before_id));
+        }
+
+        self.map_from(query.load(&mut conn))
+    }
+
+    fn set_suspicious(
+        &self,
+        login_audit_id: &Uuid,
+        id_user: &Uuid,
+        is_suspicious: bool,
+    ) -> Result<LoginAudit> {
+        use crate::infrastructure::schema::login_audit::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let updated = update(login_audit)
+            .filter(id.eq(login_audit_id))
+            .filter(user_id.eq(id_user))
+            .set(suspicious.eq(is_suspicious))
+            .get_result::<LoginAuditDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(LoginAudit::from(updated))
+    }
+}
@@ -0,0 +1,52 @@
+use diesel::result::DatabaseErrorKind;
+use tracing::error;
+
+use crate::domain::error::Error;
+
+pub trait MapUniqueViolation {
+    /// Maps a unique constraint violation to `AlreadyExistsError`, any other failure to
+    /// `RepositoryError`.
+    fn map_unique_violation(e: diesel::result::Error) -> Error {
+        error!("{:?}", e);
+
+        match e {
+            diesel::result::Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                Error::AlreadyExistsError
+            }
+            _ => Error::RepositoryError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestRepository;
+
+    impl MapUniqueViolation for TestRepository {}
+
+    fn database_error(kind: DatabaseErrorKind) -> diesel::result::Error {
+        diesel::result::Error::DatabaseError(kind, Box::new(String::from("constraint failed")))
+    }
+
+    #[test]
+    fn test_unique_violation_already_exists() {
+        let error = TestRepository::map_unique_violation(database_error(
+            DatabaseErrorKind::UniqueViolation,
+        ));
+
+        assert!(matches!(error, Error::AlreadyExistsError));
+    }
+
+    #[test]
+    fn test_other_errors_are_repository_errors() {
+        let error = TestRepository::map_unique_violation(database_error(
+            DatabaseErrorKind::ForeignKeyViolation,
+        ));
+        assert!(matches!(error, Error::RepositoryError));
+
+        let error = TestRepository::map_unique_violation(diesel::result::Error::NotFound);
+        assert!(matches!(error, Error::RepositoryError));
+    }
+}
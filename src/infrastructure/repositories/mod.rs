@@ -1,8 +1,24 @@
+pub mod access_token_audit_repository;
+pub mod access_token_repository;
+pub mod board_repository;
+pub mod checklist_repository;
+pub mod comment_repository;
 pub mod file_repository;
 mod get_pool;
+pub mod label_repository;
+pub mod login_attempt_repository;
+pub mod login_audit_repository;
 mod map_from;
+mod map_unique_violation;
+pub mod refresh_token_repository;
+pub mod reminder_repository;
+pub mod revoked_token_repository;
 pub mod role_repository;
 pub mod session_repository;
 pub mod task_repository;
 pub mod team_repository;
+pub mod token_repository;
+pub mod two_factor_repository;
+pub mod user_identity_repository;
 pub mod user_repository;
+pub mod webauthn_credential_repository;
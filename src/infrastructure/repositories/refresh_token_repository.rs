@@ -0,0 +1,216 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use derive_new::new;
+use r2d2_redis::r2d2::Pool;
+use r2d2_redis::redis;
+use r2d2_redis::redis::Commands;
+use r2d2_redis::RedisConnectionManager;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::core::token::{generate_token, hash_token};
+use crate::domain::constants::REFRESH_TOKEN_EXPIRE_SECONDS;
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::repositories::refresh_token::RedisRefreshTokenRepository;
+
+/// Refresh tokens have the form `{family_id}.{secret}`, so the family can be
+/// revoked even when a rotated token is replayed.
+#[derive(new, Clone, Debug)]
+pub struct RedisRefreshTokenRepositoryImpl {
+    pool: Arc<Pool<RedisConnectionManager>>,
+}
+
+impl RedisRefreshTokenRepositoryImpl {
+    fn token_key(refresh_token: &str) -> String {
+        format!("refresh_token:{}", hash_token(refresh_token))
+    }
+
+    fn used_token_key(refresh_token: &str) -> String {
+        format!("refresh_token_used:{}", hash_token(refresh_token))
+    }
+
+    fn family_key(family_id: &str) -> String {
+        format!("refresh_family:{}", family_id)
+    }
+
+    fn user_families_key(user_id: &Uuid) -> String {
+        format!("user_refresh_families:{}", user_id)
+    }
+
+    fn family_id(refresh_token: &str) -> Result<&str> {
+        refresh_token
+            .split_once('.')
+            .map(|(family_id, _)| family_id)
+            .ok_or(Error::RedisError)
+    }
+
+    fn issue(
+        connection: &mut impl redis::ConnectionLike,
+        user_id: &Uuid,
+        family_id: &str,
+    ) -> Result<String> {
+        let refresh_token = format!("{}.{}", family_id, generate_token());
+        let expire_seconds = REFRESH_TOKEN_EXPIRE_SECONDS as usize;
+        let user_families_key = Self::user_families_key(user_id);
+
+        redis::pipe()
+            .atomic()
+            .set_ex(
+                Self::family_key(family_id),
+                user_id.to_string(),
+                expire_seconds,
+            )
+            .ignore()
+            .set_ex(
+                Self::token_key(&refresh_token),
+                user_id.to_string(),
+                expire_seconds,
+            )
+            .ignore()
+            .sadd(&user_families_key, family_id)
+            .ignore()
+            .expire(&user_families_key, expire_seconds)
+            .ignore()
+            .query::<()>(connection)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        Ok(refresh_token)
+    }
+}
+
+impl RedisRefreshTokenRepository for RedisRefreshTokenRepositoryImpl {
+    fn create(&self, user_id: &Uuid) -> Result<String> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let family_id = Uuid::new_v4().simple().to_string();
+
+        Self::issue(&mut *connection, user_id, &family_id)
+    }
+
+    fn rotate(&self, refresh_token: &str) -> Result<(Uuid, String)> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let family_id = Self::family_id(refresh_token)?;
+        let token_key = Self::token_key(refresh_token);
+        let used_token_key = Self::used_token_key(refresh_token);
+
+        let (value, _): (Option<String>, i64) = redis::pipe()
+            .atomic()
+            .get(&token_key)
+            .del(&token_key)
+            .query(&mut *connection)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        let Some(value) = value else {
+            let reused = connection
+                .exists::<&str, bool>(&used_token_key)
+                .map_err(|e| {
+                    error!("{:?}", e);
+                    Error::RedisError
+                })?;
+
+            if reused {
+                warn!(
+                    "Refresh token reuse detected, revoking family {}",
+                    family_id
+                );
+                connection
+                    .del::<&str, ()>(&Self::family_key(family_id))
+                    .map_err(|_| Error::RedisError)?;
+            }
+
+            return Err(Error::RedisError);
+        };
+
+        connection
+            .set_ex::<&str, &str, ()>(
+                &used_token_key,
+                family_id,
+                REFRESH_TOKEN_EXPIRE_SECONDS as usize,
+            )
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        let family_alive = connection
+            .exists::<&str, bool>(&Self::family_key(family_id))
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        if !family_alive {
+            return Err(Error::RedisError);
+        }
+
+        let user_id = Uuid::from_str(&value).map_err(|_| Error::RedisError)?;
+        let new_refresh_token = Self::issue(&mut *connection, &user_id, family_id)?;
+
+        Ok((user_id, new_refresh_token))
+    }
+
+    fn revoke(&self, refresh_token: &str) -> Result<()> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let family_id = Self::family_id(refresh_token)?;
+
+        redis::pipe()
+            .atomic()
+            .del(Self::family_key(family_id))
+            .ignore()
+            .del(Self::token_key(refresh_token))
+            .ignore()
+            .query::<()>(&mut *connection)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })
+    }
+
+    fn revoke_user(&self, user_id: &Uuid) -> Result<()> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let user_families_key = Self::user_families_key(user_id);
+
+        let families = connection
+            .smembers::<&str, Vec<String>>(&user_families_key)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+
+        for family_id in &families {
+            pipe.del(Self::family_key(family_id)).ignore();
+        }
+
+        pipe.del(&user_families_key)
+            .ignore()
+            .query::<()>(&mut *connection)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })
+    }
+}
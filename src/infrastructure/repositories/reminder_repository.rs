@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{insert_into, RunQueryDsl};
+use tracing::error;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::task::task_reminder::TaskReminder;
+use crate::domain::repositories::reminder::ReminderRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::task_reminder::TaskReminderDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+
+#[derive(Clone, new)]
+pub struct ReminderRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl GetPool for ReminderRepositoryImpl {}
+
+impl ReminderRepository for ReminderRepositoryImpl {
+    fn claim(&self, reminder: &TaskReminder) -> Result<bool> {
+        use crate::infrastructure::schema::task_reminder::dsl::task_reminder;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let inserted = insert_into(task_reminder)
+            .values(TaskReminderDiesel::from(reminder.clone()))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(inserted > 0)
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use chrono::Utc;
+use derive_new::new;
+use r2d2_redis::r2d2::Pool;
+use r2d2_redis::redis::Commands;
+use r2d2_redis::RedisConnectionManager;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::repositories::revoked_token::RedisRevokedTokenRepository;
+
+#[derive(new, Clone, Debug)]
+pub struct RedisRevokedTokenRepositoryImpl {
+    pool: Arc<Pool<RedisConnectionManager>>,
+}
+
+impl RedisRevokedTokenRepositoryImpl {
+    /// Sorted set of revoked `jti`s scored by their expiry, so expired ones can be
+    /// dropped and the rest loaded at once.
+    const REVOKED_KEY: &'static str = "revoked_access_tokens";
+}
+
+impl RedisRevokedTokenRepository for RedisRevokedTokenRepositoryImpl {
+    fn revoke(&self, jti: &Uuid, expires_timestamp: i64) -> Result<()> {
+        let remaining_seconds = expires_timestamp - Utc::now().timestamp();
+
+        if remaining_seconds <= 0 {
+            return Ok(());
+        }
+
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        connection
+            .zadd::<&str, i64, String, ()>(Self::REVOKED_KEY, jti.to_string(), expires_timestamp)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })
+    }
+
+    fn get_revoked(&self, now: i64) -> Result<Vec<Uuid>> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        connection
+            .zrembyscore::<&str, &str, i64, ()>(Self::REVOKED_KEY, "-inf", now)
+            .and_then(|_| connection.zrange::<&str, Vec<String>>(Self::REVOKED_KEY, 0, -1))
+            .map(|jtis| {
+                jtis.iter()
+                    .filter_map(|jti| Uuid::parse_str(jti).ok())
+                    .collect()
+            })
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use derive_new::new;
@@ -9,7 +10,8 @@ use r2d2_redis::RedisConnectionManager;
 use tracing::{debug, error};
 use uuid::Uuid;
 
-use crate::domain::constants::ONE_DAY;
+use crate::core::token::generate_token;
+use crate::domain::constants::SESSION_EXPIRE_SECONDS;
 use crate::domain::error::Error;
 use crate::domain::error::Result;
 use crate::domain::repositories::session::RedisSessionRepository;
@@ -19,50 +21,57 @@ pub struct RedisSessionRepositoryImpl {
     pool: Arc<Pool<RedisConnectionManager>>,
 }
 
+impl RedisSessionRepositoryImpl {
+    fn session_key(session_id: &str) -> String {
+        format!("session_id:{}", session_id)
+    }
+
+    fn user_sessions_key(user_id: &Uuid) -> String {
+        format!("user_sessions:{}", user_id)
+    }
+}
+
 impl RedisSessionRepository for RedisSessionRepositoryImpl {
     fn create(&self, user_id: &Uuid) -> Result<String> {
         let binding = self.pool.clone();
         let client = binding.deref();
-        let mut connection = client.get().unwrap();
-
-        match self.validate(&format!("session_id:{}", &user_id)) {
-            Ok(key) => Ok(key),
-            Err(_) => {
-                let exp = ONE_DAY * 3;
-                let key = format!("session_id:{}", user_id);
-                connection
-                    .set::<&str, String, ()>(
-                        &format!("session_id:{}", user_id),
-                        user_id.to_string(),
-                    )
-                    .expect("Failed to set session data");
-                connection
-                    .expire::<&str, ()>(&key, exp as usize)
-                    .expect("Failed to set expiration time");
-                Ok(user_id.to_string())
-            }
-        }
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let session_id = generate_token();
+        let key = Self::session_key(&session_id);
+        let user_sessions_key = Self::user_sessions_key(user_id);
+
+        connection
+            .set_ex::<&str, String, ()>(&key, user_id.to_string(), SESSION_EXPIRE_SECONDS as usize)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+        connection
+            .sadd::<&str, &str, ()>(&user_sessions_key, &session_id)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+        connection
+            .expire::<&str, ()>(&user_sessions_key, SESSION_EXPIRE_SECONDS as usize)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        Ok(session_id)
     }
 
-    fn validate(&self, session_id: &str) -> Result<String> {
+    fn validate(&self, session_id: &str) -> Result<Uuid> {
         debug!("Session validate id: {}", session_id);
         let binding = self.pool.clone();
         let client = binding.deref();
-        let mut connection = client.get().unwrap();
-        match connection.exists::<&str, i64>(session_id) {
-            Ok(value) => {
-                if value > 0 {
-                    match connection.get::<&str, String>(session_id) {
-                        Ok(_) => {
-                            let split_session = session_id.split(':').collect::<Vec<&str>>()[1];
-                            Ok(split_session.to_string())
-                        }
-                        Err(_) => Err(Error::RedisError),
-                    }
-                } else {
-                    Err(Error::RedisError)
-                }
-            }
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        match connection.get::<&str, Option<String>>(&Self::session_key(session_id)) {
+            Ok(Some(value)) => Uuid::from_str(&value).map_err(|_| Error::RedisError),
+            Ok(None) => Err(Error::RedisError),
             Err(e) => {
                 error!("{}", e);
                 Err(Error::RedisError)
@@ -70,32 +79,77 @@ impl RedisSessionRepository for RedisSessionRepositoryImpl {
         }
     }
 
-    fn session_expand(&self, session_id: &str) -> Result<()> {
-        if self.validate(session_id).is_err() {
-            return Err(Error::RedisError);
-        }
+    fn session_expand(&self, session_id: &str) -> Result<Uuid> {
+        let user_id = self.validate(session_id)?;
 
         let binding = self.pool.clone();
         let client = binding.deref();
-        let mut connection = client.get().unwrap();
-        let exp = ONE_DAY * 3;
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
         connection
-            .expire::<&str, ()>(session_id, exp as usize)
+            .expire::<&str, ()>(
+                &Self::session_key(session_id),
+                SESSION_EXPIRE_SECONDS as usize,
+            )
             .map_err(|e| {
                 error!("{:?}", e);
-            })
-            .expect("Failed to set expiration time");
-        Ok(())
+                Error::RedisError
+            })?;
+        connection
+            .expire::<&str, ()>(
+                &Self::user_sessions_key(&user_id),
+                SESSION_EXPIRE_SECONDS as usize,
+            )
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        Ok(user_id)
     }
 
     fn remove_session(&self, session_id: &str) -> Result<()> {
+        let user_id = self.validate(session_id)?;
+
         let binding = self.pool.clone();
         let client = binding.deref();
-        let mut connection = client.get().unwrap();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        connection
+            .srem::<&str, &str, ()>(&Self::user_sessions_key(&user_id), session_id)
+            .map_err(|_| Error::RedisError)?;
 
-        match connection.del::<&str, ()>(session_id) {
+        match connection.del::<&str, ()>(&Self::session_key(session_id)) {
             Ok(_) => Ok(()),
-            Err(e) => Err(Error::RedisError),
+            Err(_) => Err(Error::RedisError),
         }
     }
+
+    fn remove_user_sessions(&self, user_id: &Uuid, keep_session_id: Option<&str>) -> Result<()> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let user_sessions_key = Self::user_sessions_key(user_id);
+
+        let sessions = connection
+            .smembers::<&str, Vec<String>>(&user_sessions_key)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        for session_id in sessions
+            .iter()
+            .filter(|session_id| Some(session_id.as_str()) != keep_session_id)
+        {
+            connection
+                .del::<&str, ()>(&Self::session_key(session_id))
+                .map_err(|_| Error::RedisError)?;
+            connection
+                .srem::<&str, &str, ()>(&user_sessions_key, session_id)
+                .map_err(|_| Error::RedisError)?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,27 +1,83 @@
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use derive_new::new;
+use diesel::dsl::{count_star, AsSelect};
+use diesel::pg::Pg;
+use diesel::query_builder::{BoxedSqlQuery, SqlQuery};
+use diesel::sql_types::{BigInt, Bool, Nullable, Text, Uuid as UuidType};
 use diesel::{
-    insert_into, update, BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    delete, insert_into, sql_query, update, BelongingToDsl, BoolExpressionMethods,
+    BoxableExpression, Connection, ExpressionMethods, GroupedBy, JoinOnDsl, OptionalExtension,
+    PgConnection, PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
 };
 use tracing::error;
 use uuid::Uuid;
 
+use crate::core::dependency_graph::creates_cycle;
+use crate::core::ordering::insert_at;
 use crate::domain::error::Error;
 use crate::domain::error::Result;
+use crate::domain::models::task::board_column::BoardColumn;
 use crate::domain::models::task::task_assign::TaskAssign;
+use crate::domain::models::task::task_cursor::TaskCursor;
+use crate::domain::models::task::task_deadline::TaskDeadline;
+use crate::domain::models::task::task_dependency::TaskDependency;
+use crate::domain::models::task::task_filter::{TaskFilter, TaskSort, TaskSortField};
 use crate::domain::models::task::task_information::TaskInformation;
+use crate::domain::models::task::task_priority::TaskPriority;
+use crate::domain::models::task::task_search_result::TaskSearchResult;
 use crate::domain::models::task::task_status::TaskStatus;
+use crate::domain::models::team::team_label::TeamLabel;
 use crate::domain::models::user::user_information::UserInformation;
 use crate::domain::repositories::task::TaskRepository;
 use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::checklist_item::ChecklistItemDiesel;
 use crate::infrastructure::models::task_assign::TaskAssignDiesel;
+use crate::infrastructure::models::task_dependency::TaskDependencyDiesel;
 use crate::infrastructure::models::task_information::TaskInformationDiesel;
+use crate::infrastructure::models::task_label::TaskLabelDiesel;
+use crate::infrastructure::models::task_priority::TaskPriorityDiesel;
+use crate::infrastructure::models::task_search_result::TaskSearchResultDiesel;
 use crate::infrastructure::models::task_status::TaskStatusDiesel;
+use crate::infrastructure::models::team_label::TeamLabelDiesel;
 use crate::infrastructure::models::user_information::UserInformationDiesel;
 use crate::infrastructure::repositories::get_pool::GetPool;
 use crate::infrastructure::repositories::map_from::MapFrom;
-use crate::infrastructure::schema::user_information;
+use crate::infrastructure::schema::{
+    task_checklist_item, task_information, team_label, user_information,
+};
+
+type BoxedTaskQuery<'a> = task_information::BoxedQuery<'a, Pg, AsSelect<TaskInformationDiesel, Pg>>;
+
+type BoxedCondition = Box<dyn BoxableExpression<task_information::table, Pg, SqlType = Bool>>;
+
+macro_rules! order {
+    ($query:expr, $column:expr, $descending:expr) => {
+        if $descending {
+            $query.then_order_by($column.desc())
+        } else {
+            $query.then_order_by($column.asc())
+        }
+    };
+}
+
+macro_rules! compare {
+    ($column:expr, $value:expr, $ordering:expr) => {
+        match $ordering {
+            Ordering::Less => Box::new($column.lt($value)) as BoxedCondition,
+            Ordering::Equal => Box::new($column.eq($value)) as BoxedCondition,
+            Ordering::Greater => Box::new($column.gt($value)) as BoxedCondition,
+        }
+    };
+}
+
+/// Escapes the wildcards of a `like` pattern.
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
 
 #[derive(Clone, new)]
 pub struct TaskRepositoryImpl {
@@ -32,6 +88,356 @@ impl MapFrom for TaskRepositoryImpl {}
 
 impl GetPool for TaskRepositoryImpl {}
 
+impl TaskRepositoryImpl {
+    /// Narrows a task list to the filter and orders it, the id breaks ties so pages
+    /// are stable.
+    fn apply_filter<'a>(mut query: BoxedTaskQuery<'a>, filter: &TaskFilter) -> BoxedTaskQuery<'a> {
+        use crate::infrastructure::schema::task_information::{
+            created_timestamp, creator, description, end_timestamp, id, name, position, priority,
+            status, team_id,
+        };
+        use crate::infrastructure::schema::{task_assign, task_label, team_member};
+
+        if let Some(id_member) = filter.member_id {
+            query = query.filter(
+                team_id.eq_any(
+                    team_member::table
+                        .filter(team_member::user_id.eq(id_member))
+                        .select(team_member::team_id),
+                ),
+            );
+        }
+
+        if let Some(id_team) = filter.team_id {
+            query = query.filter(team_id.eq(id_team));
+        }
+
+        if !filter.statuses.is_empty() {
+            let statuses: Vec<TaskStatusDiesel> = filter
+                .statuses
+                .iter()
+                .cloned()
+                .map(TaskStatusDiesel::from)
+                .collect();
+
+            query = query.filter(status.eq_any(statuses));
+        }
+
+        if let Some(id_assignee) = filter.assignee_id {
+            query = query.filter(
+                id.eq_any(
+                    task_assign::table
+                        .filter(task_assign::user_id.eq(id_assignee))
+                        .select(task_assign::task_id),
+                ),
+            );
+        }
+
+        if let Some(id_creator) = filter.creator_id {
+            query = query.filter(creator.eq(id_creator));
+        }
+
+        if let Some(before) = filter.due_before {
+            query = query.filter(end_timestamp.lt(before));
+        }
+
+        if let Some(after) = filter.due_after {
+            query = query.filter(end_timestamp.gt(after));
+        }
+
+        if let Some(text) = &filter.text {
+            let pattern = format!("%{}%", escape_like(text));
+
+            query = query.filter(name.ilike(pattern.clone()).or(description.ilike(pattern)));
+        }
+
+        if !filter.priorities.is_empty() {
+            let priorities: Vec<TaskPriorityDiesel> = filter
+                .priorities
+                .iter()
+                .map(|task_priority| TaskPriorityDiesel::from(*task_priority))
+                .collect();
+
+            query = query.filter(priority.eq_any(priorities));
+        }
+
+        for id_label in &filter.label_ids {
+            query = query.filter(
+                id.eq_any(
+                    task_label::table
+                        .filter(task_label::label_id.eq(*id_label))
+                        .select(task_label::task_id),
+                ),
+            );
+        }
+
+        if filter.sort.is_empty() {
+            query = query.then_order_by(end_timestamp.asc());
+        }
+
+        for sort in &filter.sort {
+            query = match sort.field {
+                TaskSortField::EndTimestamp => order!(query, end_timestamp, sort.descending),
+                TaskSortField::CreatedTimestamp => {
+                    order!(query, created_timestamp, sort.descending)
+                }
+                TaskSortField::Priority => order!(query, priority, sort.descending),
+                TaskSortField::Name => order!(query, name, sort.descending),
+                TaskSortField::Status => order!(query, status, sort.descending),
+                TaskSortField::Position => order!(query, position, sort.descending),
+            };
+        }
+
+        query.then_order_by(id.asc())
+    }
+
+    /// Full-text search over the tasks in the teams of `member_id`. A task matches through
+    /// its name and description or through its best matching comment, comments rank
+    /// lower and only provide the snippet when the task itself doesn't match.
+    fn search_query<'a>(
+        member_id: &'a Uuid,
+        team_id: Option<&'a Uuid>,
+        text: &'a str,
+        limit: i64,
+    ) -> BoxedSqlQuery<'a, Pg, SqlQuery> {
+        sql_query(
+            "select t.id, t.name, t.description, t.created_timestamp, t.end_timestamp, t.status, \
+                    t.team_id, t.creator, t.parent_id, t.priority, t.column_id, t.position, \
+                    t.recurrence_rule, t.recurrence_index, t.recurrence_pending, \
+                    t.recurrence_anchor, \
+                    ts_rank(t.search_vector, query) + coalesce(c.rank, 0) as rank, \
+                    ts_headline('english', \
+                        case when t.search_vector @@ query \
+                            then t.name || ' ' || t.description else c.body end, \
+                        query, 'StartSel=<mark>, StopSel=</mark>, MaxFragments=2') as snippet \
+             from task_information t \
+             cross join websearch_to_tsquery('english', $1) query \
+             left join lateral ( \
+                 select tc.body, ts_rank(tc.search_vector, query) as rank \
+                 from task_comment tc \
+                 where tc.task_id = t.id and tc.search_vector @@ query \
+                 order by rank desc, tc.id \
+                 limit 1 \
+             ) c on true \
+             where (t.search_vector @@ query or c.body is not null) \
+               and t.team_id in (select team_id from team_member where user_id = $2) \
+               and ($3 is null or t.team_id = $3) \
+             order by rank desc, t.id \
+             limit $4",
+        )
+        .into_boxed()
+        .bind::<Text, _>(text)
+        .bind::<UuidType, _>(member_id)
+        .bind::<Nullable<UuidType>, _>(team_id.copied())
+        .bind::<BigInt, _>(limit)
+    }
+
+    /// Builds the query of one page: the filtered and sorted tasks ordered after `after`.
+    fn page_query<'a>(
+        filter: &TaskFilter,
+        after: Option<&TaskCursor>,
+        limit: i64,
+    ) -> BoxedTaskQuery<'a> {
+        use crate::infrastructure::schema::task_information::dsl::task_information;
+
+        let mut query = Self::apply_filter(
+            task_information
+                .select(TaskInformationDiesel::as_select())
+                .limit(limit)
+                .into_boxed(),
+            filter,
+        );
+
+        if let Some(cursor) = after {
+            query = query.filter(Self::after_cursor(&filter.sort, cursor));
+        }
+
+        query
+    }
+
+    /// Compares a sort field of a row with its value in `cursor`.
+    fn compare_field(
+        field: TaskSortField,
+        cursor: &TaskCursor,
+        ordering: Ordering,
+    ) -> BoxedCondition {
+        use crate::infrastructure::schema::task_information::{
+            created_timestamp, end_timestamp, name, position, priority, status,
+        };
+
+        match field {
+            TaskSortField::EndTimestamp => compare!(end_timestamp, cursor.end_timestamp, ordering),
+            TaskSortField::CreatedTimestamp => {
+                compare!(created_timestamp, cursor.created_timestamp, ordering)
+            }
+            TaskSortField::Priority => {
+                compare!(
+                    priority,
+                    TaskPriorityDiesel::from(cursor.priority),
+                    ordering
+                )
+            }
+            TaskSortField::Name => compare!(name, cursor.name.clone(), ordering),
+            TaskSortField::Status => {
+                compare!(
+                    status,
+                    TaskStatusDiesel::from(cursor.status.clone()),
+                    ordering
+                )
+            }
+            TaskSortField::Position => compare!(position, cursor.position, ordering),
+        }
+    }
+
+    /// Matches the rows ordered after `cursor`: the first sort field that differs
+    /// from the cursor decides, the id when all of them are equal.
+    fn after_cursor(sort: &[TaskSort], cursor: &TaskCursor) -> BoxedCondition {
+        use crate::infrastructure::schema::task_information::id;
+
+        let default_sort = [TaskSort {
+            field: TaskSortField::EndTimestamp,
+            descending: false,
+        }];
+        let sort = if sort.is_empty() {
+            &default_sort[..]
+        } else {
+            sort
+        };
+
+        let mut condition: BoxedCondition = Box::new(id.gt(cursor.id));
+
+        for key in sort.iter().rev() {
+            let after = if key.descending {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+
+            condition = Box::new(
+                Self::compare_field(key.field, cursor, after).or(Self::compare_field(
+                    key.field,
+                    cursor,
+                    Ordering::Equal,
+                )
+                .and(condition)),
+            );
+        }
+
+        condition
+    }
+
+    /// Loads the assigned users, the labels and the progress of the direct subtasks of a task.
+    /// Canceled subtasks don't count towards the progress.
+    fn with_details(
+        &self,
+        conn: &mut PgConnection,
+        task_info: &TaskInformationDiesel,
+    ) -> Result<TaskInformation> {
+        Ok(self
+            .with_details_all(conn, std::slice::from_ref(task_info))?
+            .remove(0))
+    }
+
+    /// Same as `with_details` for several tasks, with one query per detail instead of
+    /// several per task.
+    fn with_details_all(
+        &self,
+        conn: &mut PgConnection,
+        task_infos: &[TaskInformationDiesel],
+    ) -> Result<Vec<TaskInformation>> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            parent_id, status, task_information,
+        };
+
+        let task_ids: Vec<Uuid> = task_infos.iter().map(|task_info| task_info.id).collect();
+
+        let assignees = TaskAssignDiesel::belonging_to(task_infos)
+            .inner_join(user_information::table)
+            .select((
+                TaskAssignDiesel::as_select(),
+                UserInformationDiesel::as_select(),
+            ))
+            .load::<(TaskAssignDiesel, UserInformationDiesel)>(conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?
+            .grouped_by(task_infos);
+
+        let children_statuses = task_information
+            .filter(parent_id.eq_any(&task_ids))
+            .select((parent_id, status))
+            .load::<(Option<Uuid>, TaskStatusDiesel)>(conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        let labels = TaskLabelDiesel::belonging_to(task_infos)
+            .inner_join(team_label::table)
+            .select((TaskLabelDiesel::as_select(), TeamLabelDiesel::as_select()))
+            .order_by(team_label::name)
+            .load::<(TaskLabelDiesel, TeamLabelDiesel)>(conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?
+            .grouped_by(task_infos);
+
+        let checklist = ChecklistItemDiesel::belonging_to(task_infos)
+            .select((task_checklist_item::task_id, task_checklist_item::done))
+            .load::<(Uuid, bool)>(conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(task_infos
+            .iter()
+            .zip(assignees.into_iter().zip(labels))
+            .map(|(task_info, (task_assignees, task_labels))| {
+                let mut task = TaskInformation::from(task_info);
+
+                task.assigned_users = task_assignees
+                    .into_iter()
+                    .map(|(_, user)| UserInformation::from(user))
+                    .collect();
+
+                let children: Vec<&TaskStatusDiesel> = children_statuses
+                    .iter()
+                    .filter(|(child_parent_id, _)| *child_parent_id == Some(task_info.id))
+                    .map(|(_, child_status)| child_status)
+                    .collect();
+
+                task.children_total = children
+                    .iter()
+                    .filter(|child_status| ***child_status != TaskStatusDiesel::Canceled)
+                    .count() as i64;
+                task.children_finished = children
+                    .iter()
+                    .filter(|child_status| ***child_status == TaskStatusDiesel::Finished)
+                    .count() as i64;
+
+                task.labels = task_labels
+                    .into_iter()
+                    .map(|(_, label)| TeamLabel::from(label))
+                    .collect();
+
+                let items: Vec<bool> = checklist
+                    .iter()
+                    .filter(|(item_task_id, _)| *item_task_id == task_info.id)
+                    .map(|(_, done)| *done)
+                    .collect();
+
+                task.checklist_total = items.len() as i64;
+                task.checklist_done = items.iter().filter(|done| **done).count() as i64;
+
+                task
+            })
+            .collect())
+    }
+}
+
 impl TaskRepository for TaskRepositoryImpl {
     fn get(&self, task_id: &Uuid) -> Result<TaskInformation> {
         use crate::infrastructure::schema::task_information::dsl::task_information;
@@ -45,19 +451,7 @@ impl TaskRepository for TaskRepositoryImpl {
             .first(&mut conn);
 
         match result {
-            Ok(value) => {
-                let query = TaskAssignDiesel::belonging_to(&value)
-                    .inner_join(user_information::table)
-                    .select(UserInformationDiesel::as_select())
-                    .load(&mut conn);
-
-                let users: Vec<UserInformation> = self.map_from(query).unwrap();
-
-                let mut task_info = TaskInformation::from(value);
-                task_info.assigned_users = users;
-
-                Ok(task_info)
-            }
+            Ok(value) => self.with_details(&mut conn, &value),
             Err(e) => {
                 error!("{:?}", e);
                 Err(Error::RepositoryError)
@@ -65,39 +459,20 @@ impl TaskRepository for TaskRepositoryImpl {
         }
     }
 
-    fn get_all(&self) -> Result<Vec<TaskInformation>> {
-        use crate::infrastructure::schema::task_information::dsl::name;
-        use crate::infrastructure::schema::task_information::dsl::task_information;
-
+    fn query(
+        &self,
+        filter: &TaskFilter,
+        after: Option<&TaskCursor>,
+        limit: i64,
+    ) -> Result<Vec<TaskInformation>> {
         let mut conn = Self::get_pool(&self.pool).unwrap();
 
-        let result = task_information
-            .select(TaskInformationDiesel::as_select())
-            .limit(1000)
-            .order_by(name)
-            .load(&mut conn);
-
-        match result {
-            Ok(value) => {
-                let tasks: Vec<TaskInformation> = value
-                    .iter()
-                    .map(|task_info| {
-                        let mut task = TaskInformation::from(task_info);
-
-                        let query = TaskAssignDiesel::belonging_to(&task_info)
-                            .inner_join(user_information::table)
-                            .select(UserInformationDiesel::as_select())
-                            .load(&mut conn);
+        let query = Self::page_query(filter, after, limit);
 
-                        let users: Vec<UserInformation> = self.map_from(query).unwrap();
-
-                        task.assigned_users = users;
-                        task
-                    })
-                    .collect();
+        let result = query.load(&mut conn);
 
-                Ok(tasks)
-            }
+        match result {
+            Ok(value) => self.with_details_all(&mut conn, &value),
             Err(e) => {
                 error!("{:?}", e);
                 Err(Error::RepositoryError)
@@ -105,39 +480,35 @@ impl TaskRepository for TaskRepositoryImpl {
         }
     }
 
-    fn get_all_for_team(&self, id_team: &Uuid) -> Result<Vec<TaskInformation>> {
-        use crate::infrastructure::schema::task_information::dsl::task_information;
-        use crate::infrastructure::schema::task_information::dsl::team_id;
-        use crate::infrastructure::schema::task_information::end_timestamp;
+    fn search(
+        &self,
+        member_id: &Uuid,
+        team_id: Option<&Uuid>,
+        text: &str,
+        limit: i64,
+    ) -> Result<Vec<TaskSearchResult>> {
         let mut conn = Self::get_pool(&self.pool).unwrap();
 
-        let result = task_information
-            .select(TaskInformationDiesel::as_select())
-            .limit(1000)
-            .filter(team_id.eq(id_team))
-            .order_by(end_timestamp)
-            .load(&mut conn);
+        let result = Self::search_query(member_id, team_id, text, limit)
+            .load::<TaskSearchResultDiesel>(&mut conn);
 
         match result {
             Ok(value) => {
-                let tasks: Vec<TaskInformation> = value
-                    .iter()
-                    .map(|task_info| {
-                        let mut task = TaskInformation::from(task_info);
-
-                        let query = TaskAssignDiesel::belonging_to(&task_info)
-                            .inner_join(user_information::table)
-                            .select(UserInformationDiesel::as_select())
-                            .load(&mut conn);
-
-                        let users: Vec<UserInformation> = self.map_from(query).unwrap();
+                let (task_infos, hits): (Vec<TaskInformationDiesel>, Vec<(f32, String)>) = value
+                    .into_iter()
+                    .map(|found| (found.task, (found.rank, found.snippet)))
+                    .unzip();
 
-                        task.assigned_users = users;
-                        task
+                Ok(self
+                    .with_details_all(&mut conn, &task_infos)?
+                    .into_iter()
+                    .zip(hits)
+                    .map(|(task, (rank, snippet))| TaskSearchResult {
+                        task,
+                        rank,
+                        snippet,
                     })
-                    .collect();
-
-                Ok(tasks)
+                    .collect())
             }
             Err(e) => {
                 error!("{:?}", e);
@@ -146,54 +517,20 @@ impl TaskRepository for TaskRepositoryImpl {
         }
     }
 
-    fn get_all_for_user(&self, id_user: &Uuid) -> Result<Vec<TaskInformation>> {
-        use crate::infrastructure::schema::task_assign::dsl::task_assign;
-        use crate::infrastructure::schema::task_assign::dsl::user_id;
-        use crate::infrastructure::schema::task_information::dsl::id;
+    fn get_all_created_by(&self, user_id: &Uuid) -> Result<Vec<TaskInformation>> {
+        use crate::infrastructure::schema::task_information::dsl::creator;
         use crate::infrastructure::schema::task_information::dsl::task_information;
         use crate::infrastructure::schema::task_information::end_timestamp;
         let mut conn = Self::get_pool(&self.pool).unwrap();
 
-        let assigned_result = task_assign
-            .select(TaskAssignDiesel::as_select())
-            .filter(user_id.eq(id_user))
-            .load(&mut conn);
-
-        let task_assigned: Vec<Uuid> = match assigned_result {
-            Ok(value) => value.iter().map(|e| e.task_id).collect(),
-            Err(e) => {
-                error!("{:?}", e);
-                return Err(Error::RepositoryError);
-            }
-        };
-
         let result = task_information
             .select(TaskInformationDiesel::as_select())
-            .limit(1000)
-            .filter(id.eq_any(task_assigned))
+            .filter(creator.eq(user_id))
             .order_by(end_timestamp)
             .load(&mut conn);
 
         match result {
-            Ok(value) => {
-                let tasks: Vec<TaskInformation> = value
-                    .iter()
-                    .map(|task_info| {
-                        let mut task = TaskInformation::from(task_info);
-                        let query = TaskAssignDiesel::belonging_to(&task_info)
-                            .inner_join(user_information::table)
-                            .select(UserInformationDiesel::as_select())
-                            .load(&mut conn);
-
-                        let users: Vec<UserInformation> = self.map_from(query).unwrap();
-
-                        task.assigned_users = users;
-                        task
-                    })
-                    .collect();
-
-                Ok(tasks)
-            }
+            Ok(value) => self.with_details_all(&mut conn, &value),
             Err(e) => {
                 error!("{:?}", e);
                 Err(Error::RepositoryError)
@@ -209,6 +546,7 @@ impl TaskRepository for TaskRepositoryImpl {
 
         let team_result = insert_into(task_information)
             .values(new_task)
+            .returning(TaskInformationDiesel::as_returning())
             .get_result::<TaskInformationDiesel>(&mut conn);
 
         match team_result {
@@ -220,8 +558,9 @@ impl TaskRepository for TaskRepositoryImpl {
         }
     }
 
-    fn assign(&self, new_task_assign: &TaskAssign) -> Result<TaskAssign> {
+    fn assign(&self, new_task_assign: &TaskAssign, start: bool) -> Result<TaskAssign> {
         use crate::infrastructure::schema::task_assign::dsl::task_assign;
+        use crate::infrastructure::schema::task_information::dsl::column_id;
         use crate::infrastructure::schema::task_information::dsl::id;
         use crate::infrastructure::schema::task_information::dsl::status;
         use crate::infrastructure::schema::task_information::dsl::task_information;
@@ -234,10 +573,16 @@ impl TaskRepository for TaskRepositoryImpl {
             .get_result::<TaskAssignDiesel>(&mut conn);
 
         match task_assign_result {
+            Ok(assigned) if !start => Ok(TaskAssign::from(assigned)),
             Ok(assigned) => {
+                // Leaving the board column lets the task show up under its new status.
                 let update_status_result = update(task_information)
                     .filter(id.eq(assigned.task_id))
-                    .set(status.eq(TaskStatusDiesel::from(TaskStatus::InProgress)))
+                    .filter(status.ne(TaskStatusDiesel::from(TaskStatus::InProgress)))
+                    .set((
+                        status.eq(TaskStatusDiesel::from(TaskStatus::InProgress)),
+                        column_id.eq(None::<Uuid>),
+                    ))
                     .execute(&mut conn);
 
                 match update_status_result {
@@ -256,6 +601,7 @@ impl TaskRepository for TaskRepositoryImpl {
     }
 
     fn complete(&self, task_id: &Uuid) -> Result<TaskInformation> {
+        use crate::infrastructure::schema::task_information::dsl::column_id;
         use crate::infrastructure::schema::task_information::dsl::id;
         use crate::infrastructure::schema::task_information::dsl::status;
         use crate::infrastructure::schema::task_information::dsl::task_information;
@@ -264,7 +610,11 @@ impl TaskRepository for TaskRepositoryImpl {
 
         let task = update(task_information)
             .filter(id.eq(task_id))
-            .set(status.eq(TaskStatusDiesel::from(TaskStatus::Finished)))
+            .set((
+                status.eq(TaskStatusDiesel::from(TaskStatus::Finished)),
+                column_id.eq(None::<Uuid>),
+            ))
+            .returning(TaskInformationDiesel::as_returning())
             .get_result::<TaskInformationDiesel>(&mut conn)
             .map_err(|e| {
                 error!("{:?}", e);
@@ -273,4 +623,600 @@ impl TaskRepository for TaskRepositoryImpl {
 
         Ok(TaskInformation::from(task))
     }
+
+    fn get_children(&self, id_parent: &Uuid) -> Result<Vec<TaskInformation>> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            end_timestamp, parent_id, task_information,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let result = task_information
+            .select(TaskInformationDiesel::as_select())
+            .filter(parent_id.eq(id_parent))
+            .order_by(end_timestamp)
+            .load(&mut conn);
+
+        match result {
+            Ok(value) => self.with_details_all(&mut conn, &value),
+            Err(e) => {
+                error!("{:?}", e);
+                Err(Error::RepositoryError)
+            }
+        }
+    }
+
+    fn set_parent(
+        &self,
+        task_id: &Uuid,
+        new_parent_id: Option<&Uuid>,
+        id_team: &Uuid,
+    ) -> Result<TaskInformation> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            id, parent_id, task_information,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+        let mut cycle = false;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // Takes the lock of the team's dependency changes, so two concurrent moves
+            // can't form a cycle together either.
+            sql_query("select pg_advisory_xact_lock(hashtext($1))")
+                .bind::<Text, _>(id_team.to_string())
+                .execute(conn)?;
+
+            if let Some(new_parent_id) = new_parent_id {
+                let mut current = Some(*new_parent_id);
+                let mut visited = Vec::new();
+
+                // The chain is kept acyclic here, `visited` only guards the loop.
+                while let Some(ancestor) = current {
+                    if ancestor == *task_id {
+                        cycle = true;
+                        return Err(diesel::result::Error::RollbackTransaction);
+                    }
+
+                    if visited.contains(&ancestor) {
+                        break;
+                    }
+                    visited.push(ancestor);
+
+                    current = task_information
+                        .filter(id.eq(ancestor))
+                        .select(parent_id)
+                        .first::<Option<Uuid>>(conn)?;
+                }
+            }
+
+            update(task_information)
+                .filter(id.eq(task_id))
+                .set(parent_id.eq(new_parent_id.copied()))
+                .returning(TaskInformationDiesel::as_returning())
+                .get_result::<TaskInformationDiesel>(conn)
+        });
+
+        match result {
+            Ok(task) => self.with_details(&mut conn, &task),
+            Err(e) => {
+                if cycle {
+                    return Err(Error::DependencyCycleError);
+                }
+
+                error!("{:?}", e);
+                Err(Error::RepositoryError)
+            }
+        }
+    }
+
+    fn set_priority(&self, task_id: &Uuid, new_priority: TaskPriority) -> Result<TaskInformation> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            id, priority, task_information,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let task = update(task_information)
+            .filter(id.eq(task_id))
+            .set(priority.eq(TaskPriorityDiesel::from(new_priority)))
+            .returning(TaskInformationDiesel::as_returning())
+            .get_result::<TaskInformationDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        self.with_details(&mut conn, &task)
+    }
+
+    fn move_to_column(
+        &self,
+        task_id: &Uuid,
+        column: &BoardColumn,
+        new_position: i32,
+    ) -> Result<TaskInformation> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            column_id, id, position, status, task_information,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // Serializes moves into a column, so two concurrent moves can't take the
+            // same position.
+            sql_query("select pg_advisory_xact_lock(hashtext($1))")
+                .bind::<Text, _>(column.id.to_string())
+                .execute(conn)?;
+
+            let mut ordered = task_information
+                .filter(column_id.eq(column.id))
+                .filter(id.ne(task_id))
+                .select(id)
+                .order_by((position, id))
+                .load::<Uuid>(conn)?;
+
+            insert_at(&mut ordered, new_position, *task_id);
+
+            for (index, ordered_id) in ordered.iter().enumerate() {
+                update(task_information)
+                    .filter(id.eq(ordered_id))
+                    .set(position.eq(index as i32))
+                    .execute(conn)?;
+            }
+
+            update(task_information)
+                .filter(id.eq(task_id))
+                .set((
+                    column_id.eq(column.id),
+                    status.eq(TaskStatusDiesel::from(column.status.clone())),
+                ))
+                .returning(TaskInformationDiesel::as_returning())
+                .get_result::<TaskInformationDiesel>(conn)
+        });
+
+        match result {
+            Ok(task) => self.with_details(&mut conn, &task),
+            Err(e) => {
+                error!("{:?}", e);
+                Err(Error::RepositoryError)
+            }
+        }
+    }
+
+    fn set_recurrence(&self, task_id: &Uuid, rule: Option<&str>) -> Result<TaskInformation> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            end_timestamp, id, recurrence_anchor, recurrence_index, recurrence_pending,
+            recurrence_rule, task_information,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let task = update(task_information)
+            .filter(id.eq(task_id))
+            .set((
+                recurrence_rule.eq(rule),
+                recurrence_pending.eq(rule.is_some()),
+                // Setting a rule starts a new series at this task.
+                rule.map(|_| {
+                    (
+                        recurrence_index.eq(0),
+                        recurrence_anchor.eq(end_timestamp.nullable()),
+                    )
+                }),
+            ))
+            .returning(TaskInformationDiesel::as_returning())
+            .get_result::<TaskInformationDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        self.with_details(&mut conn, &task)
+    }
+
+    fn get_recurrences_due(&self, now: i64) -> Result<Vec<TaskInformation>> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            end_timestamp, recurrence_pending, status, task_information,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = task_information
+            .select(TaskInformationDiesel::as_select())
+            .filter(recurrence_pending.eq(true))
+            .filter(status.ne(TaskStatusDiesel::Canceled))
+            .filter(
+                status
+                    .eq(TaskStatusDiesel::Finished)
+                    .or(end_timestamp.lt(now)),
+            )
+            .order_by(end_timestamp)
+            .limit(100)
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn create_next_occurrence(
+        &self,
+        previous_id: &Uuid,
+        next: Option<&TaskInformation>,
+    ) -> Result<bool> {
+        use crate::infrastructure::schema::{task_assign, task_checklist_item, task_information};
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // The row lock keeps two workers from creating the same occurrence.
+            let pending = task_information::table
+                .filter(task_information::id.eq(previous_id))
+                .filter(task_information::recurrence_pending.eq(true))
+                .select(task_information::id)
+                .for_update()
+                .first::<Uuid>(conn)
+                .optional()?;
+
+            if pending.is_none() {
+                return Ok(false);
+            }
+
+            if let Some(next) = next {
+                insert_into(task_information::table)
+                    .values(TaskInformationDiesel::from(next.clone()))
+                    .execute(conn)?;
+
+                let assignees = task_assign::table
+                    .filter(task_assign::task_id.eq(previous_id))
+                    .select(task_assign::user_id)
+                    .load::<Uuid>(conn)?;
+
+                for assignee in assignees {
+                    insert_into(task_assign::table)
+                        .values(TaskAssignDiesel::from(TaskAssign {
+                            id: Uuid::now_v7(),
+                            task_id: next.id,
+                            user_id: assignee,
+                        }))
+                        .execute(conn)?;
+                }
+
+                // The checklist starts over in every occurrence.
+                let checklist = task_checklist_item::table
+                    .filter(task_checklist_item::task_id.eq(previous_id))
+                    .select(ChecklistItemDiesel::as_select())
+                    .load::<ChecklistItemDiesel>(conn)?;
+
+                for item in checklist {
+                    insert_into(task_checklist_item::table)
+                        .values(ChecklistItemDiesel {
+                            id: Uuid::now_v7(),
+                            task_id: next.id,
+                            done: false,
+                            ..item
+                        })
+                        .execute(conn)?;
+                }
+            }
+
+            update(task_information::table)
+                .filter(task_information::id.eq(previous_id))
+                .set(task_information::recurrence_pending.eq(false))
+                .execute(conn)?;
+
+            Ok(true)
+        });
+
+        result.map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+
+    fn get_deadlines_between(&self, from: i64, until: i64) -> Result<Vec<TaskDeadline>> {
+        use crate::infrastructure::schema::{task_information, task_reminder};
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let tasks = task_information::table
+                .filter(
+                    task_information::status
+                        .eq_any(vec![TaskStatusDiesel::InProgress, TaskStatusDiesel::Paused]),
+                )
+                .filter(task_information::end_timestamp.gt(from))
+                .filter(task_information::end_timestamp.le(until))
+                .select((
+                    task_information::id,
+                    task_information::name,
+                    task_information::creator,
+                    task_information::end_timestamp,
+                ))
+                .order_by(task_information::end_timestamp)
+                .load::<(Uuid, String, Uuid, i64)>(conn)?;
+
+            let reminders = task_reminder::table
+                .filter(
+                    task_reminder::task_id
+                        .eq_any(tasks.iter().map(|task| task.0).collect::<Vec<_>>()),
+                )
+                .select((
+                    task_reminder::task_id,
+                    task_reminder::end_timestamp,
+                    task_reminder::offset_seconds,
+                ))
+                .load::<(Uuid, i64, i64)>(conn)?;
+
+            Ok((tasks, reminders))
+        });
+
+        let (tasks, reminders) = result.map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })?;
+
+        Ok(tasks
+            .into_iter()
+            .map(
+                |(task_id, task_name, task_creator, task_end)| TaskDeadline {
+                    id: task_id,
+                    name: task_name,
+                    creator: task_creator,
+                    end_timestamp: task_end,
+                    // Reminders of an earlier end timestamp don't count for the new one.
+                    last_reminder_offset: reminders
+                        .iter()
+                        .filter(|(reminder_task, reminder_end, _)| {
+                            *reminder_task == task_id && *reminder_end == task_end
+                        })
+                        .map(|(_, _, offset)| *offset)
+                        .min(),
+                },
+            )
+            .collect())
+    }
+
+    fn get_assignees(&self, id_task: &Uuid) -> Result<Vec<UserInformation>> {
+        use crate::infrastructure::schema::task_assign;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = task_assign::table
+            .filter(task_assign::task_id.eq(id_task))
+            .inner_join(user_information::table)
+            .select(UserInformationDiesel::as_select())
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn count_open_by_priority(&self) -> Result<Vec<(TaskPriority, i64)>> {
+        use crate::infrastructure::schema::task_information::dsl::{
+            priority, status, task_information,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let counts = task_information
+            .filter(status.eq_any(vec![TaskStatusDiesel::InProgress, TaskStatusDiesel::Paused]))
+            .group_by(priority)
+            .select((priority, count_star()))
+            .load::<(TaskPriorityDiesel, i64)>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(counts
+            .into_iter()
+            .map(|(task_priority, count)| (TaskPriority::from(task_priority), count))
+            .collect())
+    }
+
+    fn add_dependency(&self, new_dependency: &TaskDependency, id_team: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::{task_dependency, task_information};
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+        let mut cycle = false;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // Serializes dependency changes of a team, so two concurrent inserts can't
+            // form a cycle together.
+            sql_query("select pg_advisory_xact_lock(hashtext($1))")
+                .bind::<Text, _>(id_team.to_string())
+                .execute(conn)?;
+
+            let dependencies = task_dependency::table
+                .inner_join(
+                    task_information::table.on(task_information::id.eq(task_dependency::task_id)),
+                )
+                .filter(task_information::team_id.eq(id_team))
+                .select((task_dependency::task_id, task_dependency::blocker_id))
+                .load::<(Uuid, Uuid)>(conn)?;
+
+            if creates_cycle(
+                new_dependency.task_id,
+                new_dependency.blocker_id,
+                &dependencies,
+            ) {
+                cycle = true;
+                return Err(diesel::result::Error::RollbackTransaction);
+            }
+
+            insert_into(task_dependency::table)
+                .values(TaskDependencyDiesel::from(new_dependency.clone()))
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            Ok(())
+        });
+
+        result.map_err(|e| {
+            if cycle {
+                return Error::DependencyCycleError;
+            }
+
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+
+    fn remove_dependency(&self, id_task: &Uuid, id_blocker: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::task_dependency::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let removed = delete(task_dependency)
+            .filter(task_id.eq(id_task))
+            .filter(blocker_id.eq(id_blocker))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        if removed == 0 {
+            return Err(Error::RepositoryError);
+        }
+
+        Ok(())
+    }
+
+    fn get_blockers(&self, id_task: &Uuid) -> Result<Vec<TaskInformation>> {
+        use crate::infrastructure::schema::{task_dependency, task_information};
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let result = task_dependency::table
+            .inner_join(
+                task_information::table.on(task_information::id.eq(task_dependency::blocker_id)),
+            )
+            .filter(task_dependency::task_id.eq(id_task))
+            .select(TaskInformationDiesel::as_select())
+            .order_by(task_information::end_timestamp)
+            .load(&mut conn);
+
+        match result {
+            Ok(value) => self.with_details_all(&mut conn, &value),
+            Err(e) => {
+                error!("{:?}", e);
+                Err(Error::RepositoryError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::debug_query;
+
+    use super::*;
+
+    fn cursor() -> TaskCursor {
+        TaskCursor {
+            id: Uuid::now_v7(),
+            end_timestamp: 1_800_000_000,
+            created_timestamp: 1_700_000_000,
+            priority: TaskPriority::Medium,
+            name: String::from("Deploy"),
+            status: TaskStatus::Paused,
+            position: 2,
+        }
+    }
+
+    fn page_sql(filter: &TaskFilter, after: Option<&TaskCursor>) -> String {
+        debug_query::<Pg, _>(&TaskRepositoryImpl::page_query(filter, after, 21)).to_string()
+    }
+
+    #[test]
+    fn test_page_query_is_limited_to_member_teams() {
+        let member_id = Uuid::now_v7();
+        let filter = TaskFilter {
+            member_id: Some(member_id),
+            ..Default::default()
+        };
+
+        let sql = page_sql(&filter, None);
+
+        assert!(sql.contains("\"team_member\".\"user_id\" = $"));
+        assert!(sql.contains(&format!("{:?}", member_id)));
+        assert!(sql.contains(
+            "ORDER BY \"task_information\".\"end_timestamp\" ASC, \"task_information\".\"id\" ASC"
+        ));
+    }
+
+    #[test]
+    fn test_page_query_compares_cursor_values() {
+        let cursor = cursor();
+        let filter = TaskFilter {
+            member_id: Some(Uuid::now_v7()),
+            sort: vec![TaskSort {
+                field: TaskSortField::Name,
+                descending: false,
+            }],
+            ..Default::default()
+        };
+
+        let sql = page_sql(&filter, Some(&cursor));
+
+        assert!(sql.contains("\"task_information\".\"name\" > $"));
+        assert!(sql.contains("\"task_information\".\"name\" = $"));
+        assert!(sql.contains("\"task_information\".\"id\" > $"));
+        assert!(sql.contains("\"Deploy\""));
+        assert!(sql.contains(&format!("{:?}", cursor.id)));
+        // The cursor carries its values, the cursor task itself is never read.
+        assert_eq!(sql.matches("FROM \"task_information\"").count(), 1);
+    }
+
+    #[test]
+    fn test_page_query_descending_cursor() {
+        let filter = TaskFilter {
+            sort: vec![TaskSort {
+                field: TaskSortField::Priority,
+                descending: true,
+            }],
+            ..Default::default()
+        };
+
+        let sql = page_sql(&filter, Some(&cursor()));
+
+        assert!(sql.contains("\"task_information\".\"priority\" < $"));
+        assert!(sql.contains("\"task_information\".\"id\" > $"));
+    }
+
+    #[test]
+    fn test_search_query_matches_tasks_and_comments() {
+        let member_id = Uuid::now_v7();
+
+        let sql = debug_query::<Pg, _>(&TaskRepositoryImpl::search_query(
+            &member_id,
+            None,
+            "release notes",
+            20,
+        ))
+        .to_string();
+
+        assert!(sql.contains("websearch_to_tsquery('english', $1)"));
+        assert!(sql.contains("from task_comment tc"));
+        assert!(sql.contains("where (t.search_vector @@ query or c.body is not null)"));
+        assert!(sql.contains("select team_id from team_member where user_id = $2"));
+        assert!(sql.contains("\"release notes\""));
+        assert!(sql.contains(&format!("{:?}", member_id)));
+    }
+
+    #[test]
+    fn test_search_query_scopes_to_team() {
+        let member_id = Uuid::now_v7();
+        let team_id = Uuid::now_v7();
+
+        let sql = debug_query::<Pg, _>(&TaskRepositoryImpl::search_query(
+            &member_id,
+            Some(&team_id),
+            "deploy",
+            5,
+        ))
+        .to_string();
+
+        assert!(sql.contains("($3 is null or t.team_id = $3)"));
+        assert!(sql.contains(&format!("Some({:?})", team_id)));
+    }
 }
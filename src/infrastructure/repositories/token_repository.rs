@@ -0,0 +1,75 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use derive_new::new;
+use r2d2_redis::r2d2::Pool;
+use r2d2_redis::redis;
+use r2d2_redis::redis::Commands;
+use r2d2_redis::RedisConnectionManager;
+use tracing::error;
+
+use crate::core::token::{generate_token, hash_token};
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::repositories::token::RedisTokenRepository;
+
+#[derive(new, Clone, Debug)]
+pub struct RedisTokenRepositoryImpl {
+    pool: Arc<Pool<RedisConnectionManager>>,
+}
+
+impl RedisTokenRepository for RedisTokenRepositoryImpl {
+    fn create(&self, purpose: &str, value: &str, expire_seconds: i64) -> Result<String> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let token = generate_token();
+        let key = format!("{}:{}", purpose, hash_token(&token));
+
+        connection
+            .set_ex::<&str, &str, ()>(&key, value, expire_seconds as usize)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        Ok(token)
+    }
+
+    fn peek(&self, purpose: &str, token: &str) -> Result<String> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let key = format!("{}:{}", purpose, hash_token(token));
+
+        let value: Option<String> = connection.get(&key).map_err(|e| {
+            error!("{:?}", e);
+            Error::RedisError
+        })?;
+
+        value.ok_or(Error::RedisError)
+    }
+
+    fn consume(&self, purpose: &str, token: &str) -> Result<String> {
+        let binding = self.pool.clone();
+        let client = binding.deref();
+        let mut connection = client.get().map_err(|_| Error::RedisError)?;
+
+        let key = format!("{}:{}", purpose, hash_token(token));
+
+        let (value, _): (Option<String>, i64) = redis::pipe()
+            .atomic()
+            .get(&key)
+            .del(&key)
+            .query(&mut *connection)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RedisError
+            })?;
+
+        value.ok_or(Error::RedisError)
+    }
+}
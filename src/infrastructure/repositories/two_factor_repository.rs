@@ -0,0 +1,241 @@
+use std::sync::Arc;
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use derive_new::new;
+use diesel::{
+    delete, insert_into, update, BoolExpressionMethods, Connection, ExpressionMethods, QueryDsl,
+    RunQueryDsl, SelectableHelper,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::secret_cipher::SecretCipher;
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::user::two_factor_information::TwoFactorInformation;
+use crate::domain::repositories::two_factor::TwoFactorRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::user_recovery_code::UserRecoveryCodeDiesel;
+use crate::infrastructure::models::user_two_factor::UserTwoFactorDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+
+#[derive(Clone, new)]
+pub struct TwoFactorRepositoryImpl<'a> {
+    pool: Arc<DBConn>,
+    argon2: Arc<Argon2<'a>>,
+    cipher: Arc<SecretCipher>,
+}
+
+impl GetPool for TwoFactorRepositoryImpl<'_> {}
+
+impl TwoFactorRepositoryImpl<'_> {
+    fn decrypt(&self, two_factor: UserTwoFactorDiesel) -> Result<TwoFactorInformation> {
+        let secret = self.cipher.decrypt(&two_factor.secret).ok_or_else(|| {
+            error!(
+                "Can't decrypt the two-factor secret of {}",
+                two_factor.user_id
+            );
+            Error::RepositoryError
+        })?;
+
+        Ok(TwoFactorInformation {
+            secret,
+            ..TwoFactorInformation::from(two_factor)
+        })
+    }
+}
+
+impl TwoFactorRepository for TwoFactorRepositoryImpl<'_> {
+    fn get(&self, id_user: &Uuid) -> Result<TwoFactorInformation> {
+        use crate::infrastructure::schema::user_two_factor::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let two_factor = user_two_factor
+            .filter(user_id.eq(id_user))
+            .select(UserTwoFactorDiesel::as_select())
+            .first(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        if SecretCipher::is_encrypted(&two_factor.secret) {
+            return self.decrypt(two_factor);
+        }
+
+        // Secrets stored before they were encrypted are encrypted on first use.
+        update(user_two_factor)
+            .filter(user_id.eq(id_user))
+            .set(secret.eq(self.cipher.encrypt(&two_factor.secret)))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(TwoFactorInformation::from(two_factor))
+    }
+
+    fn create(
+        &self,
+        two_factor_information: &TwoFactorInformation,
+    ) -> Result<TwoFactorInformation> {
+        use crate::infrastructure::schema::user_two_factor::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let new_two_factor = UserTwoFactorDiesel {
+            secret: self.cipher.encrypt(&two_factor_information.secret),
+            ..UserTwoFactorDiesel::from(two_factor_information.clone())
+        };
+
+        let created = insert_into(user_two_factor)
+            .values(&new_two_factor)
+            .on_conflict(user_id)
+            .do_update()
+            .set(&new_two_factor)
+            .get_result::<UserTwoFactorDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        self.decrypt(created)
+    }
+
+    fn confirm(&self, id_user: &Uuid, recovery_codes: &[String]) -> Result<()> {
+        use crate::infrastructure::schema::user_recovery_code::dsl as recovery_code_dsl;
+        use crate::infrastructure::schema::user_two_factor::dsl as two_factor_dsl;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let hashed_codes = recovery_codes
+            .iter()
+            .map(|code| {
+                let salt = SaltString::generate(&mut OsRng);
+
+                match self.argon2.hash_password(code.as_bytes(), &salt) {
+                    Ok(hash) => Ok(UserRecoveryCodeDiesel {
+                        id: Uuid::now_v7(),
+                        user_id: *id_user,
+                        code_hash: hash.to_string(),
+                    }),
+                    Err(e) => {
+                        error!("{:?}", e);
+                        Err(Error::RepositoryError)
+                    }
+                }
+            })
+            .collect::<Result<Vec<UserRecoveryCodeDiesel>>>()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            update(two_factor_dsl::user_two_factor)
+                .filter(two_factor_dsl::user_id.eq(id_user))
+                .set(two_factor_dsl::confirmed.eq(true))
+                .execute(conn)?;
+
+            delete(recovery_code_dsl::user_recovery_code)
+                .filter(recovery_code_dsl::user_id.eq(id_user))
+                .execute(conn)?;
+
+            insert_into(recovery_code_dsl::user_recovery_code)
+                .values(&hashed_codes)
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+
+    fn use_step(&self, id_user: &Uuid, step: i64) -> Result<bool> {
+        use crate::infrastructure::schema::user_two_factor::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        // A single conditional update, so two logins can't both accept the same code.
+        let updated = update(user_two_factor)
+            .filter(user_id.eq(id_user))
+            .filter(last_used_step.is_null().or(last_used_step.lt(step)))
+            .set(last_used_step.eq(step))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(updated > 0)
+    }
+
+    fn use_recovery_code(&self, id_user: &Uuid, recovery_code: &str) -> Result<()> {
+        use crate::infrastructure::schema::user_recovery_code::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let codes = user_recovery_code
+            .filter(user_id.eq(id_user))
+            .select(UserRecoveryCodeDiesel::as_select())
+            .load(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        let matched = codes
+            .iter()
+            .find(|code| {
+                PasswordHash::new(&code.code_hash)
+                    .map(|hash| {
+                        self.argon2
+                            .verify_password(recovery_code.as_bytes(), &hash)
+                            .is_ok()
+                    })
+                    .unwrap_or(false)
+            })
+            .ok_or(Error::RepositoryError)?;
+
+        let deleted = delete(user_recovery_code)
+            .filter(id.eq(matched.id))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        // Only the request that deletes the code may use it, a concurrent one finds
+        // it already gone.
+        if deleted != 1 {
+            return Err(Error::RepositoryError);
+        }
+
+        Ok(())
+    }
+
+    fn remove(&self, id_user: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::user_recovery_code::dsl as recovery_code_dsl;
+        use crate::infrastructure::schema::user_two_factor::dsl as two_factor_dsl;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            delete(recovery_code_dsl::user_recovery_code)
+                .filter(recovery_code_dsl::user_id.eq(id_user))
+                .execute(conn)?;
+
+            delete(two_factor_dsl::user_two_factor)
+                .filter(two_factor_dsl::user_id.eq(id_user))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+}
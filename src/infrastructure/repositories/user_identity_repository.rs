@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{insert_into, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use tracing::error;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::user::user_identity::UserIdentity;
+use crate::domain::repositories::user_identity::UserIdentityRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::user_identity::UserIdentityDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+
+#[derive(Clone, new)]
+pub struct UserIdentityRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl GetPool for UserIdentityRepositoryImpl {}
+
+impl UserIdentityRepository for UserIdentityRepositoryImpl {
+    fn get(&self, provided_issuer: &str, provided_subject: &str) -> Result<UserIdentity> {
+        use crate::infrastructure::schema::user_identity::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let identity = user_identity
+            .filter(issuer.eq(provided_issuer))
+            .filter(subject.eq(provided_subject))
+            .select(UserIdentityDiesel::as_select())
+            .first(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(UserIdentity::from(identity))
+    }
+
+    fn create(&self, new_user_identity: &UserIdentity) -> Result<UserIdentity> {
+        use crate::infrastructure::schema::user_identity::dsl::user_identity;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(user_identity)
+            .values(UserIdentityDiesel::from(new_user_identity.clone()))
+            .get_result::<UserIdentityDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(UserIdentity::from(created))
+    }
+}
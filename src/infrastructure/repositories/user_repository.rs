@@ -1,16 +1,25 @@
+use crate::core::password_hashing::needs_rehash;
+use crate::domain::constants::DELETED_USER_ID;
 use crate::domain::error::Error;
 use crate::domain::error::Result;
+use crate::domain::models::team::team_member::TeamMember;
 use crate::domain::models::user::login_information::LoginInformation;
 use crate::domain::models::user::user_information::UserInformation;
 use crate::domain::repositories::user::UserRepository;
 use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::team_members::TeamMemberDiesel;
 use crate::infrastructure::models::user_information::UserInformationDiesel;
 use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_unique_violation::MapUniqueViolation;
 use argon2::password_hash::rand_core::OsRng;
 use argon2::password_hash::SaltString;
 use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use derive_new::new;
-use diesel::{insert_into, update, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use diesel::{
+    delete, insert_into, update, Connection, ExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 use tracing::error;
 use uuid::Uuid;
@@ -21,33 +30,91 @@ pub struct UserRepositoryImpl<'a> {
     argon2: Arc<Argon2<'a>>,
 }
 
-impl GetPool for UserRepositoryImpl<'_> {}
+impl Debug for UserRepositoryImpl<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserRepositoryImpl").finish()
+    }
+}
 
-impl UserRepository for UserRepositoryImpl<'_> {
-    fn create(&self, new_user_information: &UserInformation) -> Result<UserInformation> {
-        use crate::infrastructure::schema::user_information::dsl::user_information;
+impl GetPool for UserRepositoryImpl<'_> {}
 
-        let mut conn = Self::get_pool(&self.pool).unwrap();
-        let mut hashed_user_info = new_user_information.clone();
+impl MapUniqueViolation for UserRepositoryImpl<'_> {}
 
+impl UserRepositoryImpl<'_> {
+    fn hash_password(&self, password: &str) -> Result<String> {
         let salt = SaltString::generate(&mut OsRng);
-        let password_hash = match self
-            .argon2
-            .hash_password(hashed_user_info.password.as_bytes(), &salt)
-        {
-            Ok(pass) => pass.to_string(),
+
+        match self.argon2.hash_password(password.as_bytes(), &salt) {
+            Ok(pass) => Ok(pass.to_string()),
+            Err(e) => {
+                error!("{:?}", e);
+                Err(Error::RepositoryError)
+            }
+        }
+    }
+
+    fn check_password(&self, password: &str, password_hash: &str) -> Result<()> {
+        let parsed_hash = match PasswordHash::new(password_hash) {
+            Ok(pass) => pass,
             Err(e) => {
                 error!("{:?}", e);
                 return Err(Error::RepositoryError);
             }
         };
 
-        hashed_user_info.password = password_hash;
+        self.argon2
+            .verify_password(password.as_bytes(), &parsed_hash)
+            .map_err(|_| Error::RepositoryError)
+    }
+
+    /// Replaces a hash made with outdated argon2 settings, the password is only
+    /// known right after a successful login.
+    fn upgrade_password_hash(&self, user: &UserInformationDiesel, provided_password: &str) {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let outdated = PasswordHash::new(&user.password)
+            .map(|hash| needs_rehash(&self.argon2, &hash))
+            .unwrap_or(false);
+
+        if !outdated {
+            return;
+        }
+
+        let result = self
+            .hash_password(provided_password)
+            .and_then(|password_hash| {
+                let mut conn = Self::get_pool(&self.pool)?;
+
+                update(user_information)
+                    .filter(id.eq(user.id))
+                    .set(password.eq(password_hash))
+                    .execute(&mut conn)
+                    .map_err(|e| {
+                        error!("{:?}", e);
+                        Error::RepositoryError
+                    })
+            });
+
+        if let Err(e) = result {
+            error!("Failed to upgrade password hash: {:?}", e);
+        }
+    }
+}
+
+impl UserRepository for UserRepositoryImpl<'_> {
+    fn create(&self, new_user_information: &UserInformation) -> Result<UserInformation> {
+        use crate::infrastructure::schema::user_information::dsl::user_information;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+        let mut hashed_user_info = new_user_information.clone();
+
+        hashed_user_info.password = self.hash_password(&hashed_user_info.password)?;
 
         let new_user_information_diesel = UserInformationDiesel::from(hashed_user_info);
 
         let created_user = insert_into(user_information)
             .values(new_user_information_diesel)
+            .returning(UserInformationDiesel::as_returning())
             .get_result::<UserInformationDiesel>(&mut conn)
             .map_err(|e| {
                 error!("{:?}", e);
@@ -69,6 +136,7 @@ impl UserRepository for UserRepositoryImpl<'_> {
         let updated_user = update(user_information)
             .filter(id.eq(user_id))
             .set(profile_image.eq(profile_picture))
+            .returning(UserInformationDiesel::as_returning())
             .get_result::<UserInformationDiesel>(&mut conn)
             .map_err(|e| {
                 error!("{:?}", e);
@@ -120,25 +188,15 @@ impl UserRepository for UserRepositoryImpl<'_> {
         let user = user_information
             .select(UserInformationDiesel::as_select())
             .filter(email.eq(&login_information.email))
+            .filter(service_account_team_id.is_null())
             .first(&mut conn);
 
         match user {
             Ok(user_inf) => {
-                let parsed_hash = match PasswordHash::new(&user_inf.password) {
-                    Ok(pass) => pass,
-                    Err(e) => {
-                        error!("{:?}", e);
-                        return Err(Error::RepositoryError);
-                    }
-                };
-
-                match self
-                    .argon2
-                    .verify_password(login_information.password.as_bytes(), &parsed_hash)
-                {
-                    Ok(_) => Ok(UserInformation::from(user_inf)),
-                    Err(_) => Err(Error::RepositoryError),
-                }
+                self.check_password(&login_information.password, &user_inf.password)?;
+                self.upgrade_password_hash(&user_inf, &login_information.password);
+
+                Ok(UserInformation::from(user_inf))
             }
             Err(e) => {
                 error!("{:?}", e);
@@ -146,4 +204,342 @@ impl UserRepository for UserRepositoryImpl<'_> {
             }
         }
     }
+
+    fn get_by_email(&self, provided_email: &str) -> Result<UserInformation> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let user = user_information
+            .filter(email.eq(provided_email))
+            .select(UserInformationDiesel::as_select())
+            .first(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(UserInformation::from(user))
+    }
+
+    fn verify_password(&self, user_id: &Uuid, provided_password: &str) -> Result<()> {
+        let user = self.get(user_id)?;
+
+        self.check_password(provided_password, &user.password)
+    }
+
+    fn set_password(&self, user_id: &Uuid, provided_password: &str) -> Result<UserInformation> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let password_hash = self.hash_password(provided_password)?;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let updated_user = update(user_information)
+            .filter(id.eq(user_id))
+            .set(password.eq(password_hash))
+            .returning(UserInformationDiesel::as_returning())
+            .get_result::<UserInformationDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(UserInformation::from(updated_user))
+    }
+
+    fn set_email_verified(&self, user_id: &Uuid) -> Result<UserInformation> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let updated_user = update(user_information)
+            .filter(id.eq(user_id))
+            .set(email_verified.eq(true))
+            .returning(UserInformationDiesel::as_returning())
+            .get_result::<UserInformationDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(UserInformation::from(updated_user))
+    }
+
+    fn set_deletion_requested(
+        &self,
+        user_id: &Uuid,
+        timestamp: Option<i64>,
+    ) -> Result<UserInformation> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let updated_user = update(user_information)
+            .filter(id.eq(user_id))
+            .set(deletion_requested_timestamp.eq(timestamp))
+            .returning(UserInformationDiesel::as_returning())
+            .get_result::<UserInformationDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(UserInformation::from(updated_user))
+    }
+
+    fn get_all_deletion_due(&self, requested_before: i64) -> Result<Vec<UserInformation>> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let users = user_information
+            .filter(deletion_requested_timestamp.le(requested_before))
+            .select(UserInformationDiesel::as_select())
+            .load(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(users.into_iter().map(UserInformation::from).collect())
+    }
+
+    fn delete(&self, user_id: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::{
+            access_token_audit, login_audit, personal_access_token, task_assign,
+            task_checklist_item, task_comment, task_information, team_information, team_member,
+            user_identity, user_information, user_recovery_code, user_two_factor,
+            webauthn_credential,
+        };
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            update(task_information::table)
+                .filter(task_information::creator.eq(user_id))
+                .set(task_information::creator.eq(DELETED_USER_ID))
+                .execute(conn)?;
+
+            update(task_comment::table)
+                .filter(task_comment::author_id.eq(user_id))
+                .set(task_comment::author_id.eq(DELETED_USER_ID))
+                .execute(conn)?;
+
+            update(team_information::table)
+                .filter(team_information::creator.eq(user_id))
+                .set(team_information::creator.eq(DELETED_USER_ID))
+                .execute(conn)?;
+
+            delete(task_assign::table)
+                .filter(task_assign::user_id.eq(user_id))
+                .execute(conn)?;
+
+            update(task_checklist_item::table)
+                .filter(task_checklist_item::assignee_id.eq(user_id))
+                .set(task_checklist_item::assignee_id.eq(None::<Uuid>))
+                .execute(conn)?;
+
+            delete(team_member::table)
+                .filter(team_member::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(user_recovery_code::table)
+                .filter(user_recovery_code::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(user_two_factor::table)
+                .filter(user_two_factor::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(access_token_audit::table)
+                .filter(access_token_audit::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(personal_access_token::table)
+                .filter(personal_access_token::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(user_identity::table)
+                .filter(user_identity::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(webauthn_credential::table)
+                .filter(webauthn_credential::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(login_audit::table)
+                .filter(login_audit::user_id.eq(user_id))
+                .execute(conn)?;
+
+            delete(user_information::table)
+                .filter(user_information::id.eq(user_id))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .map_err(|e| {
+            error!("{:?}", e);
+            Error::RepositoryError
+        })
+    }
+
+    fn set_email(&self, user_id: &Uuid, new_email: &str) -> Result<UserInformation> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let updated_user = update(user_information)
+            .filter(id.eq(user_id))
+            .set((email.eq(new_email), email_verified.eq(true)))
+            .returning(UserInformationDiesel::as_returning())
+            .get_result::<UserInformationDiesel>(&mut conn)
+            .map_err(Self::map_unique_violation)?;
+
+        Ok(UserInformation::from(updated_user))
+    }
+
+    fn set_user_name(
+        &self,
+        user_id: &Uuid,
+        new_user_name: &str,
+        new_profile_image: Option<&str>,
+    ) -> Result<UserInformation> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let updated_user = update(user_information)
+            .filter(id.eq(user_id))
+            .set((
+                user_name.eq(new_user_name),
+                profile_image.eq(new_profile_image),
+            ))
+            .returning(UserInformationDiesel::as_returning())
+            .get_result::<UserInformationDiesel>(&mut conn)
+            .map_err(Self::map_unique_violation)?;
+
+        Ok(UserInformation::from(updated_user))
+    }
+
+    fn get_service_accounts(&self, team_id: &Uuid) -> Result<Vec<UserInformation>> {
+        use crate::infrastructure::schema::user_information::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let users = user_information
+            .filter(service_account_team_id.eq(team_id))
+            .select(UserInformationDiesel::as_select())
+            .order(id.asc())
+            .load(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(users.into_iter().map(UserInformation::from).collect())
+    }
+
+    fn create_service_account(
+        &self,
+        new_user_information: &UserInformation,
+        new_team_member: &TeamMember,
+    ) -> Result<UserInformation> {
+        use crate::infrastructure::schema::{team_member, user_information};
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+        let mut hashed_user_info = new_user_information.clone();
+
+        hashed_user_info.password = self.hash_password(&hashed_user_info.password)?;
+
+        let new_user_information_diesel = UserInformationDiesel::from(hashed_user_info);
+        let new_team_member_diesel = TeamMemberDiesel::from(new_team_member.clone());
+
+        let created_user = conn
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let created_user = insert_into(user_information::table)
+                    .values(new_user_information_diesel)
+                    .returning(UserInformationDiesel::as_returning())
+                    .get_result::<UserInformationDiesel>(conn)?;
+
+                insert_into(team_member::table)
+                    .values(new_team_member_diesel)
+                    .execute(conn)?;
+
+                Ok(created_user)
+            })
+            .map_err(Self::map_unique_violation)?;
+
+        Ok(UserInformation::from(created_user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use diesel::r2d2::{ConnectionManager, CustomizeConnection, Pool};
+    use diesel::PgConnection;
+
+    use super::*;
+    use crate::domain::constants::POSTGRESQL_DB_URI;
+
+    /// Every connection runs in a transaction that is never committed.
+    #[derive(Debug)]
+    struct TestTransaction;
+
+    impl CustomizeConnection<PgConnection, diesel::r2d2::Error> for TestTransaction {
+        fn on_acquire(
+            &self,
+            conn: &mut PgConnection,
+        ) -> std::result::Result<(), diesel::r2d2::Error> {
+            conn.begin_test_transaction()
+                .map_err(diesel::r2d2::Error::QueryError)
+        }
+    }
+
+    fn repository() -> UserRepositoryImpl<'static> {
+        let manager = ConnectionManager::<PgConnection>::new(
+            env::var(POSTGRESQL_DB_URI).expect("DATABASE_URL must be set"),
+        );
+
+        // A single connection, so the whole test sees its own uncommitted rows.
+        let pool = Pool::builder()
+            .max_size(1)
+            .connection_customizer(Box::new(TestTransaction))
+            .build(manager)
+            .unwrap();
+
+        UserRepositoryImpl::new(Arc::new(pool), Arc::new(Argon2::default()))
+    }
+
+    #[test]
+    #[ignore = "needs a migrated database in DATABASE_URL"]
+    fn test_create_returns_the_created_user() {
+        let repository = repository();
+        let id = Uuid::now_v7();
+
+        let created = repository
+            .create(&UserInformation {
+                id,
+                email: String::from("ada@example.com"),
+                user_name: String::from("ada"),
+                profile_image: None,
+                password: String::from("correct horse battery staple"),
+                email_verified: false,
+                deletion_requested_timestamp: None,
+                service_account_team_id: None,
+            })
+            .unwrap();
+
+        assert_eq!(created.id, id);
+        assert_eq!(created.email, "ada@example.com");
+        assert_eq!(created.user_name, "ada");
+        assert!(created.password.starts_with("$argon2"));
+
+        let renamed = repository.set_email(&id, "lovelace@example.com").unwrap();
+
+        assert_eq!(renamed.email, "lovelace@example.com");
+        assert!(renamed.password.starts_with("$argon2"));
+    }
 }
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use diesel::{
+    delete, insert_into, update, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+use crate::domain::error::Result;
+use crate::domain::models::user::webauthn_credential::WebauthnCredential;
+use crate::domain::repositories::webauthn_credential::WebauthnCredentialRepository;
+use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::models::webauthn_credential::WebauthnCredentialDiesel;
+use crate::infrastructure::repositories::get_pool::GetPool;
+use crate::infrastructure::repositories::map_from::MapFrom;
+
+#[derive(Clone, new)]
+pub struct WebauthnCredentialRepositoryImpl {
+    pool: Arc<DBConn>,
+}
+
+impl MapFrom for WebauthnCredentialRepositoryImpl {}
+
+impl GetPool for WebauthnCredentialRepositoryImpl {}
+
+impl WebauthnCredentialRepository for WebauthnCredentialRepositoryImpl {
+    fn create(&self, new_credential: &WebauthnCredential) -> Result<WebauthnCredential> {
+        use crate::infrastructure::schema::webauthn_credential::dsl::webauthn_credential;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let created = insert_into(webauthn_credential)
+            .values(WebauthnCredentialDiesel::from(new_credential.clone()))
+            .get_result::<WebauthnCredentialDiesel>(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(WebauthnCredential::from(created))
+    }
+
+    fn get_all_for_user(&self, id_user: &Uuid) -> Result<Vec<WebauthnCredential>> {
+        use crate::infrastructure::schema::webauthn_credential::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let query = webauthn_credential
+            .filter(user_id.eq(id_user))
+            .select(WebauthnCredentialDiesel::as_select())
+            .order_by(created_timestamp)
+            .load(&mut conn);
+
+        self.map_from(query)
+    }
+
+    fn update_usage(
+        &self,
+        id_credential: &Uuid,
+        new_passkey: &str,
+        new_sign_count: i64,
+        used_timestamp: i64,
+    ) -> Result<bool> {
+        use crate::infrastructure::schema::webauthn_credential::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        // Checked in the update itself, so two logins with the same signature can't
+        // both pass a check made against a counter read before either was stored.
+        let updated = update(webauthn_credential)
+            .filter(id.eq(id_credential))
+            .filter(
+                sign_count
+                    .lt(new_sign_count)
+                    .or(sign_count.eq(0).and(sign_count.eq(new_sign_count))),
+            )
+            .set((
+                passkey.eq(new_passkey),
+                sign_count.eq(new_sign_count),
+                last_used_timestamp.eq(used_timestamp),
+            ))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        Ok(updated == 1)
+    }
+
+    fn remove(&self, id_credential: &Uuid, id_user: &Uuid) -> Result<()> {
+        use crate::infrastructure::schema::webauthn_credential::dsl::*;
+
+        let mut conn = Self::get_pool(&self.pool).unwrap();
+
+        let removed = delete(webauthn_credential)
+            .filter(id.eq(id_credential))
+            .filter(user_id.eq(id_user))
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("{:?}", e);
+                Error::RepositoryError
+            })?;
+
+        if removed == 0 {
+            return Err(Error::RepositoryError);
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,80 @@
 // @generated automatically by Diesel CLI.
 
 pub mod sql_types {
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "access_token_scope"))]
+    pub struct AccessTokenScope;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "task_priority"))]
+    pub struct TaskPriority;
+
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "task_status"))]
     pub struct TaskStatus;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
+diesel::table! {
+    access_token_audit (id) {
+        id -> Uuid,
+        access_token_id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 255]
+        method -> Varchar,
+        created_timestamp -> Int8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::TaskStatus;
+
+    board_column (id) {
+        id -> Uuid,
+        team_id -> Uuid,
+        #[max_length = 50]
+        name -> Varchar,
+        status -> TaskStatus,
+        position -> Int4,
+    }
+}
+
+diesel::table! {
+    login_audit (id) {
+        id -> Uuid,
+        user_id -> Nullable<Uuid>,
+        success -> Bool,
+        #[max_length = 50]
+        reason -> Varchar,
+        #[max_length = 45]
+        ip -> Nullable<Varchar>,
+        #[max_length = 255]
+        user_agent -> Nullable<Varchar>,
+        two_factor_used -> Bool,
+        suspicious -> Bool,
+        created_timestamp -> Int8,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AccessTokenScope;
+
+    personal_access_token (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 50]
+        name -> Varchar,
+        token_hash -> Varchar,
+        scope -> AccessTokenScope,
+        created_timestamp -> Int8,
+        expires_timestamp -> Nullable<Int8>,
+        last_used_timestamp -> Nullable<Int8>,
+    }
 }
 
 diesel::table! {
@@ -14,9 +85,45 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    task_checklist_item (id) {
+        id -> Uuid,
+        task_id -> Uuid,
+        #[max_length = 200]
+        text -> Varchar,
+        done -> Bool,
+        assignee_id -> Nullable<Uuid>,
+        position -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
+    task_comment (id) {
+        id -> Uuid,
+        task_id -> Uuid,
+        author_id -> Uuid,
+        body -> Text,
+        created_timestamp -> Int8,
+        search_vector -> Tsvector,
+    }
+}
+
+diesel::table! {
+    task_dependency (id) {
+        id -> Uuid,
+        task_id -> Uuid,
+        blocker_id -> Uuid,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::TaskStatus;
+    use super::sql_types::TaskPriority;
+    use super::sql_types::Tsvector;
 
     task_information (id) {
         id -> Uuid,
@@ -28,6 +135,34 @@ diesel::table! {
         status -> TaskStatus,
         team_id -> Uuid,
         creator -> Uuid,
+        parent_id -> Nullable<Uuid>,
+        priority -> TaskPriority,
+        search_vector -> Tsvector,
+        column_id -> Nullable<Uuid>,
+        position -> Int4,
+        #[max_length = 255]
+        recurrence_rule -> Nullable<Varchar>,
+        recurrence_index -> Int4,
+        recurrence_pending -> Bool,
+        recurrence_anchor -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    task_label (id) {
+        id -> Uuid,
+        task_id -> Uuid,
+        label_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    task_reminder (id) {
+        id -> Uuid,
+        task_id -> Uuid,
+        offset_seconds -> Int8,
+        end_timestamp -> Int8,
+        sent_timestamp -> Int8,
     }
 }
 
@@ -45,6 +180,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    team_label (id) {
+        id -> Uuid,
+        team_id -> Uuid,
+        #[max_length = 50]
+        name -> Varchar,
+        #[max_length = 7]
+        color -> Varchar,
+    }
+}
+
 diesel::table! {
     team_member (id) {
         id -> Uuid,
@@ -69,6 +215,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    user_identity (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        issuer -> Varchar,
+        subject -> Varchar,
+    }
+}
+
 diesel::table! {
     user_information (id) {
         id -> Uuid,
@@ -78,24 +233,89 @@ diesel::table! {
         #[max_length = 25]
         user_name -> Varchar,
         password -> Varchar,
+        email_verified -> Bool,
+        deletion_requested_timestamp -> Nullable<Int8>,
+        service_account_team_id -> Nullable<Uuid>,
+    }
+}
+
+diesel::table! {
+    user_recovery_code (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        code_hash -> Varchar,
+    }
+}
+
+diesel::table! {
+    user_two_factor (user_id) {
+        user_id -> Uuid,
+        secret -> Varchar,
+        confirmed -> Bool,
+        last_used_step -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    webauthn_credential (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        credential_id -> Varchar,
+        #[max_length = 50]
+        name -> Varchar,
+        passkey -> Text,
+        sign_count -> Int8,
+        created_timestamp -> Int8,
+        last_used_timestamp -> Nullable<Int8>,
     }
 }
 
+diesel::joinable!(access_token_audit -> user_information (user_id));
+diesel::joinable!(board_column -> team_information (team_id));
+diesel::joinable!(login_audit -> user_information (user_id));
+diesel::joinable!(personal_access_token -> user_information (user_id));
 diesel::joinable!(task_assign -> task_information (task_id));
 diesel::joinable!(task_assign -> user_information (user_id));
+diesel::joinable!(task_checklist_item -> task_information (task_id));
+diesel::joinable!(task_checklist_item -> user_information (assignee_id));
+diesel::joinable!(task_comment -> task_information (task_id));
+diesel::joinable!(task_comment -> user_information (author_id));
+diesel::joinable!(task_information -> board_column (column_id));
 diesel::joinable!(task_information -> team_information (team_id));
+diesel::joinable!(task_label -> task_information (task_id));
+diesel::joinable!(task_label -> team_label (label_id));
+diesel::joinable!(task_reminder -> task_information (task_id));
 diesel::joinable!(task_information -> user_information (creator));
 diesel::joinable!(team_information -> user_information (creator));
+diesel::joinable!(team_label -> team_information (team_id));
 diesel::joinable!(team_member -> team_information (team_id));
 diesel::joinable!(team_member -> team_role (role_id));
 diesel::joinable!(team_member -> user_information (user_id));
 diesel::joinable!(team_role -> team_information (team_id));
+diesel::joinable!(user_identity -> user_information (user_id));
+diesel::joinable!(user_recovery_code -> user_information (user_id));
+diesel::joinable!(user_two_factor -> user_information (user_id));
+diesel::joinable!(webauthn_credential -> user_information (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    access_token_audit,
+    board_column,
+    login_audit,
+    personal_access_token,
     task_assign,
+    task_checklist_item,
+    task_comment,
+    task_dependency,
     task_information,
+    task_label,
+    task_reminder,
     team_information,
+    team_label,
     team_member,
     team_role,
+    user_identity,
     user_information,
+    user_recovery_code,
+    user_two_factor,
+    webauthn_credential,
 );
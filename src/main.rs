@@ -53,6 +53,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let container = Container::new().await;
 
+    tokio::spawn(container.account_deletion_worker.run());
+    tokio::spawn(container.task_metrics_worker.run());
+    tokio::spawn(container.recurrence_worker.run());
+    tokio::spawn(container.reminder_worker.run());
+    tokio::spawn(container.revoked_token_sync_worker.run());
+
     tokio::spawn(async move {
         tracing::info!(message = "Starting server 🙂", %addr);
         Server::builder()
@@ -74,8 +80,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let app = Router::new().route(
             "/metrics",
             get(|| async {
-                prometheus_exporter::encode_to_string()
-                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+                let mut metrics = prometheus_exporter::encode_to_string()
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                metrics.push_str(
+                    &crate::core::metrics::encode_to_string()
+                        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+                );
+                Ok::<String, StatusCode>(metrics)
             }),
         );
 